@@ -48,5 +48,86 @@ pub fn sha3_squeeze(a: &mut Buffer, out: &mut [u8], r: usize) {
     unsafe { SHA3_squeeze(a, out.as_mut_ptr(), out.len(), r) }
 }
 
+/// Round constants of Keccak-f\[1600\].
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, in the order of the lanes visited by the pi step.
+const RHO: [u32; 24] =
+    [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+/// Lanes visited by the pi step, starting from lane 1.
+const PI: [usize; 24] =
+    [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+/// Applies Keccak-p\[1600, `rounds`\], the last `rounds` rounds of Keccak-f\[1600\], to `a`.
+///
+/// This is used by reduced-round constructions such as Keyak (12 rounds) and Kravatte
+/// (6 rounds). The assembly implementations only provide the full 24 rounds, so it is implemented
+/// in Rust.
+///
+/// # Panics
+///
+/// Panics if `rounds` is greater than 24.
+pub fn keccak_p1600(a: &mut Buffer, rounds: usize) {
+    assert!(rounds <= 24, "Keccak-f[1600] has 24 rounds");
+    for &rc in &RC[24 - rounds..] {
+        // Theta.
+        let mut c = [0u64; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi.
+        let mut last = a[1];
+        for (&pi, &rho) in PI.iter().zip(&RHO) {
+            let next = a[pi];
+            a[pi] = last.rotate_left(rho);
+            last = next;
+        }
+
+        // Chi.
+        for row in a.chunks_exact_mut(5) {
+            let r = [row[0], row[1], row[2], row[3], row[4]];
+            for x in 0..5 {
+                row[x] = r[x] ^ (!r[(x + 1) % 5] & r[(x + 2) % 5]);
+            }
+        }
+
+        // Iota.
+        a[0] ^= rc;
+    }
+}
+
 #[doc(hidden)]
 pub const IMPL: &str = env!("SHA3_ASM_SRC");
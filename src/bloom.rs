@@ -0,0 +1,204 @@
+//! Ethereum 2048-bit logs bloom filter.
+//!
+//! See the definition of `M3:2048` in the [Ethereum Yellow Paper](https://ethereum.github.io/yellowpaper/paper.pdf),
+//! section 4.3.1.
+
+use crate::keccak256;
+use core::{
+    array::TryFromSliceError,
+    fmt,
+    ops::{BitOr, BitOrAssign},
+};
+
+/// Size of a bloom filter in bytes.
+pub const BLOOM_SIZE_BYTES: usize = 256;
+
+/// Input to a [`Bloom`] filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BloomInput<'a> {
+    /// Raw input, which will be hashed with Keccak-256.
+    Raw(&'a [u8]),
+    /// Already hashed input.
+    Hash([u8; 32]),
+}
+
+impl BloomInput<'_> {
+    #[inline]
+    fn hash(&self) -> [u8; 32] {
+        match *self {
+            Self::Raw(raw) => keccak256(raw),
+            Self::Hash(hash) => hash,
+        }
+    }
+}
+
+/// Ethereum 2048-bit logs bloom filter, as found in block headers and receipts.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bloom(pub [u8; BLOOM_SIZE_BYTES]);
+
+impl Default for Bloom {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl fmt::Debug for Bloom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Bloom(0x")?;
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl Bloom {
+    /// The empty bloom filter.
+    pub const ZERO: Self = Self([0; BLOOM_SIZE_BYTES]);
+
+    /// Creates a bloom filter from the bloom of a single log entry.
+    #[inline]
+    pub fn from_log(address: &[u8; 20], topics: &[[u8; 32]]) -> Self {
+        let mut bloom = Self::ZERO;
+        bloom.accrue_log(address, topics);
+        bloom
+    }
+
+    /// Creates a bloom filter from many log entries, e.g. all the logs of a receipt or of a block.
+    pub fn from_logs<'a, I, T>(logs: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a [u8; 20], T)>,
+        T: IntoIterator<Item = &'a [u8; 32]>,
+    {
+        let mut bloom = Self::ZERO;
+        for (address, topics) in logs {
+            bloom.m3_2048(&keccak256(address));
+            for topic in topics {
+                bloom.m3_2048(&keccak256(topic));
+            }
+        }
+        bloom
+    }
+
+    /// Returns the bytes of the filter.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; BLOOM_SIZE_BYTES] {
+        &self.0
+    }
+
+    /// Creates a bloom filter from its 256-byte wire form.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<Self, TryFromSliceError> {
+        slice.try_into().map(Self)
+    }
+
+    /// Returns `true` if no bits are set.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&b| b == 0)
+    }
+
+    /// Accrues the input into the filter.
+    #[inline]
+    pub fn accrue(&mut self, input: BloomInput<'_>) {
+        self.m3_2048(&input.hash());
+    }
+
+    /// Accrues the address and topics of a log entry into the filter.
+    #[inline]
+    pub fn accrue_log(&mut self, address: &[u8; 20], topics: &[[u8; 32]]) {
+        self.accrue(BloomInput::Raw(address));
+        for topic in topics {
+            self.accrue(BloomInput::Raw(topic));
+        }
+    }
+
+    /// Accrues another filter into this one.
+    #[inline]
+    pub fn accrue_bloom(&mut self, other: &Self) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a |= *b;
+        }
+    }
+
+    /// Returns `true` if the filter possibly contains the input.
+    ///
+    /// False positives are possible, false negatives are not.
+    #[inline]
+    pub fn contains_input(&self, input: BloomInput<'_>) -> bool {
+        let mut bloom = Self::ZERO;
+        bloom.accrue(input);
+        self.contains_bloom(&bloom)
+    }
+
+    /// Returns `true` if all the bits set in `other` are also set in this filter.
+    #[inline]
+    pub fn contains_bloom(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a & b == *b)
+    }
+
+    /// Sets the three bits selected by the first three pairs of bytes of `hash`.
+    #[inline]
+    fn m3_2048(&mut self, hash: &[u8; 32]) {
+        for i in [0, 2, 4] {
+            let bit = (usize::from(hash[i]) << 8 | usize::from(hash[i + 1])) & 0x7ff;
+            self.0[BLOOM_SIZE_BYTES - 1 - bit / 8] |= 1 << (bit % 8);
+        }
+    }
+}
+
+impl From<[u8; BLOOM_SIZE_BYTES]> for Bloom {
+    #[inline]
+    fn from(bytes: [u8; BLOOM_SIZE_BYTES]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Bloom> for [u8; BLOOM_SIZE_BYTES] {
+    #[inline]
+    fn from(bloom: Bloom) -> Self {
+        bloom.0
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Bloom {
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(slice)
+    }
+}
+
+impl AsRef<[u8]> for Bloom {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl BitOr for Bloom {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(mut self, rhs: Self) -> Self {
+        self.accrue_bloom(&rhs);
+        self
+    }
+}
+
+impl BitOrAssign for Bloom {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.accrue_bloom(&rhs);
+    }
+}
+
+impl<'a> Extend<BloomInput<'a>> for Bloom {
+    fn extend<I: IntoIterator<Item = BloomInput<'a>>>(&mut self, iter: I) {
+        for input in iter {
+            self.accrue(input);
+        }
+    }
+}
@@ -0,0 +1,490 @@
+//! Deterministic random bit generators of [SP 800-90A](https://doi.org/10.6028/NIST.SP.800-90Ar1):
+//! [`HashDrbg`] and [`HmacDrbg`], with the SHA-3 hashers.
+//!
+//! Both implement [`RngCore`] and [`CryptoRng`] without prediction resistance or additional
+//! input. Prediction resistance is obtained by generating with
+//! [`generate_with_prediction_resistance`](HashDrbg::generate_with_prediction_resistance), which
+//! reseeds from fresh entropy first.
+
+use crate::{Digest, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use core::{fmt, marker::PhantomData, num::NonZeroU32};
+use digest::{core_api::BlockSizeUser, typenum::Unsigned, Output};
+use rand_core::{CryptoRng, RngCore};
+
+pub use rand_core;
+
+/// Maximum number of requests between reseeds.
+pub const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+
+/// Maximum number of bytes per request.
+pub const MAX_REQUEST_BYTES: usize = 1 << 16;
+
+/// Maximum seed length of the supported hashers, in bytes.
+const MAX_SEED_LEN: usize = 111;
+
+/// Maximum block size of the supported hashers, in bytes.
+const MAX_BLOCK_SIZE: usize = 144;
+
+/// Errors of the DRBGs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrbgError {
+    /// The entropy input is shorter than the security strength.
+    InsufficientEntropy,
+    /// The reseed counter exceeds the reseed interval; the DRBG must be reseeded.
+    ReseedRequired,
+    /// More than [`MAX_REQUEST_BYTES`] bytes were requested.
+    RequestTooLarge,
+}
+
+impl fmt::Display for DrbgError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InsufficientEntropy => "entropy input is shorter than the security strength",
+            Self::ReseedRequired => "reseed required",
+            Self::RequestTooLarge => "too many bytes requested",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DrbgError {}
+
+impl From<DrbgError> for rand_core::Error {
+    #[inline]
+    fn from(err: DrbgError) -> Self {
+        let code = rand_core::Error::CUSTOM_START + err as u32;
+        NonZeroU32::new(code).unwrap().into()
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A hasher usable by the DRBGs, with its parameters from SP 800-90A table 2.
+pub trait DrbgDigest: Digest + BlockSizeUser + private::Sealed {
+    /// Seed length of [`HashDrbg`], in bytes.
+    const SEED_LEN: usize;
+    /// Security strength, in bytes, which is the minimum length of entropy inputs.
+    const SECURITY_STRENGTH: usize;
+}
+
+macro_rules! impl_drbg_digest {
+    ($($hasher:ty => $seed_len:literal, $strength:literal;)*) => {$(
+        impl private::Sealed for $hasher {}
+
+        impl DrbgDigest for $hasher {
+            const SEED_LEN: usize = $seed_len;
+            const SECURITY_STRENGTH: usize = $strength;
+        }
+    )*};
+}
+
+impl_drbg_digest! {
+    Sha3_224 => 55, 24;
+    Sha3_256 => 55, 32;
+    Sha3_384 => 111, 32;
+    Sha3_512 => 111, 32;
+}
+
+/// Hash_DRBG of SP 800-90A section 10.1.1.
+pub struct HashDrbg<D: DrbgDigest> {
+    v: [u8; MAX_SEED_LEN],
+    c: [u8; MAX_SEED_LEN],
+    reseed_counter: u64,
+    reseed_interval: u64,
+    _digest: PhantomData<D>,
+}
+
+impl<D: DrbgDigest> Clone for HashDrbg<D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { _digest: PhantomData, ..*self }
+    }
+}
+
+impl<D: DrbgDigest> fmt::Debug for HashDrbg<D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HashDrbg { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: DrbgDigest> Drop for HashDrbg<D> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.v.zeroize();
+        self.c.zeroize();
+    }
+}
+
+impl<D: DrbgDigest> HashDrbg<D> {
+    /// Instantiates the DRBG from an entropy input, a nonce and an optional personalization
+    /// string.
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError> {
+        check_entropy::<D>(entropy)?;
+        let mut drbg = Self {
+            v: [0; MAX_SEED_LEN],
+            c: [0; MAX_SEED_LEN],
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+            _digest: PhantomData,
+        };
+        hash_df::<D>(&[entropy, nonce, personalization], &mut drbg.v[..D::SEED_LEN]);
+        drbg.derive_c();
+        Ok(drbg)
+    }
+
+    /// Sets the number of requests after which [`generate`](Self::generate) fails until the DRBG
+    /// is reseeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero or exceeds [`MAX_RESEED_INTERVAL`].
+    #[inline]
+    pub fn with_reseed_interval(mut self, interval: u64) -> Self {
+        assert!((1..=MAX_RESEED_INTERVAL).contains(&interval), "invalid reseed interval");
+        self.reseed_interval = interval;
+        self
+    }
+
+    /// Returns the number of requests since the DRBG was instantiated or last reseeded, plus one.
+    #[inline]
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Reseeds the DRBG from an entropy input and an optional additional input.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_entropy::<D>(entropy)?;
+        let v = self.v;
+        hash_df::<D>(&[&[0x01], &v[..D::SEED_LEN], entropy, additional_input], self.v_mut());
+        self.derive_c();
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// Fills `out` with random bytes, mixing in an optional additional input.
+    pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_request(out, self.reseed_counter, self.reseed_interval)?;
+
+        if !additional_input.is_empty() {
+            let w = hash::<D>(&[&[0x02], self.v(), additional_input]);
+            add_be(self.v_mut(), &w);
+        }
+
+        // Hashgen.
+        let mut data = self.v;
+        let data = &mut data[..D::SEED_LEN];
+        for chunk in out.chunks_mut(<D as Digest>::output_size()) {
+            chunk.copy_from_slice(&hash::<D>(&[&*data])[..chunk.len()]);
+            add_be(data, &[1]);
+        }
+
+        let h = hash::<D>(&[&[0x03], self.v()]);
+        let (c, counter) = (self.c, self.reseed_counter.to_be_bytes());
+        let v = self.v_mut();
+        add_be(v, &h);
+        add_be(v, &c[..D::SEED_LEN]);
+        add_be(v, &counter);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Reseeds the DRBG from an entropy input and an optional additional input, then fills `out`
+    /// with random bytes.
+    #[inline]
+    pub fn generate_with_prediction_resistance(
+        &mut self,
+        out: &mut [u8],
+        entropy: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError> {
+        if out.len() > MAX_REQUEST_BYTES {
+            return Err(DrbgError::RequestTooLarge);
+        }
+        self.reseed(entropy, additional_input)?;
+        self.generate(out, &[])
+    }
+
+    #[inline]
+    fn v(&self) -> &[u8] {
+        &self.v[..D::SEED_LEN]
+    }
+
+    #[inline]
+    fn v_mut(&mut self) -> &mut [u8] {
+        &mut self.v[..D::SEED_LEN]
+    }
+
+    #[inline]
+    fn derive_c(&mut self) {
+        let v = self.v;
+        hash_df::<D>(&[&[0x00], &v[..D::SEED_LEN]], &mut self.c[..D::SEED_LEN]);
+    }
+}
+
+impl<D: DrbgDigest> RngCore for HashDrbg<D> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.try_fill_bytes(dest) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_REQUEST_BYTES) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: DrbgDigest> CryptoRng for HashDrbg<D> {}
+
+/// HMAC_DRBG of SP 800-90A section 10.1.2.
+pub struct HmacDrbg<D: DrbgDigest> {
+    k: Output<D>,
+    v: Output<D>,
+    reseed_counter: u64,
+    reseed_interval: u64,
+}
+
+impl<D: DrbgDigest> Clone for HmacDrbg<D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            k: self.k.clone(),
+            v: self.v.clone(),
+            reseed_counter: self.reseed_counter,
+            reseed_interval: self.reseed_interval,
+        }
+    }
+}
+
+impl<D: DrbgDigest> fmt::Debug for HmacDrbg<D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacDrbg { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: DrbgDigest> Drop for HmacDrbg<D> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
+
+impl<D: DrbgDigest> HmacDrbg<D> {
+    /// Instantiates the DRBG from an entropy input, a nonce and an optional personalization
+    /// string.
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError> {
+        check_entropy::<D>(entropy)?;
+        let mut v = Output::<D>::default();
+        v.iter_mut().for_each(|b| *b = 0x01);
+        let mut drbg = Self {
+            k: Output::<D>::default(),
+            v,
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+        };
+        drbg.update(&[entropy, nonce, personalization]);
+        Ok(drbg)
+    }
+
+    /// Sets the number of requests after which [`generate`](Self::generate) fails until the DRBG
+    /// is reseeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero or exceeds [`MAX_RESEED_INTERVAL`].
+    #[inline]
+    pub fn with_reseed_interval(mut self, interval: u64) -> Self {
+        assert!((1..=MAX_RESEED_INTERVAL).contains(&interval), "invalid reseed interval");
+        self.reseed_interval = interval;
+        self
+    }
+
+    /// Returns the number of requests since the DRBG was instantiated or last reseeded, plus one.
+    #[inline]
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Reseeds the DRBG from an entropy input and an optional additional input.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_entropy::<D>(entropy)?;
+        self.update(&[entropy, additional_input]);
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// Fills `out` with random bytes, mixing in an optional additional input.
+    pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_request(out, self.reseed_counter, self.reseed_interval)?;
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in out.chunks_mut(<D as Digest>::output_size()) {
+            self.v = hmac::<D>(&self.k, &[&self.v[..]]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Reseeds the DRBG from an entropy input and an optional additional input, then fills `out`
+    /// with random bytes.
+    #[inline]
+    pub fn generate_with_prediction_resistance(
+        &mut self,
+        out: &mut [u8],
+        entropy: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError> {
+        if out.len() > MAX_REQUEST_BYTES {
+            return Err(DrbgError::RequestTooLarge);
+        }
+        self.reseed(entropy, additional_input)?;
+        self.generate(out, &[])
+    }
+
+    /// HMAC_DRBG_Update, with the provided data given in parts.
+    fn update(&mut self, provided: &[&[u8]]) {
+        let empty = provided.iter().all(|part| part.is_empty());
+        for byte in [0x00, 0x01] {
+            if byte != 0x00 && empty {
+                break;
+            }
+            let byte = [byte];
+            let mut parts = [&[][..]; 5];
+            parts[0] = &self.v[..];
+            parts[1] = &byte[..];
+            parts[2..2 + provided.len()].copy_from_slice(provided);
+            self.k = hmac::<D>(&self.k, &parts[..2 + provided.len()]);
+            self.v = hmac::<D>(&self.k, &[&self.v[..]]);
+        }
+    }
+}
+
+impl<D: DrbgDigest> RngCore for HmacDrbg<D> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.try_fill_bytes(dest) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_REQUEST_BYTES) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: DrbgDigest> CryptoRng for HmacDrbg<D> {}
+
+#[inline]
+fn check_entropy<D: DrbgDigest>(entropy: &[u8]) -> Result<(), DrbgError> {
+    if entropy.len() < D::SECURITY_STRENGTH {
+        return Err(DrbgError::InsufficientEntropy);
+    }
+    Ok(())
+}
+
+#[inline]
+fn check_request(out: &[u8], reseed_counter: u64, reseed_interval: u64) -> Result<(), DrbgError> {
+    if out.len() > MAX_REQUEST_BYTES {
+        return Err(DrbgError::RequestTooLarge);
+    }
+    if reseed_counter > reseed_interval {
+        return Err(DrbgError::ReseedRequired);
+    }
+    Ok(())
+}
+
+/// Hashes the concatenation of `parts`.
+#[inline]
+fn hash<D: Digest>(parts: &[&[u8]]) -> Output<D> {
+    let mut hasher = D::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize()
+}
+
+/// Hash_df of SP 800-90A section 10.3.1, filling `out`.
+fn hash_df<D: Digest>(input: &[&[u8]], out: &mut [u8]) {
+    let bits = (out.len() as u32 * 8).to_be_bytes();
+    for (i, chunk) in out.chunks_mut(<D as Digest>::output_size()).enumerate() {
+        let mut hasher = D::new();
+        hasher.update([i as u8 + 1]);
+        hasher.update(bits);
+        for part in input {
+            hasher.update(part);
+        }
+        chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+    }
+}
+
+/// HMAC of the concatenation of `parts`, with a key no longer than a block.
+fn hmac<D: DrbgDigest>(key: &[u8], parts: &[&[u8]]) -> Output<D> {
+    let block_size = D::BlockSize::USIZE;
+    let mut pad = [0u8; MAX_BLOCK_SIZE];
+    let pad = &mut pad[..block_size];
+    pad[..key.len()].copy_from_slice(key);
+
+    pad.iter_mut().for_each(|b| *b ^= 0x36);
+    let mut inner = D::new();
+    inner.update(&*pad);
+    for part in parts {
+        inner.update(part);
+    }
+    let inner = inner.finalize();
+
+    pad.iter_mut().for_each(|b| *b ^= 0x36 ^ 0x5c);
+    let mut outer = D::new();
+    outer.update(&*pad);
+    outer.update(inner);
+    outer.finalize()
+}
+
+/// Adds the big-endian integer `b` to the big-endian integer `a`, modulo `2^(8 * a.len())`.
+#[inline]
+fn add_be(a: &mut [u8], b: &[u8]) {
+    let mut carry = 0u16;
+    let mut b = b.iter().rev();
+    for a in a.iter_mut().rev() {
+        let sum = *a as u16 + *b.next().unwrap_or(&0) as u16 + carry;
+        *a = sum as u8;
+        carry = sum >> 8;
+    }
+}
@@ -0,0 +1,177 @@
+//! The [duplex construction](https://keccak.team/files/SpongeDuplex.pdf) over Keccak-f[1600].
+//!
+//! Unlike a sponge, a duplex object keeps its state after producing output: every
+//! [`duplexing`](Duplex::duplexing) call absorbs one padded input block, permutes, and returns up
+//! to one block of output. Protocols such as SpongeWrap, transcripts and authenticated encryption
+//! are built on top of it.
+
+use core::fmt;
+use sha3_asm::Buffer;
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A duplex object with a rate of `RATE` bytes.
+///
+/// `RATE` must be a non-zero multiple of 8 lower than 200.
+///
+/// Inputs are padded with a domain byte followed by the final bit of the `pad10*1` rule. The
+/// domain byte holds the domain separation bits followed by the first bit of the padding, as in
+/// the SHA-3 padding bytes: `0x01` for plain `pad10*1`, which is the default, `0x06` for SHA-3 or
+/// `0x1f` for SHAKE.
+#[derive(Clone)]
+pub struct Duplex<const RATE: usize> {
+    a: Buffer,
+    domain: u8,
+}
+
+impl<const RATE: usize> Default for Duplex<RATE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const RATE: usize> fmt::Debug for Duplex<RATE> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Duplex { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const RATE: usize> Drop for Duplex<RATE> {
+    fn drop(&mut self) {
+        self.a.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const RATE: usize> ZeroizeOnDrop for Duplex<RATE> {}
+
+impl<const RATE: usize> Duplex<RATE> {
+    const VALID_RATE: () = assert!(RATE != 0 && RATE % 8 == 0 && RATE < 200, "invalid rate");
+
+    /// Creates a duplex object with an all-zero state and the `0x01` domain byte.
+    #[inline]
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_RATE;
+        Self { a: [0; 25], domain: 0x01 }
+    }
+
+    /// Sets the domain byte used to pad the inputs of [`duplexing`](Self::duplexing).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain` is zero, as it must contain the first bit of the padding, or if its
+    /// most significant bit is set, as it would cancel the last bit of the padding when the input
+    /// fills the rate but one byte.
+    #[inline]
+    pub const fn with_domain(mut self, domain: u8) -> Self {
+        assert!(domain != 0, "the domain byte must contain the first padding bit");
+        assert!(domain < 0x80, "the domain byte must not overlap the last padding bit");
+        self.domain = domain;
+        self
+    }
+
+    /// Returns the domain byte.
+    #[inline]
+    pub const fn domain(&self) -> u8 {
+        self.domain
+    }
+
+    /// Returns the lanes of the state.
+    #[inline]
+    pub const fn state(&self) -> &Buffer {
+        &self.a
+    }
+
+    /// Returns the lanes of the state mutably.
+    #[inline]
+    pub fn state_mut(&mut self) -> &mut Buffer {
+        &mut self.a
+    }
+
+    /// Pads `input` with the domain byte and `pad10*1`, absorbs it, and writes the first
+    /// `out.len()` bytes of the new state to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not shorter than `RATE`, or if `out` is longer than `RATE`.
+    pub fn duplexing(&mut self, input: &[u8], out: &mut [u8]) {
+        assert!(input.len() < RATE, "duplex input must be shorter than the rate");
+        assert!(out.len() <= RATE, "duplex output must not be longer than the rate");
+        let mut block = [0u8; RATE];
+        block[..input.len()].copy_from_slice(input);
+        block[input.len()] ^= self.domain;
+        block[RATE - 1] ^= 0x80;
+        sha3_asm::sha3_absorb(&mut self.a, &block, RATE);
+        self.extract(0, out);
+
+        #[cfg(feature = "zeroize")]
+        block.zeroize();
+    }
+
+    /// Absorbs `input` without any padding, and writes the first `out.len()` bytes of the new
+    /// state to `out`.
+    ///
+    /// The caller is responsible for the padding and domain separation of its inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` or `out` is longer than `RATE`.
+    pub fn duplexing_unpadded(&mut self, input: &[u8], out: &mut [u8]) {
+        assert!(input.len() <= RATE, "duplex input must not be longer than the rate");
+        assert!(out.len() <= RATE, "duplex output must not be longer than the rate");
+        self.xor_in(0, input);
+        self.permute();
+        self.extract(0, out);
+    }
+
+    /// Applies Keccak-f[1600] to the state.
+    #[inline]
+    pub fn permute(&mut self) {
+        sha3_asm::sha3_absorb(&mut self.a, &[0; RATE], RATE);
+    }
+
+    /// XORs `data` into the state, starting at byte `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + data.len()` exceeds the 200 bytes of the state.
+    pub fn xor_in(&mut self, offset: usize, data: &[u8]) {
+        assert!(offset + data.len() <= 200, "out of the state bounds");
+        for (i, &byte) in data.iter().enumerate() {
+            let pos = offset + i;
+            self.a[pos / 8] ^= (byte as u64) << (8 * (pos % 8));
+        }
+    }
+
+    /// Overwrites the state with `data`, starting at byte `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + data.len()` exceeds the 200 bytes of the state.
+    pub fn overwrite(&mut self, offset: usize, data: &[u8]) {
+        assert!(offset + data.len() <= 200, "out of the state bounds");
+        for (i, &byte) in data.iter().enumerate() {
+            let pos = offset + i;
+            let shift = 8 * (pos % 8);
+            let lane = &mut self.a[pos / 8];
+            *lane = (*lane & !(0xff << shift)) | ((byte as u64) << shift);
+        }
+    }
+
+    /// Reads `out.len()` bytes of the state, starting at byte `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + out.len()` exceeds the 200 bytes of the state.
+    pub fn extract(&self, offset: usize, out: &mut [u8]) {
+        assert!(offset + out.len() <= 200, "out of the state bounds");
+        for (i, byte) in out.iter_mut().enumerate() {
+            let pos = offset + i;
+            *byte = (self.a[pos / 8] >> (8 * (pos % 8))) as u8;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! [EIP-191](https://eips.ethereum.org/EIPS/eip-191) signed data hashing.
+
+use crate::Keccak256;
+use digest::Digest;
+
+const PERSONAL_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Hashes a message according to EIP-191 version `0x45` (`personal_sign`).
+///
+/// The message is prefixed with `"\x19Ethereum Signed Message:\n"` followed by its length in
+/// decimal, and then hashed with Keccak-256.
+pub fn eip191_personal_message_hash(msg: impl AsRef<[u8]>) -> [u8; 32] {
+    let msg = msg.as_ref();
+
+    // `usize::MAX` is at most 20 decimal digits.
+    let mut len_buf = [0u8; 20];
+    let mut pos = len_buf.len();
+    let mut len = msg.len();
+    loop {
+        pos -= 1;
+        len_buf[pos] = b'0' + (len % 10) as u8;
+        len /= 10;
+        if len == 0 {
+            break;
+        }
+    }
+
+    let mut hasher = Keccak256::new();
+    hasher.update(PERSONAL_MESSAGE_PREFIX);
+    hasher.update(&len_buf[pos..]);
+    hasher.update(msg);
+    hasher.finalize().into()
+}
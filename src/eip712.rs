@@ -0,0 +1,524 @@
+//! [EIP-712](https://eips.ethereum.org/EIPS/eip-712) typed structured data hashing.
+//!
+//! All hashing is streamed into [`Keccak256`] hashers: type encodings, dynamic values and arrays
+//! are never concatenated into intermediate buffers.
+
+use crate::{keccak256, Keccak256};
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+use digest::Digest;
+use serde_json::{Map, Value};
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Fields of the `EIP712Domain` struct, in the order mandated by the specification.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// EIP-712 error.
+#[derive(Debug)]
+pub enum Eip712Error {
+    /// The input is not valid JSON.
+    Json(serde_json::Error),
+    /// The JSON does not follow the typed data format.
+    InvalidTypedData(&'static str),
+    /// A type is neither a defined struct nor an atomic or dynamic Solidity type.
+    UnknownType(String),
+    /// A struct member is missing from the data.
+    MissingField(String),
+    /// A value cannot be encoded as its declared type.
+    InvalidValue {
+        /// The declared type.
+        ty: String,
+        /// Why the value was rejected.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for Eip712Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "invalid JSON: {e}"),
+            Self::InvalidTypedData(reason) => write!(f, "invalid typed data: {reason}"),
+            Self::UnknownType(ty) => write!(f, "unknown type `{ty}`"),
+            Self::MissingField(name) => write!(f, "missing field `{name}`"),
+            Self::InvalidValue { ty, reason } => write!(f, "invalid value for `{ty}`: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip712Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Eip712Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+type Result<T, E = Eip712Error> = core::result::Result<T, E>;
+
+/// A member of an EIP-712 struct type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip712Field {
+    /// The member name.
+    pub name: String,
+    /// The member type, e.g. `address`, `Person` or `uint256[]`.
+    pub ty: String,
+}
+
+impl Eip712Field {
+    /// Creates a new struct member.
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self { name: name.into(), ty: ty.into() }
+    }
+}
+
+/// EIP-712 typed data, as accepted by `eth_signTypedData_v4`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedData {
+    /// Struct type definitions, keyed by type name.
+    pub types: BTreeMap<String, Vec<Eip712Field>>,
+    /// The name of the type of [`message`](Self::message).
+    pub primary_type: String,
+    /// The `EIP712Domain` values.
+    pub domain: Map<String, Value>,
+    /// The message to sign.
+    pub message: Value,
+}
+
+impl TypedData {
+    /// Parses typed data from its standard JSON representation.
+    pub fn from_json(s: &str) -> Result<Self> {
+        Self::from_value(serde_json::from_str(s)?)
+    }
+
+    /// Parses typed data from an already deserialized JSON value.
+    pub fn from_value(value: Value) -> Result<Self> {
+        let mut value = match value {
+            Value::Object(map) => map,
+            _ => return Err(Eip712Error::InvalidTypedData("expected an object")),
+        };
+
+        let types = match value.remove("types") {
+            Some(Value::Object(types)) => types,
+            _ => return Err(Eip712Error::InvalidTypedData("`types` must be an object")),
+        };
+        let types = types
+            .into_iter()
+            .map(|(name, fields)| {
+                let fields = match fields {
+                    Value::Array(fields) => fields,
+                    _ => return Err(Eip712Error::InvalidTypedData("type must be an array")),
+                };
+                let fields = fields
+                    .iter()
+                    .map(|field| match (field.get("name"), field.get("type")) {
+                        (Some(Value::String(name)), Some(Value::String(ty))) => {
+                            Ok(Eip712Field::new(name.as_str(), ty.as_str()))
+                        }
+                        _ => Err(Eip712Error::InvalidTypedData(
+                            "type member must have a string `name` and `type`",
+                        )),
+                    })
+                    .collect::<Result<_>>()?;
+                Ok((name, fields))
+            })
+            .collect::<Result<_>>()?;
+
+        let primary_type = match value.remove("primaryType") {
+            Some(Value::String(primary_type)) => primary_type,
+            _ => return Err(Eip712Error::InvalidTypedData("`primaryType` must be a string")),
+        };
+        let domain = match value.remove("domain") {
+            Some(Value::Object(domain)) => domain,
+            _ => return Err(Eip712Error::InvalidTypedData("`domain` must be an object")),
+        };
+        let message = value.remove("message").unwrap_or_else(|| Value::Object(Map::new()));
+
+        Ok(Self { types, primary_type, domain, message })
+    }
+
+    /// Returns `encodeType(ty)`: the type signature of `ty` followed by the signatures of all the
+    /// struct types it references, sorted by name.
+    pub fn encode_type(&self, ty: &str) -> Result<String> {
+        let mut s = String::new();
+        self.write_encode_type(ty, self.struct_fields(ty)?, &mut s);
+        Ok(s)
+    }
+
+    /// Returns `typeHash(ty) = keccak256(encodeType(ty))`.
+    pub fn type_hash(&self, ty: &str) -> Result<[u8; 32]> {
+        self.fields_type_hash(ty, self.struct_fields(ty)?)
+    }
+
+    /// Returns `hashStruct(ty, data) = keccak256(typeHash(ty) ‖ encodeData(data))`.
+    pub fn hash_struct(&self, ty: &str, data: &Value) -> Result<[u8; 32]> {
+        self.hash_struct_fields(ty, self.struct_fields(ty)?, data)
+    }
+
+    /// Returns the domain separator, `hashStruct(EIP712Domain, domain)`.
+    ///
+    /// If `EIP712Domain` is not present in [`types`](Self::types), it is derived from the fields
+    /// present in [`domain`](Self::domain).
+    pub fn domain_separator(&self) -> Result<[u8; 32]> {
+        let domain = Value::Object(self.domain.clone());
+        match self.types.get(DOMAIN_TYPE) {
+            Some(fields) => self.hash_struct_fields(DOMAIN_TYPE, fields, &domain),
+            None => {
+                let fields = DOMAIN_FIELDS
+                    .iter()
+                    .filter(|(name, _)| self.domain.contains_key(*name))
+                    .map(|&(name, ty)| Eip712Field::new(name, ty))
+                    .collect::<Vec<_>>();
+                self.hash_struct_fields(DOMAIN_TYPE, &fields, &domain)
+            }
+        }
+    }
+
+    /// Returns the hash to be signed:
+    /// `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    ///
+    /// If the primary type is `EIP712Domain`, the message hash is omitted.
+    pub fn signing_hash(&self) -> Result<[u8; 32]> {
+        let mut hasher = Keccak256::new();
+        hasher.update([0x19, 0x01]);
+        hasher.update(self.domain_separator()?);
+        if self.primary_type != DOMAIN_TYPE {
+            hasher.update(self.hash_struct(&self.primary_type, &self.message)?);
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    fn struct_fields(&self, ty: &str) -> Result<&[Eip712Field]> {
+        self.types.get(ty).map(Vec::as_slice).ok_or_else(|| Eip712Error::UnknownType(ty.into()))
+    }
+
+    fn fields_type_hash(&self, ty: &str, fields: &[Eip712Field]) -> Result<[u8; 32]> {
+        let mut hasher = HashWriter(Keccak256::new());
+        self.write_encode_type(ty, fields, &mut hasher);
+        Ok(hasher.0.finalize().into())
+    }
+
+    fn write_encode_type(&self, ty: &str, fields: &[Eip712Field], w: &mut impl Write) {
+        let mut deps = BTreeSet::new();
+        for field in fields {
+            self.collect_deps(base_type(&field.ty), &mut deps);
+        }
+        deps.remove(ty);
+
+        write_type_signature(ty, fields, w);
+        for dep in deps {
+            write_type_signature(dep, &self.types[dep], w);
+        }
+    }
+
+    fn collect_deps<'a>(&'a self, ty: &'a str, deps: &mut BTreeSet<&'a str>) {
+        if let Some(fields) = self.types.get(ty) {
+            if deps.insert(ty) {
+                for field in fields {
+                    self.collect_deps(base_type(&field.ty), deps);
+                }
+            }
+        }
+    }
+
+    fn hash_struct_fields(
+        &self,
+        ty: &str,
+        fields: &[Eip712Field],
+        data: &Value,
+    ) -> Result<[u8; 32]> {
+        let data = match data {
+            Value::Object(data) => data,
+            _ => return Err(invalid_value(ty, "expected an object")),
+        };
+
+        let mut hasher = Keccak256::new();
+        hasher.update(self.fields_type_hash(ty, fields)?);
+        for field in fields {
+            let value = data
+                .get(&field.name)
+                .ok_or_else(|| Eip712Error::MissingField(field.name.clone()))?;
+            hasher.update(self.encode_value(&field.ty, value)?);
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// Encodes a single member value into its 32-byte `encodeData` representation.
+    fn encode_value(&self, ty: &str, value: &Value) -> Result<[u8; 32]> {
+        if let Some((inner, len)) = split_array(ty)? {
+            let items = match value {
+                Value::Array(items) => items,
+                _ => return Err(invalid_value(ty, "expected an array")),
+            };
+            if len.map_or(false, |len| len != items.len()) {
+                return Err(invalid_value(ty, "wrong array length"));
+            }
+            let mut hasher = Keccak256::new();
+            for item in items {
+                hasher.update(self.encode_value(inner, item)?);
+            }
+            return Ok(hasher.finalize().into());
+        }
+
+        if let Some(fields) = self.types.get(ty) {
+            return self.hash_struct_fields(ty, fields, value);
+        }
+
+        let mut word = [0u8; 32];
+        match ty {
+            "string" => match value {
+                Value::String(s) => word = keccak256(s.as_bytes()),
+                _ => return Err(invalid_value(ty, "expected a string")),
+            },
+            "bytes" => {
+                let s = value.as_str().ok_or_else(|| invalid_value(ty, "expected a hex string"))?;
+                let mut hasher = Keccak256::new();
+                hex_decode_chunks(s, |chunk| hasher.update(chunk))
+                    .map_err(|e| invalid_value(ty, e))?;
+                word = hasher.finalize().into();
+            }
+            "bool" => match value {
+                Value::Bool(b) => word[31] = *b as u8,
+                _ => return Err(invalid_value(ty, "expected a boolean")),
+            },
+            "address" => {
+                let s = value.as_str().ok_or_else(|| invalid_value(ty, "expected a hex string"))?;
+                let len = hex_decode_into(s, &mut word[12..]).map_err(|e| invalid_value(ty, e))?;
+                if len != 20 {
+                    return Err(invalid_value(ty, "expected 20 bytes"));
+                }
+            }
+            _ => {
+                if let Some(size) = ty.strip_prefix("bytes") {
+                    let size = parse_size(size).filter(|size| (1..=32).contains(size));
+                    let size = size.ok_or_else(|| Eip712Error::UnknownType(ty.into()))?;
+                    let s =
+                        value.as_str().ok_or_else(|| invalid_value(ty, "expected a hex string"))?;
+                    hex_decode_into(s, &mut word[..size]).map_err(|e| invalid_value(ty, e))?;
+                } else if let Some(bits) = ty.strip_prefix("uint") {
+                    let bits = parse_int_bits(ty, bits)?;
+                    word = parse_int(value, bits, false).map_err(|e| invalid_value(ty, e))?;
+                } else if let Some(bits) = ty.strip_prefix("int") {
+                    let bits = parse_int_bits(ty, bits)?;
+                    word = parse_int(value, bits, true).map_err(|e| invalid_value(ty, e))?;
+                } else {
+                    return Err(Eip712Error::UnknownType(ty.into()));
+                }
+            }
+        }
+        Ok(word)
+    }
+}
+
+/// [`fmt::Write`] adapter which feeds everything written into a hasher.
+struct HashWriter(Keccak256);
+
+impl Write for HashWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.update(s);
+        Ok(())
+    }
+}
+
+fn write_type_signature(ty: &str, fields: &[Eip712Field], w: &mut impl Write) {
+    // Writing into a `String` or a hasher never fails.
+    let _ = w.write_str(ty);
+    let _ = w.write_char('(');
+    for (i, field) in fields.iter().enumerate() {
+        if i != 0 {
+            let _ = w.write_char(',');
+        }
+        let _ = write!(w, "{} {}", field.ty, field.name);
+    }
+    let _ = w.write_char(')');
+}
+
+/// Strips all array suffixes from a type.
+fn base_type(ty: &str) -> &str {
+    ty.find('[').map_or(ty, |i| &ty[..i])
+}
+
+/// Splits `T[]` or `T[N]` into `T` and the optional fixed length.
+fn split_array(ty: &str) -> Result<Option<(&str, Option<usize>)>> {
+    if !ty.ends_with(']') {
+        return Ok(None);
+    }
+    let open = ty.rfind('[').ok_or_else(|| Eip712Error::UnknownType(ty.into()))?;
+    let len = &ty[open + 1..ty.len() - 1];
+    let len = if len.is_empty() {
+        None
+    } else {
+        Some(parse_size(len).ok_or_else(|| Eip712Error::UnknownType(ty.into()))?)
+    };
+    Ok(Some((&ty[..open], len)))
+}
+
+fn parse_size(s: &str) -> Option<usize> {
+    if s.is_empty() || s.starts_with('0') || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn parse_int_bits(ty: &str, bits: &str) -> Result<usize> {
+    parse_size(bits)
+        .filter(|bits| bits % 8 == 0 && (8..=256).contains(bits))
+        .ok_or_else(|| Eip712Error::UnknownType(ty.into()))
+}
+
+fn invalid_value(ty: &str, reason: &'static str) -> Eip712Error {
+    Eip712Error::InvalidValue { ty: ty.to_owned(), reason }
+}
+
+fn strip_hex_prefix(s: &str) -> Result<&[u8], &'static str> {
+    let hex = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"));
+    let hex = hex.ok_or("hex string must start with `0x`")?.as_bytes();
+    if hex.len() % 2 != 0 {
+        return Err("hex string has an odd length");
+    }
+    Ok(hex)
+}
+
+fn hex_nibble(c: u8) -> Result<u8, &'static str> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err("invalid hex character"),
+    }
+}
+
+fn hex_decode_slice(hex: &[u8], out: &mut [u8]) -> Result<(), &'static str> {
+    for (byte, pair) in out.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_nibble(pair[0])? << 4) | hex_nibble(pair[1])?;
+    }
+    Ok(())
+}
+
+/// Decodes a `0x`-prefixed hex string into the start of `out`, returning the decoded length.
+fn hex_decode_into(s: &str, out: &mut [u8]) -> Result<usize, &'static str> {
+    let hex = strip_hex_prefix(s)?;
+    let len = hex.len() / 2;
+    if len > out.len() {
+        return Err("hex string is too long");
+    }
+    hex_decode_slice(hex, &mut out[..len])?;
+    Ok(len)
+}
+
+/// Decodes a `0x`-prefixed hex string in fixed-size chunks, passing each to `f`.
+fn hex_decode_chunks(s: &str, mut f: impl FnMut(&[u8])) -> Result<(), &'static str> {
+    let mut buf = [0u8; 128];
+    for chunk in strip_hex_prefix(s)?.chunks(buf.len() * 2) {
+        let out = &mut buf[..chunk.len() / 2];
+        hex_decode_slice(chunk, out)?;
+        f(out);
+    }
+    Ok(())
+}
+
+/// Parses a JSON number, decimal string or hex string into a 256-bit big-endian word, checking
+/// that it fits in `bits` bits, and sign-extending negative values if `signed`.
+fn parse_int(value: &Value, bits: usize, signed: bool) -> Result<[u8; 32], &'static str> {
+    let mut word = [0u8; 32];
+    let negative = match value {
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                word[24..].copy_from_slice(&n.to_be_bytes());
+                false
+            } else if let Some(n) = n.as_i64() {
+                word[24..].copy_from_slice(&n.unsigned_abs().to_be_bytes());
+                true
+            } else {
+                return Err("expected an integer");
+            }
+        }
+        Value::String(s) => {
+            let (negative, s) = match s.strip_prefix('-') {
+                Some(s) => (true, s),
+                None => (false, s.as_str()),
+            };
+            if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                parse_uint_radix(hex, 16, &mut word)?;
+            } else {
+                parse_uint_radix(s, 10, &mut word)?;
+            }
+            negative
+        }
+        _ => return Err("expected a number or a numeric string"),
+    };
+
+    let bit_len = bit_len(&word);
+    if !signed {
+        if negative && bit_len != 0 {
+            return Err("negative value for unsigned integer");
+        }
+        if bit_len > bits {
+            return Err("value out of range");
+        }
+        return Ok(word);
+    }
+
+    // The magnitude of a positive value must be below `2^(bits-1)`; a negative one may also be
+    // exactly `2^(bits-1)`.
+    let is_min =
+        negative && bit_len == bits && word.iter().map(|b| b.count_ones()).sum::<u32>() == 1;
+    if bit_len >= bits && !is_min {
+        return Err("value out of range");
+    }
+    if negative {
+        // Two's complement negation.
+        let mut carry = true;
+        for byte in word.iter_mut().rev() {
+            let (b, c) = (!*byte).overflowing_add(carry as u8);
+            *byte = b;
+            carry = c;
+        }
+    }
+    Ok(word)
+}
+
+fn parse_uint_radix(s: &str, radix: u32, word: &mut [u8; 32]) -> Result<(), &'static str> {
+    if s.is_empty() {
+        return Err("empty number");
+    }
+    for c in s.chars() {
+        let digit = c.to_digit(radix).ok_or("invalid digit")?;
+        let mut carry = digit;
+        for byte in word.iter_mut().rev() {
+            let v = *byte as u32 * radix + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err("value out of range");
+        }
+    }
+    Ok(())
+}
+
+fn bit_len(word: &[u8; 32]) -> usize {
+    match word.iter().position(|&b| b != 0) {
+        Some(i) => (32 - i) * 8 - word[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
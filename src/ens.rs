@@ -0,0 +1,178 @@
+//! [ENS](https://docs.ens.domains) name hashing and encoding.
+//!
+//! Names must be normalized according to [ENSIP-15](https://docs.ens.domains/ensip/15) before
+//! hashing. Full normalization requires Unicode data tables which are out of the scope of this
+//! crate, so it is left to a pluggable [`Normalizer`].
+
+use crate::{keccak256, Keccak256};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt;
+use digest::Digest;
+
+/// Selector of `resolve(bytes name, bytes data)`, from
+/// [ENSIP-10](https://docs.ens.domains/ensip/10), which takes a [DNS-encoded](dns_encode) name.
+pub const RESOLVE_SELECTOR: [u8; 4] = [0x90, 0x61, 0xb9, 0x23];
+
+/// Returns the hash of a single label: `keccak256(label)`.
+///
+/// Labels in the encoded labelhash form `[<64 hex digits>]` are decoded instead of hashed.
+#[inline]
+pub fn labelhash(label: &str) -> [u8; 32] {
+    decode_encoded_labelhash(label).unwrap_or_else(|| keccak256(label.as_bytes()))
+}
+
+/// Returns the namehash of a name, as defined in [ENSIP-1](https://docs.ens.domains/ensip/1).
+///
+/// Labels are walked from right to left, hashing each into the node of its parent. The name
+/// should already be normalized, see [`namehash_normalized`].
+pub fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let mut hasher = Keccak256::new();
+        hasher.update(node);
+        hasher.update(labelhash(label));
+        node = hasher.finalize().into();
+    }
+    node
+}
+
+/// Returns the label itself if it is in the encoded labelhash form `[<64 hex digits>]`.
+fn decode_encoded_labelhash(label: &str) -> Option<[u8; 32]> {
+    let hex = label.strip_prefix('[')?.strip_suffix(']')?.as_bytes();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(hex.chunks_exact(2)) {
+        let hi = (pair[0] as char).to_digit(16)?;
+        let lo = (pair[1] as char).to_digit(16)?;
+        *byte = (hi << 4 | lo) as u8;
+    }
+    Some(hash)
+}
+
+/// ENS name normalizer, e.g. an [ENSIP-15](https://docs.ens.domains/ensip/15) implementation.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait Normalizer {
+    /// The error returned for names which cannot be normalized.
+    type Error;
+
+    /// Normalizes a full name, returning it unchanged if it is already normalized.
+    fn normalize<'a>(&self, name: &'a str) -> Result<Cow<'a, str>, Self::Error>;
+}
+
+/// Normalizer for ASCII-only names, which lowercases letters.
+///
+/// This is a subset of ENSIP-15 normalization which rejects all non-ASCII names.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsciiNormalizer;
+
+/// Error returned by [`AsciiNormalizer`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsciiNormalizerError {
+    /// The name contains a non-ASCII character.
+    NonAscii,
+    /// The name contains an empty label.
+    EmptyLabel,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for AsciiNormalizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NonAscii => "name contains a non-ASCII character",
+            Self::EmptyLabel => "name contains an empty label",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsciiNormalizerError {}
+
+#[cfg(feature = "alloc")]
+impl Normalizer for AsciiNormalizer {
+    type Error = AsciiNormalizerError;
+
+    fn normalize<'a>(&self, name: &'a str) -> Result<Cow<'a, str>, Self::Error> {
+        if !name.is_ascii() {
+            return Err(AsciiNormalizerError::NonAscii);
+        }
+        if !name.is_empty() && name.split('.').any(str::is_empty) {
+            return Err(AsciiNormalizerError::EmptyLabel);
+        }
+        if name.bytes().any(|b| b.is_ascii_uppercase()) {
+            Ok(Cow::Owned(name.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(name))
+        }
+    }
+}
+
+/// Normalizes `name` with `normalizer` and returns its [`namehash`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn namehash_normalized<N: Normalizer + ?Sized>(
+    name: &str,
+    normalizer: &N,
+) -> Result<[u8; 32], N::Error> {
+    normalizer.normalize(name).map(|name| namehash(&name))
+}
+
+/// Error returned by [`dns_encode`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmptyLabelError;
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for EmptyLabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("name contains an empty label")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyLabelError {}
+
+/// Encodes a name in DNS wire format, as expected by `resolve(bytes,bytes)`: each label is
+/// prefixed with its length, and the name is terminated by the empty root label.
+///
+/// Labels longer than 255 bytes are replaced by their encoded labelhash `[<64 hex digits>]`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn dns_encode(name: &str) -> Result<Vec<u8>, EmptyLabelError> {
+    let mut out = Vec::with_capacity(name.len() + 2);
+    if !name.is_empty() {
+        for label in name.split('.') {
+            match label.len() {
+                0 => return Err(EmptyLabelError),
+                len @ 1..=255 => {
+                    out.push(len as u8);
+                    out.extend_from_slice(label.as_bytes());
+                }
+                _ => {
+                    const HEX: &[u8; 16] = b"0123456789abcdef";
+                    out.push(66);
+                    out.push(b'[');
+                    for byte in keccak256(label.as_bytes()) {
+                        out.push(HEX[(byte >> 4) as usize]);
+                        out.push(HEX[(byte & 0x0f) as usize]);
+                    }
+                    out.push(b']');
+                }
+            }
+        }
+    }
+    out.push(0);
+    Ok(out)
+}
@@ -0,0 +1,308 @@
+//! [Ethash](https://ethereum.org/en/developers/docs/consensus-mechanisms/pow/mining/mining-algorithms/ethash/)
+//! proof-of-work verification, as used by Ethereum before the merge and by Ethereum Classic.
+//!
+//! Light verification only needs the cache of the block's epoch, from which the few dataset
+//! items accessed by [`hashimoto_light`](Cache::hashimoto_light) are computed on the fly.
+
+use crate::{keccak256, Digest, Keccak512};
+use alloc::vec::Vec;
+use sha3_asm::Buffer;
+
+/// Number of blocks per epoch.
+pub const EPOCH_LENGTH: u64 = 30_000;
+
+const WORD_BYTES: usize = 4;
+const DATASET_BYTES_INIT: u64 = 1 << 30;
+const DATASET_BYTES_GROWTH: u64 = 1 << 23;
+const CACHE_BYTES_INIT: u64 = 1 << 24;
+const CACHE_BYTES_GROWTH: u64 = 1 << 17;
+const MIX_BYTES: usize = 128;
+const HASH_BYTES: usize = 64;
+const DATASET_PARENTS: u32 = 256;
+const CACHE_ROUNDS: usize = 3;
+const ACCESSES: u32 = 64;
+
+/// Number of 32-bit words in a node.
+const NODE_WORDS: usize = HASH_BYTES / WORD_BYTES;
+/// Number of 32-bit words in the mix.
+const MIX_WORDS: usize = MIX_BYTES / WORD_BYTES;
+/// Number of nodes in the mix.
+const MIX_NODES: usize = MIX_WORDS / NODE_WORDS;
+
+/// A 64-byte node of the cache or the dataset, as little-endian 32-bit words.
+type Node = [u32; NODE_WORDS];
+
+/// Output of [`hashimoto_light`](Cache::hashimoto_light).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashimotoOutput {
+    /// The mix digest, which is committed to in the block header.
+    pub mix_digest: [u8; 32],
+    /// The result, which must not exceed `2^256 / difficulty`.
+    pub result: [u8; 32],
+}
+
+/// Returns the epoch of a block.
+#[inline]
+pub const fn epoch(block_number: u64) -> u64 {
+    block_number / EPOCH_LENGTH
+}
+
+/// Returns the seed hash of an epoch: Keccak-256 applied `epoch` times to 32 zero bytes.
+pub fn seed_hash(epoch: u64) -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    for _ in 0..epoch {
+        seed = keccak256(&seed);
+    }
+    seed
+}
+
+/// Returns the size of the cache of an epoch in bytes.
+pub fn cache_size(epoch: u64) -> usize {
+    let mut size = CACHE_BYTES_INIT + CACHE_BYTES_GROWTH * epoch - HASH_BYTES as u64;
+    while !is_prime(size / HASH_BYTES as u64) {
+        size -= 2 * HASH_BYTES as u64;
+    }
+    size as usize
+}
+
+/// Returns the size of the full dataset of an epoch in bytes.
+pub fn dataset_size(epoch: u64) -> u64 {
+    let mut size = DATASET_BYTES_INIT + DATASET_BYTES_GROWTH * epoch - MIX_BYTES as u64;
+    while !is_prime(size / MIX_BYTES as u64) {
+        size -= 2 * MIX_BYTES as u64;
+    }
+    size
+}
+
+/// The verification cache of an epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cache {
+    nodes: Vec<Node>,
+    dataset_size: u64,
+}
+
+impl Cache {
+    /// Generates the cache of an epoch.
+    ///
+    /// This takes a while, as the cache is 16 MiB at epoch 0 and grows by 128 KiB per epoch.
+    pub fn new(epoch: u64) -> Self {
+        Self::with_parameters(cache_size(epoch), dataset_size(epoch), &seed_hash(epoch))
+    }
+
+    /// Generates a cache with custom parameters.
+    ///
+    /// For example, with [ECIP-1099](https://ecips.ethereumclassic.org/ECIPs/ecip-1099) on
+    /// Ethereum Classic, epochs are 60000 blocks long, and the cache of epoch `e` uses the sizes of
+    /// epoch `e` with the seed hash of epoch `2 * e`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cache_size` is not a non-zero multiple of 64, or `dataset_size` is smaller than
+    /// 128.
+    pub fn with_parameters(cache_size: usize, dataset_size: u64, seed: &[u8; 32]) -> Self {
+        assert!(
+            cache_size != 0 && cache_size % HASH_BYTES == 0,
+            "cache size must be a non-zero multiple of {HASH_BYTES}"
+        );
+        assert!(dataset_size >= MIX_BYTES as u64, "dataset size must be at least {MIX_BYTES}");
+
+        // Sequentially produce the initial dataset.
+        let n = cache_size / HASH_BYTES;
+        let mut nodes = Vec::with_capacity(n);
+        nodes.push(node_from_bytes(&Keccak512::digest(seed).into()));
+        for i in 1..n {
+            let mut node = nodes[i - 1];
+            keccak512_64(&mut node);
+            nodes.push(node);
+        }
+
+        // Apply a low-round version of RandMemoHash.
+        for _ in 0..CACHE_ROUNDS {
+            for i in 0..n {
+                let v = nodes[i][0] as usize % n;
+                let mut node = nodes[(i + n - 1) % n];
+                node.iter_mut().zip(&nodes[v]).for_each(|(a, b)| *a ^= b);
+                keccak512_64(&mut node);
+                nodes[i] = node;
+            }
+        }
+
+        Self { nodes, dataset_size }
+    }
+
+    /// Returns the size of the cache in bytes.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.nodes.len() * HASH_BYTES
+    }
+
+    /// Returns the size in bytes of the full dataset generated from this cache.
+    #[inline]
+    pub fn dataset_size(&self) -> u64 {
+        self.dataset_size
+    }
+
+    /// Returns the cache as bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.nodes.iter().flat_map(node_to_bytes).collect()
+    }
+
+    /// Computes the 64-byte item of the full dataset at `index`.
+    #[inline]
+    pub fn dataset_item(&self, index: u32) -> [u8; 64] {
+        node_to_bytes(&self.dataset_node(index))
+    }
+
+    /// Computes the mix digest and result of a header with the light verification algorithm.
+    ///
+    /// `header_hash` is the Keccak-256 hash of the RLP-encoded header without the mix digest and
+    /// the nonce.
+    pub fn hashimoto_light(&self, header_hash: &[u8; 32], nonce: u64) -> HashimotoOutput {
+        let rows = (self.dataset_size / MIX_BYTES as u64) as u32;
+
+        // Combine the header and the little-endian nonce into a 64-byte seed.
+        let mut seed = [0u8; 40];
+        seed[..32].copy_from_slice(header_hash);
+        seed[32..].copy_from_slice(&nonce.to_le_bytes());
+        let seed: [u8; 64] = Keccak512::digest(seed).into();
+        let seed_head = u32::from_le_bytes(seed[..4].try_into().unwrap());
+
+        // Start the mix with the replicated seed.
+        let mut mix = [0u32; MIX_WORDS];
+        for (word, chunk) in mix.iter_mut().zip(seed.chunks_exact(4).cycle()) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        // Mix in random dataset nodes.
+        for i in 0..ACCESSES {
+            let parent = fnv(i ^ seed_head, mix[i as usize % MIX_WORDS]) % rows;
+            for (j, words) in mix.chunks_exact_mut(NODE_WORDS).enumerate() {
+                let node = self.dataset_node(parent * MIX_NODES as u32 + j as u32);
+                words.iter_mut().zip(&node).for_each(|(a, &b)| *a = fnv(*a, b));
+            }
+        }
+
+        // Compress the mix.
+        let mut mix_digest = [0u8; 32];
+        for (out, words) in mix_digest.chunks_exact_mut(4).zip(mix.chunks_exact(4)) {
+            let word = fnv(fnv(fnv(words[0], words[1]), words[2]), words[3]);
+            out.copy_from_slice(&word.to_le_bytes());
+        }
+
+        let mut buf = [0u8; 96];
+        buf[..64].copy_from_slice(&seed);
+        buf[64..].copy_from_slice(&mix_digest);
+        HashimotoOutput { mix_digest, result: keccak256(&buf) }
+    }
+
+    /// Verifies the proof-of-work of a header, returning `true` if `mix_digest` matches and the
+    /// result does not exceed `2^256 / difficulty`.
+    ///
+    /// `difficulty` is a 256-bit big-endian integer.
+    pub fn verify(
+        &self,
+        header_hash: &[u8; 32],
+        nonce: u64,
+        mix_digest: &[u8; 32],
+        difficulty: &[u8; 32],
+    ) -> bool {
+        let output = self.hashimoto_light(header_hash, nonce);
+        output.mix_digest == *mix_digest && meets_difficulty(&output.result, difficulty)
+    }
+
+    fn dataset_node(&self, index: u32) -> Node {
+        let n = self.nodes.len();
+        let mut mix = self.nodes[index as usize % n];
+        mix[0] ^= index;
+        keccak512_64(&mut mix);
+        for j in 0..DATASET_PARENTS {
+            let parent = fnv(index ^ j, mix[j as usize % NODE_WORDS]) as usize % n;
+            mix.iter_mut().zip(&self.nodes[parent]).for_each(|(a, &b)| *a = fnv(*a, b));
+        }
+        keccak512_64(&mut mix);
+        mix
+    }
+}
+
+/// Returns `true` if `result <= 2^256 / difficulty`, i.e. `result * difficulty <= 2^256`.
+fn meets_difficulty(result: &[u8; 32], difficulty: &[u8; 32]) -> bool {
+    let limbs = |bytes: &[u8; 32]| -> [u64; 4] {
+        core::array::from_fn(|i| {
+            u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap())
+        })
+    };
+    let (a, b) = (limbs(result), limbs(difficulty));
+    if b == [0; 4] {
+        return false;
+    }
+
+    // Schoolbook multiplication into 512 bits, little-endian limbs.
+    let mut product = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let t = a[i] as u128 * b[j] as u128 + product[i + j] as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + 4] = carry as u64;
+    }
+    match product[4..] {
+        [0, 0, 0, 0] => true,
+        [1, 0, 0, 0] => product[..4] == [0; 4],
+        _ => false,
+    }
+}
+
+/// Keccak-512 of a 64-byte node, in place.
+///
+/// The node fits in a single block, so it is written directly into the lanes of the state with
+/// the padding, permuted once, and read back, without going through a hasher.
+#[inline]
+fn keccak512_64(node: &mut Node) {
+    let mut a: Buffer = [0; 25];
+    for (lane, words) in a.iter_mut().zip(node.chunks_exact(2)) {
+        *lane = words[0] as u64 | (words[1] as u64) << 32;
+    }
+    // Keccak padding at byte 64, and the final bit of the 72-byte block.
+    a[8] = 0x01 | 0x80 << 56;
+    // Absorbing a zero lane only applies the permutation.
+    sha3_asm::sha3_absorb(&mut a, &[0; 8], 8);
+    for (words, lane) in node.chunks_exact_mut(2).zip(&a) {
+        words[0] = *lane as u32;
+        words[1] = (*lane >> 32) as u32;
+    }
+}
+
+#[inline]
+fn fnv(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(0x0100_0193) ^ b
+}
+
+#[inline]
+fn node_from_bytes(bytes: &[u8; 64]) -> Node {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+#[inline]
+fn node_to_bytes(node: &Node) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(node) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut i = 2;
+    while i * i <= n {
+        if n % i == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
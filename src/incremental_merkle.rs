@@ -0,0 +1,323 @@
+//! Fixed-depth, append-only Keccak-256 Merkle tree, as maintained on-chain by the
+//! [deposit contract](https://github.com/ethereum/consensus-specs/blob/dev/solidity_deposit_contract/deposit_contract.sol)
+//! and bridge contracts such as the Polygon zkEVM `DepositContract`.
+//!
+//! Only the frontier of the tree, the rightmost completed node of each level, is stored. Empty
+//! leaves are zero, and empty subtrees hash to precomputed zero hashes. Nodes are hashed as
+//! `keccak256(left . right)`, without sorting.
+
+use crate::keccak256;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Error returned when appending to a full tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeFullError;
+
+impl fmt::Display for TreeFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("incremental Merkle tree is full")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TreeFullError {}
+
+/// Error returned when decoding an invalid serialized tree state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidStateError;
+
+impl fmt::Display for InvalidStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid incremental Merkle tree state")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidStateError {}
+
+/// The authentication path of a retained leaf, filled in as its sibling subtrees are completed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Witness<const DEPTH: usize> {
+    index: u64,
+    path: [Option<[u8; 32]>; DEPTH],
+}
+
+/// Append-only Merkle tree of depth `DEPTH`, holding up to [`CAPACITY`](Self::CAPACITY) leaves.
+///
+/// Leaves appended with [`append_retained`](Self::append_retained) keep their authentication
+/// path up to date as the tree grows, so that [`proof`](Self::proof) can be generated for them
+/// at any time without storing the rest of the tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncrementalMerkleTree<const DEPTH: usize> {
+    count: u64,
+    branch: [[u8; 32]; DEPTH],
+    zeros: [[u8; 32]; DEPTH],
+    /// Sorted by leaf index.
+    retained: Vec<Witness<DEPTH>>,
+}
+
+impl<const DEPTH: usize> Default for IncrementalMerkleTree<DEPTH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize> IncrementalMerkleTree<DEPTH> {
+    /// The maximum number of leaves, `2^DEPTH - 1`, as in the deposit contract.
+    pub const CAPACITY: u64 = u64::MAX >> (64 - DEPTH);
+
+    /// Creates an empty tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `DEPTH` is not in `1..=64`.
+    pub fn new() -> Self {
+        assert!((1..=64).contains(&DEPTH), "invalid tree depth: {DEPTH}");
+        let mut zeros = [[0; 32]; DEPTH];
+        for h in 1..DEPTH {
+            zeros[h] = hash_node(&zeros[h - 1], &zeros[h - 1]);
+        }
+        Self { count: 0, branch: [[0; 32]; DEPTH], zeros, retained: Vec::new() }
+    }
+
+    /// Returns the number of leaves.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns `true` if the tree has no leaves.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the roots of empty subtrees, from an empty leaf up to the children of the root.
+    #[inline]
+    pub fn zero_hashes(&self) -> &[[u8; 32]; DEPTH] {
+        &self.zeros
+    }
+
+    /// Appends a leaf, returning its index.
+    #[inline]
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<u64, TreeFullError> {
+        self.append_inner(leaf, false)
+    }
+
+    /// Appends a leaf and retains its authentication path, returning its index.
+    #[inline]
+    pub fn append_retained(&mut self, leaf: [u8; 32]) -> Result<u64, TreeFullError> {
+        self.append_inner(leaf, true)
+    }
+
+    fn append_inner(&mut self, leaf: [u8; 32], retain: bool) -> Result<u64, TreeFullError> {
+        let index = self.count;
+        if index >= Self::CAPACITY {
+            return Err(TreeFullError);
+        }
+
+        if retain {
+            // The left siblings on the path are all complete, and are on the frontier.
+            let mut path = [None; DEPTH];
+            for (h, node) in path.iter_mut().enumerate() {
+                if index >> h & 1 == 1 {
+                    *node = Some(self.branch[h]);
+                }
+            }
+            self.retained.push(Witness { index, path });
+        }
+
+        // Walk up the levels while the new leaf completes a subtree, recording each completed
+        // subtree in the paths of the retained leaves it is the right sibling of.
+        let mut node = leaf;
+        for h in 0..DEPTH {
+            let subtree = index >> h;
+            for witness in &mut self.retained {
+                if (witness.index >> h) ^ 1 == subtree {
+                    witness.path[h] = Some(node);
+                }
+            }
+            if subtree & 1 == 0 {
+                self.branch[h] = node;
+                break;
+            }
+            node = hash_node(&self.branch[h], &node);
+        }
+
+        self.count += 1;
+        Ok(index)
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> [u8; 32] {
+        let mut node = [0; 32];
+        for h in 0..DEPTH {
+            node = self.hash_partial(h, &node);
+        }
+        node
+    }
+
+    /// Returns the indices of the retained leaves, in ascending order.
+    #[inline]
+    pub fn retained(&self) -> impl ExactSizeIterator<Item = u64> + '_ {
+        self.retained.iter().map(|witness| witness.index)
+    }
+
+    /// Stops retaining the authentication path of the leaf at `index`. Returns `false` if the
+    /// leaf was not retained.
+    pub fn forget(&mut self, index: u64) -> bool {
+        match self.find(index) {
+            Ok(i) => {
+                self.retained.remove(i);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the authentication path of the retained leaf at `index` against the current
+    /// [`root`](Self::root), from the leaf's sibling up to the root's children.
+    pub fn proof(&self, index: u64) -> Option<[[u8; 32]; DEPTH]> {
+        let witness = &self.retained[self.find(index).ok()?];
+
+        // The roots of the subtrees which contain the next empty leaf; their left parts are on
+        // the frontier, and the rest is empty.
+        let mut partial = [[0; 32]; DEPTH];
+        let mut node = [0; 32];
+        for (h, partial) in partial.iter_mut().enumerate() {
+            *partial = node;
+            node = self.hash_partial(h, &node);
+        }
+
+        let mut proof = [[0; 32]; DEPTH];
+        for (h, node) in proof.iter_mut().enumerate() {
+            *node = witness.path[h].unwrap_or_else(|| {
+                // The right sibling is not complete yet.
+                if (index >> h) ^ 1 == self.count >> h {
+                    partial[h]
+                } else {
+                    self.zeros[h]
+                }
+            });
+        }
+        Some(proof)
+    }
+
+    /// Returns `true` if `proof` proves that `leaf` is at `index` in the tree with the given
+    /// `root`.
+    pub fn verify(root: &[u8; 32], leaf: &[u8; 32], index: u64, proof: &[[u8; 32]; DEPTH]) -> bool {
+        let mut node = *leaf;
+        for (h, sibling) in proof.iter().enumerate() {
+            node = if index >> h & 1 == 1 {
+                hash_node(sibling, &node)
+            } else {
+                hash_node(&node, sibling)
+            };
+        }
+        node == *root
+    }
+
+    /// Serializes the state of the tree: the leaf count, the frontier and the paths of the
+    /// retained leaves.
+    ///
+    /// The encoding is the big-endian leaf count, the `DEPTH` frontier nodes, the big-endian
+    /// number of retained leaves, and for each retained leaf its big-endian index, a big-endian
+    /// bitmap of the known levels of its path, and the known nodes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(16 + DEPTH * 32 + self.retained.len() * 16);
+        out.extend_from_slice(&self.count.to_be_bytes());
+        self.branch.iter().for_each(|node| out.extend_from_slice(node));
+        out.extend_from_slice(&(self.retained.len() as u64).to_be_bytes());
+        for witness in &self.retained {
+            out.extend_from_slice(&witness.index.to_be_bytes());
+            let bitmap = witness
+                .path
+                .iter()
+                .enumerate()
+                .fold(0u64, |bitmap, (h, node)| bitmap | (node.is_some() as u64) << h);
+            out.extend_from_slice(&bitmap.to_be_bytes());
+            witness.path.iter().flatten().for_each(|node| out.extend_from_slice(node));
+        }
+        out
+    }
+
+    /// Deserializes a tree serialized with [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, InvalidStateError> {
+        let mut tree = Self::new();
+        tree.count = read_u64(&mut bytes)?;
+        if tree.count > Self::CAPACITY {
+            return Err(InvalidStateError);
+        }
+        for node in &mut tree.branch {
+            *node = read_node(&mut bytes)?;
+        }
+
+        let retained = read_u64(&mut bytes)?;
+        for _ in 0..retained {
+            let index = read_u64(&mut bytes)?;
+            let bitmap = read_u64(&mut bytes)?;
+            let sorted = tree.retained.last().map_or(true, |last| last.index < index);
+            if !sorted || index >= tree.count || (DEPTH < 64 && bitmap >> DEPTH != 0) {
+                return Err(InvalidStateError);
+            }
+            let mut path = [None; DEPTH];
+            for (h, node) in path.iter_mut().enumerate() {
+                if bitmap >> h & 1 == 1 {
+                    *node = Some(read_node(&mut bytes)?);
+                }
+            }
+            tree.retained.push(Witness { index, path });
+        }
+
+        if !bytes.is_empty() {
+            return Err(InvalidStateError);
+        }
+        Ok(tree)
+    }
+
+    /// Hashes `node`, the root of the subtree at level `h` containing the next empty leaf, into
+    /// its parent.
+    #[inline]
+    fn hash_partial(&self, h: usize, node: &[u8; 32]) -> [u8; 32] {
+        if self.count >> h & 1 == 1 {
+            hash_node(&self.branch[h], node)
+        } else {
+            hash_node(node, &self.zeros[h])
+        }
+    }
+
+    #[inline]
+    fn find(&self, index: u64) -> Result<usize, usize> {
+        self.retained.binary_search_by_key(&index, |witness| witness.index)
+    }
+}
+
+#[inline]
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    keccak256(&buf)
+}
+
+fn read_u64(bytes: &mut &[u8]) -> Result<u64, InvalidStateError> {
+    let (head, rest) = split(bytes, 8)?;
+    *bytes = rest;
+    Ok(u64::from_be_bytes(head.try_into().unwrap()))
+}
+
+fn read_node(bytes: &mut &[u8]) -> Result<[u8; 32], InvalidStateError> {
+    let (head, rest) = split(bytes, 32)?;
+    *bytes = rest;
+    Ok(head.try_into().unwrap())
+}
+
+#[inline]
+fn split<'a>(bytes: &&'a [u8], mid: usize) -> Result<(&'a [u8], &'a [u8]), InvalidStateError> {
+    if bytes.len() < mid {
+        return Err(InvalidStateError);
+    }
+    Ok(bytes.split_at(mid))
+}
@@ -0,0 +1,436 @@
+//! Key derivation functions.
+//!
+//! - [HKDF](https://datatracker.ietf.org/doc/html/rfc5869) and
+//!   [PBKDF2](https://datatracker.ietf.org/doc/html/rfc8018#section-5.2) with HMAC-SHA3-256.
+//! - The one-step KDF of [SP 800-56C](https://doi.org/10.6028/NIST.SP.800-56Cr2) with SHA3-256,
+//!   SHA3-512, KMAC128 and KMAC256, and its [`FixedInfo`].
+//! - The KMAC-based KDF of [SP 800-108r1](https://doi.org/10.6028/NIST.SP.800-108r1).
+
+use crate::{Digest, Sha3_256, Sha3_256Core, Sha3_512};
+use core::fmt;
+use digest::{
+    core_api::{Block, BlockSizeUser, Buffer, FixedOutputCore, UpdateCore},
+    typenum::Unsigned,
+    Output,
+};
+
+/// Block size of SHA3-256, which is also the HMAC key block size.
+const BLOCK_SIZE: usize = <Sha3_256Core as BlockSizeUser>::BlockSize::USIZE;
+/// Output size of SHA3-256.
+const HASH_SIZE: usize = 32;
+
+/// Error returned when the requested output is too long: longer than 255 hashes for
+/// [`hkdf_sha3_256`], or `2^32 - 1` hashes for the one-step KDF.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidLength;
+
+impl fmt::Display for InvalidLength {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid output length")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLength {}
+
+/// Derives `out.len()` bytes from the input keying material `ikm` with HKDF-SHA3-256.
+///
+/// An empty `salt` is equivalent to the default salt of 32 zero bytes.
+pub fn hkdf_sha3_256(
+    ikm: &[u8],
+    salt: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    if out.len() > 255 * HASH_SIZE {
+        return Err(InvalidLength);
+    }
+
+    // Extract.
+    let extract = HmacSha3_256::new(salt);
+    let mut inner = extract.start();
+    inner.update(ikm);
+    let prk = extract.finish(inner);
+
+    // Expand.
+    let hmac = HmacSha3_256::new(&prk);
+    let mut t = [0u8; HASH_SIZE];
+    for (i, chunk) in out.chunks_mut(HASH_SIZE).enumerate() {
+        let mut inner = hmac.start();
+        if i != 0 {
+            inner.update(t);
+        }
+        inner.update(info);
+        inner.update([i as u8 + 1]);
+        t = hmac.finish(inner);
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+    Ok(())
+}
+
+/// Derives `out.len()` bytes from `password` and `salt` with PBKDF2-HMAC-SHA3-256.
+///
+/// The inner and outer HMAC states after absorbing the padded key are computed once, and each
+/// iteration only copies them and hashes a single partial block in each.
+///
+/// # Panics
+///
+/// Panics if `rounds` is zero.
+pub fn pbkdf2_sha3_256(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) {
+    assert!(rounds != 0, "rounds must be non-zero");
+
+    let hmac = HmacSha3_256::new(password);
+    for (i, chunk) in out.chunks_mut(HASH_SIZE).enumerate() {
+        let mut inner = hmac.start();
+        inner.update(salt);
+        inner.update((i as u32 + 1).to_be_bytes());
+        let mut u = hmac.finish(inner);
+        let mut t = u;
+        for _ in 1..rounds {
+            u = hmac.mac_hash(&u);
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+/// HMAC-SHA3-256 midstates: the states after absorbing the key padded with `ipad` and `opad`.
+struct HmacSha3_256 {
+    inner: Sha3_256Core,
+    outer: Sha3_256Core,
+}
+
+impl HmacSha3_256 {
+    fn new(key: &[u8]) -> Self {
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            block[..HASH_SIZE].copy_from_slice(&Sha3_256::digest(key));
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let pad = |byte: u8| {
+            let mut core = Sha3_256Core::default();
+            let mut padded = Block::<Sha3_256Core>::default();
+            padded.iter_mut().zip(&block).for_each(|(p, k)| *p = k ^ byte);
+            core.update_blocks(core::slice::from_ref(&padded));
+            core
+        };
+        Self { inner: pad(0x36), outer: pad(0x5c) }
+    }
+
+    /// Returns a hasher for the message, continuing from the inner midstate.
+    #[inline]
+    fn start(&self) -> Sha3_256 {
+        Sha3_256::from_core(self.inner.clone())
+    }
+
+    /// Finishes the MAC of the message absorbed by `inner`.
+    #[inline]
+    fn finish(&self, inner: Sha3_256) -> [u8; HASH_SIZE] {
+        let inner_hash: [u8; HASH_SIZE] = inner.finalize().into();
+        finalize(self.outer.clone(), &inner_hash)
+    }
+
+    /// Returns the MAC of a single hash, finalizing copies of the midstates directly.
+    #[inline]
+    fn mac_hash(&self, data: &[u8; HASH_SIZE]) -> [u8; HASH_SIZE] {
+        let inner_hash = finalize(self.inner.clone(), data);
+        finalize(self.outer.clone(), &inner_hash)
+    }
+}
+
+/// Finalizes a midstate with a final message shorter than a block.
+#[inline]
+fn finalize(mut core: Sha3_256Core, data: &[u8; HASH_SIZE]) -> [u8; HASH_SIZE] {
+    let mut buffer = Buffer::<Sha3_256Core>::new(data);
+    let mut out = Output::<Sha3_256Core>::default();
+    core.finalize_fixed_core(&mut buffer, &mut out);
+    out.into()
+}
+
+/// Fixed info of the one-step KDF in the concatenation format of
+/// [SP 800-56A](https://doi.org/10.6028/NIST.SP.800-56Ar3) section 5.8.2.1.1:
+/// `AlgorithmID || PartyUInfo || PartyVInfo || SuppPubInfo || SuppPrivInfo`.
+///
+/// The first three fields are prefixed with their 32-bit big-endian length, and are omitted when
+/// not set. The supplementary fields are appended as is, so an otherwise empty fixed info with
+/// only `SuppPubInfo` set encodes arbitrary bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FixedInfo<'a> {
+    algorithm_id: Option<&'a [u8]>,
+    party_u_info: Option<&'a [u8]>,
+    party_v_info: Option<&'a [u8]>,
+    supp_pub_info: &'a [u8],
+    supp_priv_info: &'a [u8],
+}
+
+impl<'a> FixedInfo<'a> {
+    /// Creates an empty fixed info.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            algorithm_id: None,
+            party_u_info: None,
+            party_v_info: None,
+            supp_pub_info: &[],
+            supp_priv_info: &[],
+        }
+    }
+
+    /// Sets `AlgorithmID`, which identifies how the derived keying material will be used.
+    #[inline]
+    pub const fn algorithm_id(mut self, algorithm_id: &'a [u8]) -> Self {
+        self.algorithm_id = Some(algorithm_id);
+        self
+    }
+
+    /// Sets `PartyUInfo`, the public information contributed by the initiator.
+    #[inline]
+    pub const fn party_u_info(mut self, party_u_info: &'a [u8]) -> Self {
+        self.party_u_info = Some(party_u_info);
+        self
+    }
+
+    /// Sets `PartyVInfo`, the public information contributed by the responder.
+    #[inline]
+    pub const fn party_v_info(mut self, party_v_info: &'a [u8]) -> Self {
+        self.party_v_info = Some(party_v_info);
+        self
+    }
+
+    /// Sets `SuppPubInfo`, such as the length of the derived keying material.
+    #[inline]
+    pub const fn supp_pub_info(mut self, supp_pub_info: &'a [u8]) -> Self {
+        self.supp_pub_info = supp_pub_info;
+        self
+    }
+
+    /// Sets `SuppPrivInfo`, such as a shared secret known to both parties.
+    #[inline]
+    pub const fn supp_priv_info(mut self, supp_priv_info: &'a [u8]) -> Self {
+        self.supp_priv_info = supp_priv_info;
+        self
+    }
+
+    /// Returns the length of the encoding.
+    pub fn len(&self) -> usize {
+        [self.algorithm_id, self.party_u_info, self.party_v_info]
+            .iter()
+            .flatten()
+            .map(|field| 4 + field.len())
+            .sum::<usize>()
+            + self.supp_pub_info.len()
+            + self.supp_priv_info.len()
+    }
+
+    /// Returns `true` if the encoding is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the encoding into `out`.
+    pub fn encode<U: digest::Update + ?Sized>(&self, out: &mut U) {
+        for field in [self.algorithm_id, self.party_u_info, self.party_v_info].iter().flatten() {
+            out.update(&(field.len() as u32).to_be_bytes());
+            out.update(field);
+        }
+        out.update(self.supp_pub_info);
+        out.update(self.supp_priv_info);
+    }
+}
+
+/// Derives `out.len()` bytes from the shared secret `z` with the one-step KDF of
+/// [SP 800-56C](https://doi.org/10.6028/NIST.SP.800-56Cr2) using SHA3-256:
+/// `SHA3-256(counter || z || fixed_info)` for a 32-bit big-endian counter starting at 1.
+#[inline]
+pub fn one_step_sha3_256(
+    z: &[u8],
+    fixed_info: &FixedInfo<'_>,
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    one_step_hash::<Sha3_256>(z, fixed_info, out)
+}
+
+/// Derives `out.len()` bytes from the shared secret `z` with the one-step KDF of
+/// [SP 800-56C](https://doi.org/10.6028/NIST.SP.800-56Cr2) using SHA3-512.
+///
+/// See [`one_step_sha3_256`].
+#[inline]
+pub fn one_step_sha3_512(
+    z: &[u8],
+    fixed_info: &FixedInfo<'_>,
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    one_step_hash::<Sha3_512>(z, fixed_info, out)
+}
+
+fn one_step_hash<D: Digest + digest::Update>(
+    z: &[u8],
+    fixed_info: &FixedInfo<'_>,
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    let hash_size = <D as Digest>::output_size();
+    if (out.len() as u64 + hash_size as u64 - 1) / hash_size as u64 > u32::MAX as u64 {
+        return Err(InvalidLength);
+    }
+
+    for (i, chunk) in out.chunks_mut(hash_size).enumerate() {
+        let mut hasher = D::new();
+        Digest::update(&mut hasher, (i as u32 + 1).to_be_bytes());
+        Digest::update(&mut hasher, z);
+        fixed_info.encode(&mut hasher);
+        chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+    }
+    Ok(())
+}
+
+/// Derives `out.len()` bytes from the shared secret `z` with the one-step KDF of
+/// [SP 800-56C](https://doi.org/10.6028/NIST.SP.800-56Cr2) using KMAC128:
+/// `KMAC128(salt, 00000001 || z || fixed_info, L, "KDF")`.
+///
+/// Without a salt, the default salt of 164 zero bytes is used.
+#[inline]
+pub fn one_step_kmac128(z: &[u8], salt: Option<&[u8]>, fixed_info: &FixedInfo<'_>, out: &mut [u8]) {
+    one_step_kmac::<KMAC128_RATE>(z, salt, fixed_info, out)
+}
+
+/// Derives `out.len()` bytes from the shared secret `z` with the one-step KDF of
+/// [SP 800-56C](https://doi.org/10.6028/NIST.SP.800-56Cr2) using KMAC256.
+///
+/// Without a salt, the default salt of 132 zero bytes is used. See [`one_step_kmac128`].
+#[inline]
+pub fn one_step_kmac256(z: &[u8], salt: Option<&[u8]>, fixed_info: &FixedInfo<'_>, out: &mut [u8]) {
+    one_step_kmac::<KMAC256_RATE>(z, salt, fixed_info, out)
+}
+
+fn one_step_kmac<const RATE: usize>(
+    z: &[u8],
+    salt: Option<&[u8]>,
+    fixed_info: &FixedInfo<'_>,
+    out: &mut [u8],
+) {
+    let default_salt = [0u8; KMAC128_RATE - 4];
+    let salt = salt.unwrap_or(&default_salt[..RATE - 4]);
+    let mut kmac = Kmac::<RATE>::new(salt, b"KDF");
+    kmac.absorb(&1u32.to_be_bytes());
+    kmac.absorb(z);
+    fixed_info.encode(&mut kmac);
+    kmac.finalize(out);
+}
+
+/// Derives `out.len()` bytes from the key `key` with the KMAC-based KDF of
+/// [SP 800-108r1](https://doi.org/10.6028/NIST.SP.800-108r1) section 4.4:
+/// `KMAC128(key, context, L, label)`.
+#[inline]
+pub fn kdf_kmac128(key: &[u8], label: &[u8], context: &[u8], out: &mut [u8]) {
+    kdf_kmac::<KMAC128_RATE>(key, label, context, out)
+}
+
+/// Derives `out.len()` bytes from the key `key` with the KMAC-based KDF of
+/// [SP 800-108r1](https://doi.org/10.6028/NIST.SP.800-108r1) section 4.4:
+/// `KMAC256(key, context, L, label)`.
+#[inline]
+pub fn kdf_kmac256(key: &[u8], label: &[u8], context: &[u8], out: &mut [u8]) {
+    kdf_kmac::<KMAC256_RATE>(key, label, context, out)
+}
+
+fn kdf_kmac<const RATE: usize>(key: &[u8], label: &[u8], context: &[u8], out: &mut [u8]) {
+    let mut kmac = Kmac::<RATE>::new(key, label);
+    kmac.absorb(context);
+    kmac.finalize(out);
+}
+
+const KMAC128_RATE: usize = 168;
+const KMAC256_RATE: usize = 136;
+
+/// [KMAC](https://doi.org/10.6028/NIST.SP.800-185) with a variable output length, where the
+/// rate selects KMAC128 or KMAC256.
+struct Kmac<const RATE: usize> {
+    a: sha3_asm::Buffer,
+    buf: [u8; RATE],
+    pos: usize,
+}
+
+impl<const RATE: usize> Kmac<RATE> {
+    /// Starts `cSHAKE(bytepad(encode_string(key), rate) || ..., "KMAC", custom)`.
+    fn new(key: &[u8], custom: &[u8]) -> Self {
+        let mut kmac = Self { a: [0; 25], buf: [0; RATE], pos: 0 };
+        let mut enc = [0u8; 9];
+
+        kmac.absorb(left_encode(RATE as u64, &mut enc));
+        kmac.absorb(left_encode(32, &mut enc));
+        kmac.absorb(b"KMAC");
+        kmac.absorb(left_encode(custom.len() as u64 * 8, &mut enc));
+        kmac.absorb(custom);
+        kmac.fill_block();
+
+        kmac.absorb(left_encode(RATE as u64, &mut enc));
+        kmac.absorb(left_encode(key.len() as u64 * 8, &mut enc));
+        kmac.absorb(key);
+        kmac.fill_block();
+        kmac
+    }
+
+    fn absorb(&mut self, mut data: &[u8]) {
+        if self.pos != 0 {
+            let n = data.len().min(RATE - self.pos);
+            self.buf[self.pos..self.pos + n].copy_from_slice(&data[..n]);
+            self.pos += n;
+            data = &data[n..];
+            if self.pos < RATE {
+                return;
+            }
+            sha3_asm::sha3_absorb(&mut self.a, &self.buf, RATE);
+            self.pos = 0;
+        }
+        let rem = sha3_asm::sha3_absorb(&mut self.a, data, RATE);
+        self.buf[..rem].copy_from_slice(&data[data.len() - rem..]);
+        self.pos = rem;
+    }
+
+    /// Pads the absorbed data with zeroes to a whole number of blocks, as `bytepad` does.
+    fn fill_block(&mut self) {
+        if self.pos != 0 {
+            self.buf[self.pos..].fill(0);
+            sha3_asm::sha3_absorb(&mut self.a, &self.buf, RATE);
+            self.pos = 0;
+        }
+    }
+
+    /// Appends `right_encode(L)` and squeezes `L = out.len() * 8` bits.
+    fn finalize(mut self, out: &mut [u8]) {
+        let bits = out.len() as u64 * 8;
+        let be = bits.to_be_bytes();
+        let n = (8 - bits.leading_zeros() as usize / 8).max(1);
+        self.absorb(&be[8 - n..]);
+        self.absorb(&[n as u8]);
+
+        // cSHAKE padding.
+        self.buf[self.pos..].fill(0);
+        self.buf[self.pos] = 0x04;
+        self.buf[RATE - 1] |= 0x80;
+        sha3_asm::sha3_absorb(&mut self.a, &self.buf, RATE);
+        sha3_asm::sha3_squeeze(&mut self.a, out, RATE);
+    }
+}
+
+impl<const RATE: usize> digest::Update for Kmac<RATE> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.absorb(data);
+    }
+}
+
+/// `left_encode` of SP 800-185: the big-endian bytes of `x` without leading zeroes, at least one,
+/// prefixed with their number.
+#[inline]
+fn left_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = (8 - x.leading_zeros() as usize / 8).max(1);
+    buf[0] = n as u8;
+    buf[1..].copy_from_slice(&x.to_be_bytes());
+    buf.copy_within(9 - n.., 1);
+    &buf[..n + 1]
+}
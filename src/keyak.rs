@@ -0,0 +1,358 @@
+//! Authenticated encryption with the Motorist mode of [Keyak](https://keccak.team/keyak.html), with
+//! the Lake Keyak parameters: one piston over Keccak-p\[1600, 12\], a 256-bit capacity and
+//! 128-bit tags.
+//!
+//! [`LakeKeyak`] implements [`AeadInPlace`], starting a new session for every message.
+//! [`LakeKeyakSession`] keeps the session across messages, so that every tag authenticates all
+//! the messages of the session so far.
+//!
+//! River Keyak runs on Keccak-p\[800, 12\], and is not provided.
+//!
+//! The permutation is [`sha3_asm::keccak_p1600`], run on the lanes of the state.
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, Key, KeyInit, KeySizeUser, Nonce, Tag,
+};
+use core::fmt;
+use sha3_asm::Buffer;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Number of rounds of the permutation.
+const ROUNDS: usize = 12;
+/// Squeeze rate: the bytes of the state used to encrypt.
+const RS: usize = 168;
+/// Absorb rate: the bytes of the state used to inject data.
+const RA: usize = 192;
+/// Offsets of the frame bytes, right after the absorb rate.
+const EOM: usize = RA;
+const CRYPT_END: usize = RA + 1;
+const INJECT_START: usize = RA + 2;
+const INJECT_END: usize = RA + 3;
+/// Length of the tags, in bytes.
+const TAG_LEN: usize = 16;
+/// Length of the chaining values of knots, in bytes.
+const KNOT_LEN: usize = 32;
+/// Length of the key pack, in bytes.
+const KEY_PACK_LEN: usize = 40;
+
+/// Maximum length of keys, in bytes.
+pub const MAX_KEY_LEN: usize = KEY_PACK_LEN - 2;
+/// Maximum length of nonces, in bytes, such that the key pack, the nonce and the piston count and
+/// index fit in one block.
+pub const MAX_NONCE_LEN: usize = RA - KEY_PACK_LEN - 2;
+
+/// Phases of the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Phase {
+    Fresh,
+    Crypted,
+    EndOfCrypt,
+    EndOfMessage,
+}
+
+/// The engine of a single piston.
+#[derive(Clone)]
+struct Engine {
+    state: Buffer,
+    phase: Phase,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Engine {
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl Engine {
+    /// Starts an engine by injecting the secret and unique value `suv`, diversified with the
+    /// piston count and index.
+    fn new(key: &[u8], nonce: &[u8]) -> Self {
+        assert!(key.len() <= MAX_KEY_LEN, "Keyak keys must be at most 38 bytes long");
+        assert!(nonce.len() <= MAX_NONCE_LEN, "Keyak nonces must be at most 150 bytes long");
+        let mut suv = [0u8; RA];
+        suv[0] = KEY_PACK_LEN as u8;
+        suv[1..1 + key.len()].copy_from_slice(key);
+        suv[1 + key.len()] = 0x01;
+        suv[KEY_PACK_LEN..KEY_PACK_LEN + nonce.len()].copy_from_slice(nonce);
+        let len = KEY_PACK_LEN + nonce.len();
+        suv[len..len + 2].copy_from_slice(&[1, 0]);
+
+        let mut engine = Self { state: [0; 25], phase: Phase::Fresh };
+        engine.inject_collective(&suv[..len + 2]);
+
+        #[cfg(feature = "zeroize")]
+        suv.zeroize();
+
+        engine
+    }
+
+    #[inline]
+    fn permute(&mut self) {
+        sha3_asm::keccak_p1600(&mut self.state, ROUNDS);
+    }
+
+    /// Returns the byte of the state at `pos`.
+    #[inline]
+    fn byte(&self, pos: usize) -> u8 {
+        (self.state[pos / 8] >> (8 * (pos % 8))) as u8
+    }
+
+    /// XORs `byte` into the state at `pos`.
+    #[inline]
+    fn xor_byte(&mut self, pos: usize, byte: u8) {
+        self.state[pos / 8] ^= (byte as u64) << (8 * (pos % 8));
+    }
+
+    /// Marks the end of a message with the length of its tag, or not, and permutes.
+    fn spark(&mut self, eom: bool, tag_len: usize) {
+        if eom {
+            self.xor_byte(EOM, if tag_len == 0 { 0xff } else { tag_len as u8 });
+        }
+        self.permute();
+    }
+
+    /// Encrypts or decrypts the next block of `data` in place, starting at `*pos`.
+    fn crypt(&mut self, data: &mut [u8], pos: &mut usize, unwrap: bool) {
+        debug_assert_eq!(self.phase, Phase::Fresh);
+        let n = (data.len() - *pos).min(RS);
+        for (i, x) in data[*pos..*pos + n].iter_mut().enumerate() {
+            // The state ends up holding the ciphertext either way.
+            let s = self.byte(i);
+            if unwrap {
+                self.xor_byte(i, s ^ *x);
+                *x ^= s;
+            } else {
+                *x ^= s;
+                self.xor_byte(i, s ^ *x);
+            }
+        }
+        *pos += n;
+        self.xor_byte(CRYPT_END, n as u8);
+        self.phase = if *pos < data.len() { Phase::Crypted } else { Phase::EndOfCrypt };
+    }
+
+    /// Injects the next block of `input`, after the encrypted part of the state if `crypting`.
+    fn inject_block(&mut self, input: &mut &[u8], crypting: bool) {
+        let start = if crypting { RS } else { 0 };
+        let n = input.len().min(RA - start);
+        self.xor_byte(INJECT_START, start as u8);
+        for (i, &x) in input[..n].iter().enumerate() {
+            self.xor_byte(start + i, x);
+        }
+        *input = &input[n..];
+        self.xor_byte(INJECT_END, (start + n) as u8);
+    }
+
+    /// Injects the next block of associated data, after the block encrypted last if any.
+    fn inject(&mut self, ad: &mut &[u8]) {
+        debug_assert_ne!(self.phase, Phase::EndOfMessage);
+        let crypting = matches!(self.phase, Phase::Crypted | Phase::EndOfCrypt);
+        self.inject_block(ad, crypting);
+        if self.phase == Phase::Crypted || !ad.is_empty() {
+            self.spark(false, 0);
+            self.phase = Phase::Fresh;
+        } else {
+            self.phase = Phase::EndOfMessage;
+        }
+    }
+
+    /// Injects a whole message of at most one block.
+    fn inject_collective(&mut self, mut data: &[u8]) {
+        debug_assert_eq!(self.phase, Phase::Fresh);
+        debug_assert!(data.len() <= RA);
+        self.inject_block(&mut data, false);
+        self.phase = Phase::EndOfMessage;
+    }
+
+    /// Ends the message and writes its tag, which may be empty.
+    fn get_tag(&mut self, tag: &mut [u8]) {
+        debug_assert_eq!(self.phase, Phase::EndOfMessage);
+        self.spark(true, tag.len());
+        for (i, t) in tag.iter_mut().enumerate() {
+            *t = self.byte(i);
+        }
+        self.phase = Phase::Fresh;
+    }
+
+    /// Ties a knot: replaces the state with a function of it, so that earlier states cannot be
+    /// recovered from later ones.
+    fn make_knot(&mut self) {
+        let mut chaining_value = [0u8; KNOT_LEN];
+        self.get_tag(&mut chaining_value);
+        self.inject_collective(&chaining_value);
+    }
+}
+
+/// A Lake Keyak session: a sequence of messages, each authenticated together with all the
+/// previous ones.
+///
+/// A failed [`unwrap`](Self::unwrap) ends the session: all later calls fail.
+#[derive(Clone)]
+pub struct LakeKeyakSession {
+    engine: Engine,
+    failed: bool,
+}
+
+impl fmt::Debug for LakeKeyakSession {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LakeKeyakSession { ... }")
+    }
+}
+
+impl LakeKeyakSession {
+    /// Starts a session with a key of at most [`MAX_KEY_LEN`] bytes and a nonce of at most
+    /// [`MAX_NONCE_LEN`] bytes, which must not be reused with the same key.
+    ///
+    /// If `forget` is set, a knot is tied after injecting the key and nonce.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key or the nonce is too long.
+    pub fn new(key: &[u8], nonce: &[u8], forget: bool) -> Self {
+        let mut engine = Engine::new(key, nonce);
+        if forget {
+            engine.make_knot();
+        }
+        engine.get_tag(&mut []);
+        Self { engine, failed: false }
+    }
+
+    /// Encrypts `data` in place, authenticates it with the associated data `ad`, and returns the
+    /// tag.
+    ///
+    /// If `forget` is set, a knot is tied before the tag is computed, so that a later compromise
+    /// of the state does not reveal the earlier messages.
+    pub fn wrap(
+        &mut self,
+        data: &mut [u8],
+        ad: &[u8],
+        forget: bool,
+    ) -> Result<[u8; TAG_LEN], Error> {
+        let mut tag = [0u8; TAG_LEN];
+        self.wrap_inner(data, ad, false, forget, &mut tag)?;
+        Ok(tag)
+    }
+
+    /// Decrypts `data` in place and verifies its tag in constant time.
+    ///
+    /// `forget` must match the value given to [`wrap`](Self::wrap). On failure, the content of
+    /// `data` is unspecified and must not be used.
+    pub fn unwrap(
+        &mut self,
+        data: &mut [u8],
+        ad: &[u8],
+        tag: &[u8; TAG_LEN],
+        forget: bool,
+    ) -> Result<(), Error> {
+        let mut expected = [0u8; TAG_LEN];
+        self.wrap_inner(data, ad, true, forget, &mut expected)?;
+        let diff = expected.iter().zip(tag).fold(0, |acc, (a, b)| acc | (a ^ b));
+        if diff == 0 {
+            Ok(())
+        } else {
+            self.failed = true;
+            Err(Error)
+        }
+    }
+
+    fn wrap_inner(
+        &mut self,
+        data: &mut [u8],
+        mut ad: &[u8],
+        unwrap: bool,
+        forget: bool,
+        tag: &mut [u8; TAG_LEN],
+    ) -> Result<(), Error> {
+        if self.failed {
+            return Err(Error);
+        }
+        let engine = &mut self.engine;
+        let mut pos = 0;
+        if data.is_empty() && ad.is_empty() {
+            engine.inject(&mut ad);
+        }
+        while pos < data.len() {
+            engine.crypt(data, &mut pos, unwrap);
+            engine.inject(&mut ad);
+        }
+        while !ad.is_empty() {
+            engine.inject(&mut ad);
+        }
+        if forget {
+            engine.make_knot();
+        }
+        engine.get_tag(tag);
+        Ok(())
+    }
+}
+
+/// Lake Keyak with 128-bit keys and nonces, starting a new session for every message.
+///
+/// On decryption failure, the buffer is zeroed.
+#[derive(Clone)]
+pub struct LakeKeyak {
+    key: [u8; 16],
+}
+
+impl fmt::Debug for LakeKeyak {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LakeKeyak { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for LakeKeyak {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl KeySizeUser for LakeKeyak {
+    type KeySize = U16;
+}
+
+impl KeyInit for LakeKeyak {
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self { key: (*key).into() }
+    }
+}
+
+impl AeadCore for LakeKeyak {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for LakeKeyak {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        let mut session = LakeKeyakSession::new(&self.key, nonce, false);
+        session.wrap(buffer, associated_data, false).map(GenericArray::from)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        let mut session = LakeKeyakSession::new(&self.key, nonce, false);
+        let result = session.unwrap(buffer, associated_data, tag.as_ref(), false);
+        if result.is_err() {
+            buffer.fill(0);
+        }
+        result
+    }
+}
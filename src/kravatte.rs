@@ -0,0 +1,336 @@
+//! The [Kravatte](https://keccak.team/kravatte.html) deck function and its modes.
+//!
+//! Kravatte is [Farfalle](https://eprint.iacr.org/2016/1188) instantiated with
+//! Keccak-p\[1600, 6\] for all its permutations, and the rolling functions of Kravatte Achouffe.
+//! A deck function takes a sequence of strings and returns an arbitrarily long output that
+//! depends on all of them:
+//!
+//! - the key is expanded into the mask `k = p(K || 1 || 0*)`;
+//! - every string, with its frame bits, is padded with `1 || 0*` and cut into blocks `m_i`, which
+//!   are accumulated into `x += p(m_i + k_i)`, where the masks `k_i` are successive applications
+//!   of the rolling function `roll_c` to `k`, and one mask is skipped after every string;
+//! - the output blocks are `p(roll_e^j(p(x))) + k'`, where `k'` is the next mask.
+//!
+//! The modes are the session authenticated encryption [`KravatteSane`], and the tweakable
+//! wide-block cipher [`KravatteWbc`].
+
+use core::fmt;
+use sha3_asm::Buffer;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+pub use crate::strobe::AuthError;
+
+/// Number of rounds of the permutation.
+const ROUNDS: usize = 6;
+/// Width of the permutation, in bytes.
+const WIDTH: usize = 200;
+/// Length of the tags of [`KravatteSane`], in bytes.
+const TAG_LEN: usize = 16;
+
+/// Maximum length of keys, in bytes.
+pub const MAX_KEY_LEN: usize = WIDTH - 1;
+
+/// The rolling function of the compression masks, on the last 5 lanes.
+#[inline]
+fn roll_c(a: &mut Buffer) {
+    let x = a[20].rotate_left(7) ^ a[21] ^ (a[21] >> 3);
+    a.copy_within(21..25, 20);
+    a[24] = x;
+}
+
+/// The rolling function of the expansion, on the last 10 lanes.
+#[inline]
+fn roll_e(a: &mut Buffer) {
+    let x = a[15].rotate_left(7) ^ a[16].rotate_left(18) ^ (a[17] & (a[16] >> 1));
+    a.copy_within(16..25, 15);
+    a[24] = x;
+}
+
+#[inline]
+fn permute(a: &mut Buffer) {
+    sha3_asm::keccak_p1600(a, ROUNDS);
+}
+
+/// XORs `bytes` into the lanes of `a`, starting at the first byte.
+#[inline]
+fn xor_bytes(a: &mut Buffer, bytes: &[u8]) {
+    for (i, &byte) in bytes.iter().enumerate() {
+        a[i / 8] ^= (byte as u64) << (8 * (i % 8));
+    }
+}
+
+/// The Kravatte deck function, keyed and fed with a sequence of strings.
+///
+/// Compressing a string updates the state incrementally, so appending strings to a sequence does
+/// not recompute the earlier ones, and outputs can be read at any point with
+/// [`expand`](Self::expand).
+#[derive(Clone)]
+pub struct Kravatte {
+    /// The mask of the next block.
+    k: Buffer,
+    /// The accumulator.
+    x: Buffer,
+}
+
+impl fmt::Debug for Kravatte {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Kravatte { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Kravatte {
+    fn drop(&mut self) {
+        self.k.zeroize();
+        self.x.zeroize();
+    }
+}
+
+impl Kravatte {
+    /// Creates a deck function with the key `key`, of at most [`MAX_KEY_LEN`] bytes, and an
+    /// empty sequence of strings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is too long.
+    pub fn new(key: &[u8]) -> Self {
+        assert!(key.len() <= MAX_KEY_LEN, "Kravatte keys must be at most 199 bytes long");
+        let mut k = [0; 25];
+        xor_bytes(&mut k, key);
+        k[key.len() / 8] ^= 0x01 << (8 * (key.len() % 8));
+        permute(&mut k);
+        Self { k, x: [0; 25] }
+    }
+
+    /// Appends the string `data` to the sequence.
+    #[inline]
+    pub fn compress(&mut self, data: &[u8]) {
+        self.compress_framed(data, 0x01);
+    }
+
+    /// Appends the string `data` followed by frame bits to the sequence.
+    ///
+    /// `last` holds the frame bits followed by the first bit of the padding, starting from the
+    /// least significant bit, like the SHA-3 padding bytes.
+    fn compress_framed(&mut self, data: &[u8], last: u8) {
+        let mut blocks = data.chunks_exact(WIDTH);
+        for block in &mut blocks {
+            let mut a = self.k;
+            xor_bytes(&mut a, block);
+            self.accumulate(&mut a);
+        }
+        let rem = blocks.remainder();
+        let mut a = self.k;
+        xor_bytes(&mut a, rem);
+        a[rem.len() / 8] ^= (last as u64) << (8 * (rem.len() % 8));
+        self.accumulate(&mut a);
+        // Skip one mask after every string.
+        roll_c(&mut self.k);
+    }
+
+    #[inline]
+    fn accumulate(&mut self, a: &mut Buffer) {
+        permute(a);
+        self.x.iter_mut().zip(a.iter()).for_each(|(x, a)| *x ^= a);
+        roll_c(&mut self.k);
+
+        #[cfg(feature = "zeroize")]
+        a.zeroize();
+    }
+
+    /// Writes the output of the sequence so far to `out`, skipping its first `offset` bytes.
+    #[inline]
+    pub fn expand(&self, offset: usize, out: &mut [u8]) {
+        out.fill(0);
+        self.expand_xor(offset, out);
+    }
+
+    /// XORs the output of the sequence so far into `out`, skipping its first `offset` bytes.
+    pub fn expand_xor(&self, offset: usize, out: &mut [u8]) {
+        let mut y = self.x;
+        permute(&mut y);
+        for _ in 0..offset / WIDTH {
+            roll_e(&mut y);
+        }
+        let mut skip = offset % WIDTH;
+        let mut out = out;
+        while !out.is_empty() {
+            let mut z = y;
+            permute(&mut z);
+            z.iter_mut().zip(&self.k).for_each(|(z, k)| *z ^= k);
+            let n = out.len().min(WIDTH - skip);
+            let (head, tail) = out.split_at_mut(n);
+            for (i, byte) in head.iter_mut().enumerate() {
+                let pos = skip + i;
+                *byte ^= (z[pos / 8] >> (8 * (pos % 8))) as u8;
+            }
+            out = tail;
+            skip = 0;
+            roll_e(&mut y);
+
+            #[cfg(feature = "zeroize")]
+            z.zeroize();
+        }
+
+        #[cfg(feature = "zeroize")]
+        y.zeroize();
+    }
+}
+
+/// Kravatte-SANE: session authenticated encryption with a nonce.
+///
+/// Every tag authenticates the nonce and all the messages of the session so far. The history is
+/// compressed incrementally: associated data is framed with the bits `0 || e` and ciphertexts with
+/// `1 || e`, where `e` alternates between messages.
+///
+/// A failed [`unwrap`](Self::unwrap) ends the session: all later calls fail.
+#[derive(Clone, Debug)]
+pub struct KravatteSane {
+    deck: Kravatte,
+    e: bool,
+    failed: bool,
+}
+
+impl KravatteSane {
+    /// Starts a session with the key `key` and the nonce `nonce`, which must not be reused with
+    /// the same key, and returns it with the tag of the nonce.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is too long.
+    pub fn new(key: &[u8], nonce: &[u8]) -> (Self, [u8; TAG_LEN]) {
+        let mut deck = Kravatte::new(key);
+        deck.compress(nonce);
+        let mut tag = [0; TAG_LEN];
+        deck.expand(0, &mut tag);
+        (Self { deck, e: false, failed: false }, tag)
+    }
+
+    /// Encrypts `data` in place, authenticates it with the associated data `ad`, and returns the
+    /// tag.
+    pub fn wrap(&mut self, data: &mut [u8], ad: &[u8]) -> Result<[u8; TAG_LEN], AuthError> {
+        if self.failed {
+            return Err(AuthError);
+        }
+        self.deck.expand_xor(TAG_LEN, data);
+        Ok(self.update(data, ad))
+    }
+
+    /// Decrypts `data` in place and verifies its tag in constant time.
+    ///
+    /// On failure, `data` is zeroed.
+    pub fn unwrap(
+        &mut self,
+        data: &mut [u8],
+        ad: &[u8],
+        tag: &[u8; TAG_LEN],
+    ) -> Result<(), AuthError> {
+        if self.failed {
+            data.fill(0);
+            return Err(AuthError);
+        }
+        // The keystream depends on the history before this message, and the tag on the ciphertext.
+        let keystream = self.deck.clone();
+        let expected = self.update(data, ad);
+        keystream.expand_xor(TAG_LEN, data);
+        if expected.iter().zip(tag).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0 {
+            Ok(())
+        } else {
+            self.failed = true;
+            data.fill(0);
+            Err(AuthError)
+        }
+    }
+
+    /// Appends the associated data and the ciphertext to the history, and returns the tag.
+    fn update(&mut self, ciphertext: &[u8], ad: &[u8]) -> [u8; TAG_LEN] {
+        let e = (self.e as u8) << 1;
+        if !ad.is_empty() || ciphertext.is_empty() {
+            self.deck.compress_framed(ad, 0x04 | e);
+        }
+        if !ciphertext.is_empty() {
+            self.deck.compress_framed(ciphertext, 0x05 | e);
+        }
+        self.e = !self.e;
+        let mut tag = [0; TAG_LEN];
+        self.deck.expand(0, &mut tag);
+        tag
+    }
+}
+
+/// Kravatte-WBC: a tweakable wide-block cipher, enciphering messages of any length in place.
+///
+/// The message is split into a left part `L` and a right part `R`, which go through a four-round
+/// Feistel network, `R0 += H(L)`, `L += G(W, R)`, `R += G(W, L)`, `L0 += H(R)`, where `W` is the
+/// tweak, `H(X)` is the deck function of `X || 0` truncated to one block, `G(W, X)` is the deck
+/// function of `W` followed by `X || 1`, and `L0` and `R0` are the first block of `L` and `R`.
+///
+/// Short messages are split in halves. Longer messages are split so that `L` fills a power of two
+/// blocks of the compression.
+#[derive(Clone, Debug)]
+pub struct KravatteWbc {
+    deck: Kravatte,
+}
+
+impl KravatteWbc {
+    /// Creates a cipher with the key `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is too long.
+    #[inline]
+    pub fn new(key: &[u8]) -> Self {
+        Self { deck: Kravatte::new(key) }
+    }
+
+    /// Enciphers `data` in place with the tweak `tweak`.
+    pub fn encipher(&self, tweak: &[u8], data: &mut [u8]) {
+        let (l, r) = data.split_at_mut(split(data.len()));
+        self.h(l, r);
+        self.g(tweak, r, l);
+        self.g(tweak, l, r);
+        self.h(r, l);
+    }
+
+    /// Deciphers `data` in place with the tweak `tweak`.
+    pub fn decipher(&self, tweak: &[u8], data: &mut [u8]) {
+        let (l, r) = data.split_at_mut(split(data.len()));
+        self.h(r, l);
+        self.g(tweak, l, r);
+        self.g(tweak, r, l);
+        self.h(l, r);
+    }
+
+    /// `out0 += H(input)`.
+    fn h(&self, input: &[u8], out: &mut [u8]) {
+        let mut deck = self.deck.clone();
+        deck.compress_framed(input, 0x02);
+        let n = out.len().min(WIDTH);
+        deck.expand_xor(0, &mut out[..n]);
+    }
+
+    /// `out += G(tweak, input)`.
+    fn g(&self, tweak: &[u8], input: &[u8], out: &mut [u8]) {
+        let mut deck = self.deck.clone();
+        deck.compress(tweak);
+        deck.compress_framed(input, 0x03);
+        deck.expand_xor(0, out);
+    }
+}
+
+/// Returns the length of the left part of a message of `len` bytes.
+fn split(len: usize) -> usize {
+    // The left part and its frame byte fill a power of two blocks.
+    if len <= 2 * (WIDTH - 1) {
+        (len + 1) / 2
+    } else {
+        let q = (len + 2) / WIDTH;
+        let mut x = 1;
+        while x * 2 < q {
+            x *= 2;
+        }
+        x * WIDTH - 1
+    }
+}
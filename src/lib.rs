@@ -2,6 +2,12 @@
 #![no_std]
 #![warn(missing_docs, rust_2018_idioms)]
 #![allow(rustdoc::broken_intra_doc_links)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use digest::{self, Digest};
 
@@ -11,34 +17,114 @@ use digest::const_oid::{AssociatedOid, ObjectIdentifier};
 use digest::{
     block_buffer::Eager,
     consts::{U104, U136, U144, U28, U32, U48, U64, U72},
-    core_api::{AlgorithmName, BlockSizeUser, BufferKindUser},
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
+        UpdateCore,
+    },
     typenum::Unsigned,
-    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+    HashMarker, Output, OutputSizeUser, Reset,
 };
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 #[macro_use]
 mod macros;
 mod state;
 use state::Sha3State;
 
+pub mod bloom;
+
+mod eip191;
+pub use eip191::eip191_personal_message_hash;
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub mod drbg;
+
+pub mod duplex;
+
+pub mod ens;
+
+#[cfg(feature = "eip712")]
+#[cfg_attr(docsrs, doc(cfg(feature = "eip712")))]
+pub mod eip712;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod ethash;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod incremental_merkle;
+
+pub mod kdf;
+
+#[cfg(feature = "aead")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aead")))]
+pub mod keyak;
+
+pub mod kravatte;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod merkle;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod mmr;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod mpt;
+
+pub mod progpow;
+
+pub mod rlp;
+
+pub mod rlpx;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod solidity;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod sparse_merkle;
+
+pub mod starknet;
+
+pub mod storage;
+
+pub mod strobe;
+
+pub mod transcript;
+
 // Paddings
 const KECCAK: u8 = 0x01;
 const SHA3: u8 = 0x06;
 // const SHAKE: u8 = 0x1f;
 // const CSHAKE: u8 = 0x4;
 
-impl_sha3!(Keccak224, U28, U144, KECCAK, "Keccak-224");
-impl_sha3!(Keccak256, U32, U136, KECCAK, "Keccak-256");
-impl_sha3!(Keccak384, U48, U104, KECCAK, "Keccak-384");
-impl_sha3!(Keccak512, U64, U72, KECCAK, "Keccak-512");
+impl_sha3!(Keccak224, Keccak224Core, U28, U144, KECCAK, "Keccak-224");
+impl_sha3!(Keccak256, Keccak256Core, U32, U136, KECCAK, "Keccak-256");
+impl_sha3!(Keccak384, Keccak384Core, U48, U104, KECCAK, "Keccak-384");
+impl_sha3!(Keccak512, Keccak512Core, U64, U72, KECCAK, "Keccak-512");
 
 // TODO: Block size is not derived from bits
-// impl_sha3!(Keccak256Full, U200, U136, KECCAK, "SHA-3 CryptoNight variant");
+// impl_sha3!(Keccak256Full, Keccak256FullCore, U200, U136, KECCAK, "SHA-3 CryptoNight variant");
+
+impl_sha3!(Sha3_224, Sha3_224Core, U28, U144, SHA3, "SHA-3-224", "2.16.840.1.101.3.4.2.7");
+impl_sha3!(Sha3_256, Sha3_256Core, U32, U136, SHA3, "SHA-3-256", "2.16.840.1.101.3.4.2.8");
+impl_sha3!(Sha3_384, Sha3_384Core, U48, U104, SHA3, "SHA-3-384", "2.16.840.1.101.3.4.2.9");
+impl_sha3!(Sha3_512, Sha3_512Core, U64, U72, SHA3, "SHA-3-512", "2.16.840.1.101.3.4.2.10");
 
-impl_sha3!(Sha3_224, U28, U144, SHA3, "SHA-3-224", "2.16.840.1.101.3.4.2.7");
-impl_sha3!(Sha3_256, U32, U136, SHA3, "SHA-3-256", "2.16.840.1.101.3.4.2.8");
-impl_sha3!(Sha3_384, U48, U104, SHA3, "SHA-3-384", "2.16.840.1.101.3.4.2.9");
-impl_sha3!(Sha3_512, U64, U72, SHA3, "SHA-3-512", "2.16.840.1.101.3.4.2.10");
+/// Computes the Keccak-256 hash of `data` without going through a hasher's temporary buffer.
+#[inline]
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    unsafe { Sha3State::<256, KECCAK>::oneshot(data, out.as_mut_ptr()) };
+    out
+}
 
 #[doc(hidden)]
 pub use sha3_asm::IMPL;
@@ -1,85 +1,110 @@
 macro_rules! impl_sha3 {
-    ($name:ident, $output_size:ident, $rate:ident, $pad:expr, $alg_name:expr $(,)?) => {
-        #[doc = concat!($alg_name, " hasher state.")]
+    ($name:ident, $core:ident, $output_size:ident, $rate:ident, $pad:expr, $alg_name:expr $(,)?) => {
+        #[doc = concat!("Core ", $alg_name, " hasher state, absorbing whole blocks.")]
         #[derive(Clone)]
-        pub struct $name {
-            #[doc(hidden)]
+        pub struct $core {
             state: Sha3State<{ <$output_size>::USIZE * 8 }, $pad>,
         }
 
-        impl Default for $name {
+        impl Default for $core {
             #[inline]
             fn default() -> Self {
                 Self { state: Default::default() }
             }
         }
 
-        impl Reset for $name {
+        impl $core {
+            /// Returns the digest of the data absorbed so far and of the partial block in
+            /// `buffer`, without finalizing or resetting the hasher, which can keep absorbing
+            /// data afterwards.
+            ///
+            /// The lanes and the partial block are copied and padded on the stack. The core and
+            /// the buffer of a hasher are obtained with [`CoreWrapper::decompose`].
+            #[doc(alias = "current_digest")]
+            #[inline]
+            pub fn peek_finalize(&self, buffer: &Buffer<Self>) -> Output<Self> {
+                let mut out = Output::<Self>::default();
+                unsafe { self.state.peek(buffer.get_data(), out.as_mut_ptr()) };
+                out
+            }
+        }
+
+        impl Reset for $core {
             #[inline]
             fn reset(&mut self) {
                 self.state.reset();
             }
         }
 
-        impl AlgorithmName for $name {
+        impl AlgorithmName for $core {
             #[inline]
             fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.write_str(stringify!($name))
             }
         }
 
-        impl fmt::Debug for $name {
+        impl fmt::Debug for $core {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(concat!(stringify!($name), " { ... }"))
+                f.write_str(concat!(stringify!($core), " { ... }"))
             }
         }
 
-        impl HashMarker for $name {}
+        impl HashMarker for $core {}
 
-        impl BlockSizeUser for $name {
+        impl BlockSizeUser for $core {
             type BlockSize = $rate;
         }
 
-        impl BufferKindUser for $name {
+        impl BufferKindUser for $core {
             type BufferKind = Eager;
         }
 
-        impl OutputSizeUser for $name {
+        impl OutputSizeUser for $core {
             type OutputSize = $output_size;
         }
 
-        impl Update for $name {
+        impl UpdateCore for $core {
             #[inline]
-            fn update(&mut self, data: &[u8]) {
-                unsafe { self.state.update(data.as_ptr(), data.len()) }
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                let len = blocks.len() * <$rate>::USIZE;
+                unsafe { self.state.absorb_blocks(blocks.as_ptr().cast(), len) }
             }
         }
 
-        impl FixedOutput for $name {
+        impl FixedOutputCore for $core {
             #[inline]
-            fn finalize_into(mut self, out: &mut Output<Self>) {
-                unsafe { self.state.finalize(out.as_mut_ptr()) }
-            }
-        }
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                let pos = buffer.get_pos();
+                let block = buffer.pad_with_zeros();
+                unsafe { self.state.finalize(block.as_mut_ptr(), pos, out.as_mut_ptr()) };
 
-        impl FixedOutputReset for $name {
-            #[inline]
-            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
-                unsafe { self.state.finalize(out.as_mut_ptr()) };
-                Reset::reset(self);
+                #[cfg(feature = "zeroize")]
+                block.as_mut_slice().zeroize();
             }
         }
+
+        #[doc = concat!($alg_name, " hasher state.")]
+        ///
+        /// With the `zeroize` feature, the lanes are wiped on drop and the partial block on
+        /// finalization, but [`CoreWrapper`] does not wipe the partial block when dropped.
+        pub type $name = CoreWrapper<$core>;
     };
 
     (
-        $name:ident, $output_size:ident, $rate:ident, $pad:expr, $alg_name:expr, $oid:literal $(,)?
+        $name:ident,
+        $core:ident,
+        $output_size:ident,
+        $rate:ident,
+        $pad:expr,
+        $alg_name:expr,
+        $oid:literal $(,)?
     ) => {
-        impl_sha3!($name, $output_size, $rate, $pad, $alg_name);
+        impl_sha3!($name, $core, $output_size, $rate, $pad, $alg_name);
 
         #[cfg(feature = "oid")]
         #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
-        impl AssociatedOid for $name {
+        impl AssociatedOid for $core {
             const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap($oid);
         }
     };
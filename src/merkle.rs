@@ -0,0 +1,329 @@
+//! Keccak-256 Merkle trees compatible with OpenZeppelin's
+//! [`MerkleProof.sol`](https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/utils/cryptography/MerkleProof.sol)
+//! and [`@openzeppelin/merkle-tree`](https://github.com/OpenZeppelin/merkle-tree).
+//!
+//! Pairs of nodes are sorted before being hashed, so proofs do not need to encode the side of
+//! each sibling. Leaves of a [standard](MerkleTree::standard) tree are hashed twice to prevent
+//! second preimage attacks, in which an inner node is presented as a leaf.
+
+use crate::keccak256;
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::fmt;
+
+/// Minimum number of nodes in a level for it to be hashed in parallel.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// Merkle tree error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleTreeError {
+    /// The tree has no leaves.
+    Empty,
+    /// A leaf index is out of bounds.
+    IndexOutOfBounds(usize),
+    /// A leaf index is given more than once in a multiproof.
+    DuplicateIndex(usize),
+}
+
+impl fmt::Display for MerkleTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Merkle tree must have at least one leaf"),
+            Self::IndexOutOfBounds(index) => write!(f, "leaf index {index} is out of bounds"),
+            Self::DuplicateIndex(index) => write!(f, "leaf index {index} is duplicated"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MerkleTreeError {}
+
+/// Error returned when processing a malformed multiproof, the equivalent of
+/// `MerkleProofInvalidMultiproof` in `MerkleProof.sol`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidMultiProof;
+
+impl fmt::Display for InvalidMultiProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid Merkle multiproof")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidMultiProof {}
+
+/// Hashes a pair of nodes in sorted order: `keccak256(min(a, b) . max(a, b))`.
+#[inline]
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(lo);
+    buf[32..].copy_from_slice(hi);
+    keccak256(&buf)
+}
+
+/// Returns the leaf hash of a standard tree value: `keccak256(keccak256(encoded))`, where
+/// `encoded` is the `abi.encode` of the leaf values.
+#[inline]
+pub fn hash_leaf(encoded: &[u8]) -> [u8; 32] {
+    keccak256(&keccak256(encoded))
+}
+
+/// Returns the root reconstructed from `leaf` and its `proof`.
+pub fn process_proof(leaf: &[u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(*leaf, |node, sibling| hash_pair(&node, sibling))
+}
+
+/// Returns `true` if `proof` proves that `leaf` is in the tree with the given `root`.
+#[inline]
+pub fn verify(root: &[u8; 32], leaf: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    process_proof(leaf, proof) == *root
+}
+
+/// Returns the root reconstructed from `leaves` and a multiproof, as in `processMultiProof`.
+///
+/// `leaves` must be in the order returned by [`MerkleTree::multi_proof`]. Unlike the Solidity
+/// implementation, this never reads an inner node before it is computed.
+pub fn process_multi_proof(
+    leaves: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+) -> Result<[u8; 32], InvalidMultiProof> {
+    if leaves.len() + proof.len() != proof_flags.len() + 1 {
+        return Err(InvalidMultiProof);
+    }
+
+    let mut hashes = Vec::with_capacity(proof_flags.len());
+    let mut leaves = leaves.iter();
+    let mut proof = proof.iter();
+    let mut hash_pos = 0;
+    for &flag in proof_flags {
+        let mut next = |hashes: &Vec<[u8; 32]>| match leaves.next() {
+            Some(leaf) => Some(*leaf),
+            None => {
+                hash_pos += 1;
+                hashes.get(hash_pos - 1).copied()
+            }
+        };
+        let a = next(&hashes).ok_or(InvalidMultiProof)?;
+        let b = if flag { next(&hashes) } else { proof.next().copied() };
+        hashes.push(hash_pair(&a, &b.ok_or(InvalidMultiProof)?));
+    }
+
+    match hashes.last() {
+        Some(_) if proof.next().is_some() => Err(InvalidMultiProof),
+        Some(root) => Ok(*root),
+        None => Ok(*leaves.next().or_else(|| proof.next()).ok_or(InvalidMultiProof)?),
+    }
+}
+
+/// Returns `true` if the multiproof proves that all of `leaves` are in the tree with the given
+/// `root`.
+#[inline]
+pub fn verify_multi_proof(
+    root: &[u8; 32],
+    leaves: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+) -> bool {
+    process_multi_proof(leaves, proof, proof_flags) == Ok(*root)
+}
+
+/// A proof that several leaves are in a tree, verifiable with `multiProofVerify`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiProof {
+    /// The proven leaves, in the order expected by the proof.
+    pub leaves: Vec<[u8; 32]>,
+    /// The sibling nodes needed to reconstruct the root.
+    pub proof: Vec<[u8; 32]>,
+    /// Whether each hash combines two already known nodes, or a known node and the next node
+    /// of `proof`.
+    pub proof_flags: Vec<bool>,
+}
+
+impl MultiProof {
+    /// Returns the root reconstructed from the proof.
+    #[inline]
+    pub fn process(&self) -> Result<[u8; 32], InvalidMultiProof> {
+        process_multi_proof(&self.leaves, &self.proof, &self.proof_flags)
+    }
+
+    /// Returns `true` if the proof is valid for `root`.
+    #[inline]
+    pub fn verify(&self, root: &[u8; 32]) -> bool {
+        verify_multi_proof(root, &self.leaves, &self.proof, &self.proof_flags)
+    }
+}
+
+/// A complete binary Merkle tree with the same layout as `@openzeppelin/merkle-tree`.
+///
+/// The nodes are stored in a flat array with the root first, and the children of node `i` at
+/// `2i + 1` and `2i + 2`. Leaves are stored at the end of the array in reverse order, so that the
+/// trees of any number of leaves are as balanced as possible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    nodes: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    /// Builds a tree from leaf hashes, in the given order.
+    pub fn new(leaves: &[[u8; 32]]) -> Result<Self, MerkleTreeError> {
+        if leaves.is_empty() {
+            return Err(MerkleTreeError::Empty);
+        }
+
+        let len = 2 * leaves.len() - 1;
+        let mut nodes = vec![[0; 32]; len];
+        for (node, leaf) in nodes[leaves.len() - 1..].iter_mut().rev().zip(leaves) {
+            *node = *leaf;
+        }
+
+        // Hash the inner nodes one level at a time, from the deepest one. The children of a level
+        // all come after it, so the level can be borrowed mutably while its children are borrowed
+        // immutably.
+        let mut end = leaves.len() - 1;
+        while end > 0 {
+            let start = (1 << (usize::BITS - 1 - end.leading_zeros())) - 1;
+            let (parents, children) = nodes.split_at_mut(2 * start + 1);
+            hash_level(&mut parents[start..end], &children[..2 * (end - start)]);
+            end = start;
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Builds a tree from leaf hashes, sorted in ascending order, like `SimpleMerkleTree.of`.
+    pub fn sorted(leaves: &[[u8; 32]]) -> Result<Self, MerkleTreeError> {
+        let mut leaves = leaves.to_vec();
+        leaves.sort_unstable();
+        Self::new(&leaves)
+    }
+
+    /// Builds a tree from the `abi.encode`d values of the leaves, like `StandardMerkleTree.of`.
+    ///
+    /// Leaves are hashed with [`hash_leaf`] and sorted, so use [`position`](Self::position) to
+    /// find the index of a leaf.
+    pub fn standard<V: AsRef<[u8]>>(values: &[V]) -> Result<Self, MerkleTreeError> {
+        let mut leaves: Vec<_> = values.iter().map(|value| hash_leaf(value.as_ref())).collect();
+        leaves.sort_unstable();
+        Self::new(&leaves)
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> [u8; 32] {
+        self.nodes[0]
+    }
+
+    /// Returns the number of leaves.
+    #[inline]
+    pub fn num_leaves(&self) -> usize {
+        (self.nodes.len() + 1) / 2
+    }
+
+    /// Returns the leaf at `index`.
+    #[inline]
+    pub fn leaf(&self, index: usize) -> Option<&[u8; 32]> {
+        self.tree_index(index).ok().map(|i| &self.nodes[i])
+    }
+
+    /// Returns an iterator over the leaves, in order.
+    #[inline]
+    pub fn leaves(&self) -> impl DoubleEndedIterator<Item = &[u8; 32]> + ExactSizeIterator + '_ {
+        self.nodes[self.num_leaves() - 1..].iter().rev()
+    }
+
+    /// Returns the index of `leaf`, if it is in the tree.
+    #[inline]
+    pub fn position(&self, leaf: &[u8; 32]) -> Option<usize> {
+        self.leaves().position(|l| l == leaf)
+    }
+
+    /// Returns the proof of the leaf at `index`, from the leaf's sibling up to the root's
+    /// children.
+    pub fn proof(&self, index: usize) -> Result<Vec<[u8; 32]>, MerkleTreeError> {
+        let mut i = self.tree_index(index)?;
+        let mut proof = Vec::with_capacity(usize::BITS as usize - i.leading_zeros() as usize);
+        while i > 0 {
+            proof.push(self.nodes[sibling(i)]);
+            i = parent(i);
+        }
+        Ok(proof)
+    }
+
+    /// Returns a proof that all the leaves at `indices` are in the tree.
+    pub fn multi_proof(&self, indices: &[usize]) -> Result<MultiProof, MerkleTreeError> {
+        let mut tree_indices =
+            indices.iter().map(|&index| self.tree_index(index)).collect::<Result<Vec<_>, _>>()?;
+        tree_indices.sort_unstable_by(|a, b| b.cmp(a));
+        if let Some(w) = tree_indices.windows(2).find(|w| w[0] == w[1]) {
+            return Err(MerkleTreeError::DuplicateIndex(self.nodes.len() - 1 - w[0]));
+        }
+
+        let leaves = tree_indices.iter().map(|&i| self.nodes[i]).collect();
+        let mut proof = Vec::new();
+        let mut proof_flags = Vec::new();
+        let mut stack = VecDeque::from(tree_indices);
+        while let Some(&i) = stack.front() {
+            if i == 0 {
+                break;
+            }
+            stack.pop_front();
+            let s = sibling(i);
+            if stack.front() == Some(&s) {
+                proof_flags.push(true);
+                stack.pop_front();
+            } else {
+                proof_flags.push(false);
+                proof.push(self.nodes[s]);
+            }
+            stack.push_back(parent(i));
+        }
+        if indices.is_empty() {
+            proof.push(self.root());
+        }
+
+        Ok(MultiProof { leaves, proof, proof_flags })
+    }
+
+    /// Returns the index in `nodes` of the leaf at `index`.
+    #[inline]
+    fn tree_index(&self, index: usize) -> Result<usize, MerkleTreeError> {
+        if index < self.num_leaves() {
+            Ok(self.nodes.len() - 1 - index)
+        } else {
+            Err(MerkleTreeError::IndexOutOfBounds(index))
+        }
+    }
+}
+
+#[inline]
+fn sibling(i: usize) -> usize {
+    if i % 2 == 1 {
+        i + 1
+    } else {
+        i - 1
+    }
+}
+
+#[inline]
+fn parent(i: usize) -> usize {
+    (i - 1) / 2
+}
+
+/// Hashes each pair of `children` into the corresponding node of `parents`.
+fn hash_level(parents: &mut [[u8; 32]], children: &[[u8; 32]]) {
+    #[cfg(feature = "rayon")]
+    if parents.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        parents
+            .par_iter_mut()
+            .zip(children.par_chunks_exact(2))
+            .for_each(|(parent, pair)| *parent = hash_pair(&pair[0], &pair[1]));
+        return;
+    }
+
+    for (parent, pair) in parents.iter_mut().zip(children.chunks_exact(2)) {
+        *parent = hash_pair(&pair[0], &pair[1]);
+    }
+}
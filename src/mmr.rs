@@ -0,0 +1,444 @@
+//! Merkle Mountain Range: an append-only accumulator made of perfect binary trees, the peaks.
+//!
+//! Nodes are numbered from zero in the order in which they are appended, i.e. in post-order, and
+//! the size of an MMR is its number of nodes. Parents are hashed as `keccak256(left . right)`, and
+//! the root is obtained by bagging the peaks from right to left:
+//! `keccak256(peak_0 . keccak256(peak_1 . ... peak_n))`.
+//!
+//! Nodes are kept in an [`MmrStore`], which can be backed by memory, e.g. a `Vec`, or by a
+//! database.
+
+use crate::keccak256;
+use alloc::vec::Vec;
+use core::{convert::Infallible, fmt};
+
+/// Storage of the nodes of an [`Mmr`].
+pub trait MmrStore {
+    /// The error returned by the store.
+    type Error;
+
+    /// Returns the node at `pos`, or `None` if it is not stored.
+    fn get(&self, pos: u64) -> Result<Option<[u8; 32]>, Self::Error>;
+
+    /// Stores `nodes` at the consecutive positions starting at `pos`, which is the current size
+    /// of the MMR.
+    fn append(&mut self, pos: u64, nodes: &[[u8; 32]]) -> Result<(), Self::Error>;
+}
+
+/// In-memory store, where the node at `pos` is at index `pos`.
+impl MmrStore for Vec<[u8; 32]> {
+    type Error = Infallible;
+
+    #[inline]
+    fn get(&self, pos: u64) -> Result<Option<[u8; 32]>, Self::Error> {
+        Ok(usize::try_from(pos).ok().and_then(|pos| self.as_slice().get(pos)).copied())
+    }
+
+    #[inline]
+    fn append(&mut self, pos: u64, nodes: &[[u8; 32]]) -> Result<(), Self::Error> {
+        debug_assert_eq!(pos, self.len() as u64);
+        self.extend_from_slice(nodes);
+        Ok(())
+    }
+}
+
+/// MMR error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmrError<E> {
+    /// The store returned an error.
+    Store(E),
+    /// A node is missing from the store.
+    MissingNode(u64),
+    /// The size is not the size of an MMR, or is out of bounds.
+    InvalidSize(u64),
+    /// The leaf index is out of bounds.
+    LeafOutOfBounds(u64),
+    /// The MMR is empty, and has no root.
+    Empty,
+}
+
+impl<E: fmt::Display> fmt::Display for MmrError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Store(e) => write!(f, "MMR store error: {e}"),
+            Self::MissingNode(pos) => write!(f, "MMR node {pos} is missing from the store"),
+            Self::InvalidSize(size) => write!(f, "invalid MMR size: {size}"),
+            Self::LeafOutOfBounds(index) => write!(f, "MMR leaf index {index} is out of bounds"),
+            Self::Empty => f.write_str("MMR is empty"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for MmrError<E> {}
+
+/// A proof that a leaf is in an MMR.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InclusionProof {
+    /// The size of the MMR.
+    pub mmr_size: u64,
+    /// The index of the leaf.
+    pub leaf_index: u64,
+    /// The siblings on the path from the leaf to its peak.
+    pub path: Vec<[u8; 32]>,
+    /// The other peaks, in order.
+    pub peaks: Vec<[u8; 32]>,
+}
+
+impl InclusionProof {
+    /// Returns `true` if the proof shows that `leaf` is in the MMR with the given `root`.
+    pub fn verify(&self, root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+        let peak_positions = match peaks(self.mmr_size) {
+            Some(peaks) => peaks,
+            None => return false,
+        };
+        if self.leaf_index >= leaf_count(&peak_positions)
+            || self.path.len() > MAX_PATH_LEN
+            || self.peaks.len() + 1 != peak_positions.len()
+        {
+            return false;
+        }
+        let pos = match leaf_index_to_pos(self.leaf_index) {
+            Some(pos) => pos,
+            None => return false,
+        };
+        let (peak_pos, peak) = match climb(pos, *leaf, &self.path) {
+            Some(peak) => peak,
+            None => return false,
+        };
+        let index = match peak_positions.iter().position(|&p| p == peak_pos) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let mut all = self.peaks.clone();
+        all.insert(index, peak);
+        bag_peaks(&all) == Some(*root)
+    }
+}
+
+/// A proof that an MMR is an extension of an older, smaller MMR.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsistencyProof {
+    /// The size of the old MMR.
+    pub old_size: u64,
+    /// The size of the new MMR.
+    pub new_size: u64,
+    /// The peaks of the old MMR.
+    pub old_peaks: Vec<[u8; 32]>,
+    /// For each old peak, the siblings on the path to the new peak which contains it.
+    pub paths: Vec<Vec<[u8; 32]>>,
+    /// The new peaks which contain no old peak, in order.
+    pub new_peaks: Vec<[u8; 32]>,
+}
+
+impl ConsistencyProof {
+    /// Returns `true` if the proof shows that the MMR with `new_root` extends the MMR with
+    /// `old_root`.
+    pub fn verify(&self, old_root: &[u8; 32], new_root: &[u8; 32]) -> bool {
+        let (old_positions, new_positions) = match (peaks(self.old_size), peaks(self.new_size)) {
+            (Some(old), Some(new)) if self.old_size <= self.new_size => (old, new),
+            _ => return false,
+        };
+        if self.old_peaks.len() != old_positions.len()
+            || self.paths.len() != old_positions.len()
+            || self.paths.iter().any(|path| path.len() > MAX_PATH_LEN)
+            || bag_peaks(&self.old_peaks) != Some(*old_root)
+        {
+            return false;
+        }
+
+        // The new peaks, from either the old peaks or the proof.
+        let mut new_peaks: Vec<Option<[u8; 32]>> = alloc::vec![None; new_positions.len()];
+        for ((&pos, peak), path) in old_positions.iter().zip(&self.old_peaks).zip(&self.paths) {
+            let (peak_pos, peak) = match climb(pos, *peak, path) {
+                Some(peak) => peak,
+                None => return false,
+            };
+            let index = match new_positions.iter().position(|&p| p == peak_pos) {
+                Some(index) => index,
+                None => return false,
+            };
+            match new_peaks[index] {
+                Some(other) if other != peak => return false,
+                _ => new_peaks[index] = Some(peak),
+            }
+        }
+        let mut extra = self.new_peaks.iter();
+        let new_peaks = new_peaks.into_iter().map(|peak| peak.or_else(|| extra.next().copied()));
+        let new_peaks = match new_peaks.collect::<Option<Vec<_>>>() {
+            Some(peaks) if extra.next().is_none() => peaks,
+            _ => return false,
+        };
+        bag_peaks(&new_peaks) == Some(*new_root)
+    }
+}
+
+/// Siblings on the path from a node to its peak.
+type Path = Vec<[u8; 32]>;
+
+/// Merkle Mountain Range over a node store.
+#[derive(Clone, Debug, Default)]
+pub struct Mmr<S> {
+    store: S,
+    size: u64,
+}
+
+impl<S: MmrStore> Mmr<S> {
+    /// Creates an empty MMR.
+    #[inline]
+    pub fn new(store: S) -> Self {
+        Self { store, size: 0 }
+    }
+
+    /// Opens an MMR of `size` nodes, all of which are in `store`.
+    pub fn with_size(store: S, size: u64) -> Result<Self, MmrError<S::Error>> {
+        if peaks(size).is_none() {
+            return Err(MmrError::InvalidSize(size));
+        }
+        Ok(Self { store, size })
+    }
+
+    /// Returns the number of nodes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the number of leaves.
+    #[inline]
+    pub fn leaf_count(&self) -> u64 {
+        leaf_count(&peaks(self.size).unwrap_or_default())
+    }
+
+    /// Returns a reference to the store.
+    #[inline]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Consumes the MMR, returning the store.
+    #[inline]
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Appends a leaf and the parents it completes, returning the position of the leaf.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<u64, MmrError<S::Error>> {
+        let leaf_pos = self.size;
+        let mut nodes = Vec::with_capacity(1);
+        nodes.push(leaf);
+        let mut pos = leaf_pos;
+        let mut height = 0;
+        let mut node = leaf;
+        while pos_height(pos + 1) > height {
+            let left = self.node(pos - sibling_offset(height).expect("MMR height overflow"))?;
+            node = hash_node(&left, &node);
+            nodes.push(node);
+            pos += 1;
+            height += 1;
+        }
+        self.store.append(leaf_pos, &nodes).map_err(MmrError::Store)?;
+        self.size = pos + 1;
+        Ok(leaf_pos)
+    }
+
+    /// Returns the peaks.
+    pub fn peaks(&self) -> Result<Vec<[u8; 32]>, MmrError<S::Error>> {
+        self.peaks_at(self.size)
+    }
+
+    /// Returns the root, the bagged peaks.
+    pub fn root(&self) -> Result<[u8; 32], MmrError<S::Error>> {
+        bag_peaks(&self.peaks()?).ok_or(MmrError::Empty)
+    }
+
+    /// Returns the root of the MMR when it had `size` nodes.
+    pub fn root_at(&self, size: u64) -> Result<[u8; 32], MmrError<S::Error>> {
+        bag_peaks(&self.peaks_at(size)?).ok_or(MmrError::Empty)
+    }
+
+    /// Returns a proof that the leaf at `leaf_index` is in the MMR.
+    #[inline]
+    pub fn inclusion_proof(&self, leaf_index: u64) -> Result<InclusionProof, MmrError<S::Error>> {
+        self.inclusion_proof_at(leaf_index, self.size)
+    }
+
+    /// Returns a proof that the leaf at `leaf_index` is in the MMR when it had `size` nodes.
+    pub fn inclusion_proof_at(
+        &self,
+        leaf_index: u64,
+        size: u64,
+    ) -> Result<InclusionProof, MmrError<S::Error>> {
+        let peak_positions = self.peak_positions(size)?;
+        let pos = match leaf_index_to_pos(leaf_index) {
+            Some(pos) if leaf_index < leaf_count(&peak_positions) => pos,
+            _ => return Err(MmrError::LeafOutOfBounds(leaf_index)),
+        };
+        let (peak_pos, path) = self.path(pos, &peak_positions)?;
+        let peaks = peak_positions
+            .iter()
+            .filter(|&&p| p != peak_pos)
+            .map(|&p| self.node(p))
+            .collect::<Result<_, _>>()?;
+        Ok(InclusionProof { mmr_size: size, leaf_index, path, peaks })
+    }
+
+    /// Returns a proof that the MMR extends the MMR it was when it had `old_size` nodes.
+    pub fn consistency_proof(&self, old_size: u64) -> Result<ConsistencyProof, MmrError<S::Error>> {
+        let old_positions = self.peak_positions(old_size)?;
+        let new_positions = self.peak_positions(self.size)?;
+
+        let mut old_peaks = Vec::with_capacity(old_positions.len());
+        let mut paths = Vec::with_capacity(old_positions.len());
+        let mut covered = Vec::with_capacity(old_positions.len());
+        for &pos in &old_positions {
+            old_peaks.push(self.node(pos)?);
+            let (peak_pos, path) = self.path(pos, &new_positions)?;
+            paths.push(path);
+            covered.push(peak_pos);
+        }
+        let new_peaks = new_positions
+            .iter()
+            .filter(|&&pos| !covered.contains(&pos))
+            .map(|&pos| self.node(pos))
+            .collect::<Result<_, _>>()?;
+
+        Ok(ConsistencyProof { old_size, new_size: self.size, old_peaks, paths, new_peaks })
+    }
+
+    /// Returns the position of the peak containing `pos` and the siblings on the path to it.
+    fn path(
+        &self,
+        mut pos: u64,
+        peak_positions: &[u64],
+    ) -> Result<(u64, Path), MmrError<S::Error>> {
+        let mut path = Vec::new();
+        let mut height = pos_height(pos);
+        while !peak_positions.contains(&pos) {
+            // Nodes below a stored peak have a height lower than 63.
+            let offset = sibling_offset(height).expect("MMR height overflow");
+            if pos_height(pos + 1) > height {
+                // Right child.
+                path.push(self.node(pos - offset)?);
+                pos += 1;
+            } else {
+                path.push(self.node(pos + offset)?);
+                pos += offset + 1;
+            }
+            height += 1;
+        }
+        Ok((pos, path))
+    }
+
+    fn peaks_at(&self, size: u64) -> Result<Vec<[u8; 32]>, MmrError<S::Error>> {
+        self.peak_positions(size)?.into_iter().map(|pos| self.node(pos)).collect()
+    }
+
+    fn peak_positions(&self, size: u64) -> Result<Vec<u64>, MmrError<S::Error>> {
+        match peaks(size) {
+            Some(peaks) if size <= self.size => Ok(peaks),
+            _ => Err(MmrError::InvalidSize(size)),
+        }
+    }
+
+    fn node(&self, pos: u64) -> Result<[u8; 32], MmrError<S::Error>> {
+        self.store.get(pos).map_err(MmrError::Store)?.ok_or(MmrError::MissingNode(pos))
+    }
+}
+
+/// The maximum length of the path from a leaf to its peak, as peaks are lower than 64.
+const MAX_PATH_LEN: usize = 63;
+
+/// Returns the position of the leaf at `index`, or `None` if it does not fit in a `u64`.
+#[inline]
+pub fn leaf_index_to_pos(index: u64) -> Option<u64> {
+    index.checked_mul(2)?.checked_sub(index.count_ones() as u64)
+}
+
+/// Returns the size of an MMR with `leaf_count` leaves, or `None` if it does not fit in a `u64`.
+#[inline]
+pub fn mmr_size(leaf_count: u64) -> Option<u64> {
+    leaf_count.checked_mul(2)?.checked_sub(leaf_count.count_ones() as u64)
+}
+
+/// Returns the number of leaves under the peaks at `peak_positions`.
+fn leaf_count(peak_positions: &[u64]) -> u64 {
+    // Each peak of height `h` holds `2^h` leaves in `2^(h + 1) - 1` nodes.
+    peak_positions.iter().map(|&pos| 1 << pos_height(pos)).sum()
+}
+
+/// Bags the peaks into the root, or returns `None` if there are no peaks.
+pub fn bag_peaks(peaks: &[[u8; 32]]) -> Option<[u8; 32]> {
+    let (last, rest) = peaks.split_last()?;
+    Some(rest.iter().rev().fold(*last, |acc, peak| hash_node(peak, &acc)))
+}
+
+/// Returns the positions of the peaks of an MMR of `size` nodes, or `None` if `size` is not the
+/// size of an MMR.
+fn peaks(size: u64) -> Option<Vec<u64>> {
+    let mut peaks = Vec::new();
+    let mut left = size;
+    let mut offset = 0;
+    let mut prev_height = u32::MAX;
+    while left > 0 {
+        // The highest perfect tree which fits in the remaining nodes.
+        let height = 62 - left.checked_add(1)?.leading_zeros();
+        if height >= prev_height {
+            return None;
+        }
+        let tree_size = (2 << height) - 1;
+        peaks.push(offset + tree_size - 1);
+        offset += tree_size;
+        left -= tree_size;
+        prev_height = height;
+    }
+    Some(peaks)
+}
+
+/// Returns the height of the node at `pos`, where leaves have height zero.
+fn pos_height(pos: u64) -> u32 {
+    // Positions of the leftmost nodes of each height are `2^(h + 1) - 2`. Jump left to the
+    // leftmost node of the same height by subtracting the size of the perfect trees before it.
+    let mut n = pos + 1;
+    while n & (n + 1) != 0 {
+        n -= (1 << (63 - n.leading_zeros())) - 1;
+    }
+    63 - n.leading_zeros()
+}
+
+/// Returns the distance between the siblings of height `height`, or `None` if it does not fit in
+/// a `u64`.
+#[inline]
+fn sibling_offset(height: u32) -> Option<u64> {
+    if height < 63 {
+        Some((2 << height) - 1)
+    } else {
+        None
+    }
+}
+
+/// Returns the position and hash of the peak reached from the node at `pos` through `path`, or
+/// `None` if the position overflows.
+fn climb(mut pos: u64, mut node: [u8; 32], path: &[[u8; 32]]) -> Option<(u64, [u8; 32])> {
+    for (height, sibling) in (pos_height(pos)..).zip(path) {
+        let offset = sibling_offset(height)?;
+        let next = pos.checked_add(1)?;
+        if pos_height(next) > height {
+            node = hash_node(sibling, &node);
+            pos = next;
+        } else {
+            node = hash_node(&node, sibling);
+            pos = next.checked_add(offset)?;
+        }
+    }
+    Some((pos, node))
+}
+
+#[inline]
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    keccak256(&buf)
+}
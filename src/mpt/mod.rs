@@ -0,0 +1,181 @@
+//! Ethereum [Merkle Patricia Trie](https://ethereum.org/en/developers/docs/data-structures-and-encoding/patricia-merkle-trie/)
+//! root computation and proof verification.
+
+use crate::keccak256;
+use alloc::vec::Vec;
+
+mod proof;
+pub use proof::{
+    verify_account_proof, verify_proof, verify_storage_proof, ProofError, TrieAccount,
+};
+
+mod rlp;
+
+/// Root hash of an empty trie, `keccak256(rlp(""))`.
+pub const EMPTY_ROOT_HASH: [u8; 32] = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+/// Computes the root of the trie containing the given key/value pairs.
+///
+/// Values are stored as is, so they should already be RLP-encoded where the trie requires it,
+/// e.g. for accounts or storage values.
+///
+/// # Panics
+///
+/// Panics if the keys are not sorted in strictly ascending order.
+pub fn trie_root<I, K, V>(input: I) -> [u8; 32]
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+{
+    let input = input.into_iter().collect::<Vec<_>>();
+    let items = input.iter().map(|(k, v)| (k.as_ref(), v.as_ref())).collect::<Vec<_>>();
+    assert!(items.windows(2).all(|w| w[0].0 < w[1].0), "trie keys must be sorted and unique");
+    root(&items)
+}
+
+/// Computes the root of a "secure" trie, in which each key is hashed with Keccak-256 before
+/// insertion, as used for the state and storage tries.
+///
+/// The input does not need to be sorted.
+///
+/// # Panics
+///
+/// Panics if a key is present more than once.
+pub fn sec_trie_root<I, K, V>(input: I) -> [u8; 32]
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<[u8]>,
+    V: AsRef<[u8]>,
+{
+    let mut input = input.into_iter().map(|(k, v)| (keccak256(k.as_ref()), v)).collect::<Vec<_>>();
+    input.sort_unstable_by_key(|(key, _)| *key);
+    trie_root(input)
+}
+
+/// Computes the root of the trie mapping `rlp(index)` to each item, as used for the transactions,
+/// receipts and withdrawals roots in block headers.
+///
+/// Items are stored as is, so they should already be encoded, e.g. as EIP-2718 envelopes.
+pub fn ordered_trie_root<I>(input: I) -> [u8; 32]
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut input = input
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let mut key = Vec::with_capacity(9);
+            rlp::encode_uint(i as u64, &mut key);
+            (key, v)
+        })
+        .collect::<Vec<_>>();
+    // `rlp(0) = 0x80` sorts after `rlp(1..=127)`.
+    input.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    trie_root(input)
+}
+
+type Item<'a> = (&'a [u8], &'a [u8]);
+
+fn root(items: &[Item<'_>]) -> [u8; 32] {
+    if items.is_empty() {
+        return EMPTY_ROOT_HASH;
+    }
+    keccak256(&encode_node(items, 0))
+}
+
+/// RLP-encodes the node holding `items`, all of which share their first `depth` nibbles.
+fn encode_node(items: &[Item<'_>], depth: usize) -> Vec<u8> {
+    let mut payload = Vec::new();
+
+    if let [(key, value)] = items {
+        // Leaf.
+        encode_hex_prefix(key, depth, nibble_len(key), true, &mut payload);
+        rlp::encode_bytes(value, &mut payload);
+        return rlp::wrap_list(&payload);
+    }
+
+    let first = items[0].0;
+    let last = items[items.len() - 1].0;
+    let shared = common_prefix_len(first, last, depth);
+    if shared > depth {
+        // Extension.
+        encode_hex_prefix(first, depth, shared, false, &mut payload);
+        encode_reference(&encode_node(items, shared), &mut payload);
+        return rlp::wrap_list(&payload);
+    }
+
+    // Branch. Since items are sorted, a key ending at this node can only be the first one.
+    let mut rest = items;
+    let mut value: &[u8] = &[];
+    if nibble_len(rest[0].0) == depth {
+        value = rest[0].1;
+        rest = &rest[1..];
+    }
+    for n in 0..16 {
+        let count = rest.iter().take_while(|(key, _)| nibble_at(key, depth) == n).count();
+        if count == 0 {
+            payload.push(rlp::EMPTY_STRING_CODE);
+        } else {
+            encode_reference(&encode_node(&rest[..count], depth + 1), &mut payload);
+            rest = &rest[count..];
+        }
+    }
+    rlp::encode_bytes(value, &mut payload);
+    rlp::wrap_list(&payload)
+}
+
+/// Appends a reference to a child node: the node itself if its encoding is shorter than 32
+/// bytes, its hash otherwise.
+fn encode_reference(node: &[u8], out: &mut Vec<u8>) {
+    if node.len() < 32 {
+        out.extend_from_slice(node);
+    } else {
+        rlp::encode_bytes(&keccak256(node), out);
+    }
+}
+
+/// Appends the RLP string of the hex-prefix encoding of the nibbles of `key` in `from..to`.
+fn encode_hex_prefix(key: &[u8], from: usize, to: usize, leaf: bool, out: &mut Vec<u8>) {
+    let len = to - from;
+    let odd = len % 2 == 1;
+    let flag = ((leaf as u8) << 1 | odd as u8) << 4;
+
+    let mut encoded = Vec::with_capacity(len / 2 + 1);
+    let mut i = from;
+    if odd {
+        encoded.push(flag | nibble_at(key, i));
+        i += 1;
+    } else {
+        encoded.push(flag);
+    }
+    while i < to {
+        encoded.push(nibble_at(key, i) << 4 | nibble_at(key, i + 1));
+        i += 2;
+    }
+    rlp::encode_bytes(&encoded, out);
+}
+
+#[inline]
+fn nibble_len(key: &[u8]) -> usize {
+    key.len() * 2
+}
+
+#[inline]
+fn nibble_at(key: &[u8], i: usize) -> u8 {
+    let byte = key[i / 2];
+    if i % 2 == 0 {
+        byte >> 4
+    } else {
+        byte & 0x0f
+    }
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8], from: usize) -> usize {
+    let max = nibble_len(a).min(nibble_len(b));
+    (from..max).find(|&i| nibble_at(a, i) != nibble_at(b, i)).unwrap_or(max)
+}
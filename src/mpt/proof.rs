@@ -0,0 +1,267 @@
+//! Merkle proof verification, e.g. for proofs returned by `eth_getProof`.
+
+use super::{nibble_at, nibble_len, rlp, rlp::Item, EMPTY_ROOT_HASH};
+use crate::keccak256;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Merkle proof verification error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// A proof node, or a node embedded in it, is not a valid trie node.
+    MalformedNode {
+        /// The index of the proof node.
+        index: usize,
+        /// Why the node was rejected.
+        reason: &'static str,
+    },
+    /// The hash of a proof node does not match the reference to it in its parent node, or the
+    /// root hash.
+    HashMismatch {
+        /// The index of the proof node.
+        index: usize,
+        /// The hash referenced by the parent node.
+        expected: [u8; 32],
+        /// The hash of the proof node.
+        found: [u8; 32],
+    },
+    /// The proof ends before the path of the key is resolved.
+    MissingNode {
+        /// The index of the missing proof node.
+        index: usize,
+    },
+    /// The proof continues past the node at which the path of the key terminates.
+    PathDivergence {
+        /// The index of the first extraneous proof node.
+        index: usize,
+    },
+    /// The proven value is not a valid account or storage value.
+    MalformedValue(&'static str),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedNode { index, reason } => {
+                write!(f, "malformed proof node {index}: {reason}")
+            }
+            Self::HashMismatch { index, .. } => {
+                write!(f, "hash of proof node {index} does not match its reference")
+            }
+            Self::MissingNode { index } => write!(f, "proof is missing node {index}"),
+            Self::PathDivergence { index } => {
+                write!(f, "proof node {index} is past the end of the key's path")
+            }
+            Self::MalformedValue(reason) => write!(f, "malformed proven value: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProofError {}
+
+/// An account as stored in the state trie.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TrieAccount {
+    /// The account nonce.
+    pub nonce: u64,
+    /// The account balance, as a 256-bit big-endian integer.
+    pub balance: [u8; 32],
+    /// The root of the account's storage trie.
+    pub storage_root: [u8; 32],
+    /// The hash of the account's code.
+    pub code_hash: [u8; 32],
+}
+
+/// Reference to the next node on the path.
+enum NodeRef<'a> {
+    Hash([u8; 32]),
+    Inline(&'a [u8]),
+    Empty,
+}
+
+/// Verifies a Merkle proof of `key` against the trie `root`.
+///
+/// `proof` contains the RLP-encoded nodes on the path of `key`, starting from the root node.
+/// Nodes shorter than 32 bytes which are embedded in their parent are not part of the proof.
+///
+/// Returns the value stored at `key`, or `None` if the proof shows that `key` is not in the trie.
+pub fn verify_proof<P: AsRef<[u8]>>(
+    root: &[u8; 32],
+    key: &[u8],
+    proof: &[P],
+) -> Result<Option<Vec<u8>>, ProofError> {
+    // The empty trie has no nodes to prove.
+    if *root == EMPTY_ROOT_HASH && proof.is_empty() {
+        return Ok(None);
+    }
+
+    let mut nodes = proof.iter().map(AsRef::as_ref).enumerate().peekable();
+    let key_len = nibble_len(key);
+    let mut pos = 0;
+    let mut index = 0;
+    let mut next = NodeRef::Hash(*root);
+
+    let value = loop {
+        let node = match next {
+            NodeRef::Hash(expected) => {
+                let (i, node) =
+                    nodes.next().ok_or(ProofError::MissingNode { index: proof.len() })?;
+                index = i;
+                let found = keccak256(node);
+                if found != expected {
+                    return Err(ProofError::HashMismatch { index, expected, found });
+                }
+                node
+            }
+            NodeRef::Inline(node) => node,
+            NodeRef::Empty => break None,
+        };
+
+        // The root node of the empty trie.
+        if *root == EMPTY_ROOT_HASH && node == [rlp::EMPTY_STRING_CODE] {
+            break None;
+        }
+
+        let malformed = |reason| ProofError::MalformedNode { index, reason };
+        let items = rlp::decode_list(node).map_err(malformed)?;
+        match *items.as_slice() {
+            [path, value] => {
+                let path = match path {
+                    Item::Bytes(path) => path,
+                    Item::List(_) => return Err(malformed("node path must be a string")),
+                };
+                let (leaf, skip) = decode_hex_prefix(path).map_err(malformed)?;
+                let path_len = nibble_len(path) - skip;
+                if key_len - pos < path_len
+                    || (0..path_len).any(|i| nibble_at(path, skip + i) != nibble_at(key, pos + i))
+                {
+                    break None;
+                }
+                pos += path_len;
+
+                if leaf {
+                    match value {
+                        Item::Bytes(value) if pos == key_len => break Some(value),
+                        Item::Bytes(_) => break None,
+                        Item::List(_) => return Err(malformed("leaf value must be a string")),
+                    }
+                }
+                next = decode_reference(value).map_err(malformed)?;
+                if let NodeRef::Empty = next {
+                    return Err(malformed("extension node must have a child"));
+                }
+            }
+            [ref children @ .., value] if children.len() == 16 => {
+                if pos == key_len {
+                    match value {
+                        Item::Bytes([]) => break None,
+                        Item::Bytes(value) => break Some(value),
+                        Item::List(_) => return Err(malformed("branch value must be a string")),
+                    }
+                }
+                next =
+                    decode_reference(children[nibble_at(key, pos) as usize]).map_err(malformed)?;
+                pos += 1;
+            }
+            _ => return Err(malformed("node must have 2 or 17 items")),
+        }
+    };
+
+    if let Some(&(index, _)) = nodes.peek() {
+        return Err(ProofError::PathDivergence { index });
+    }
+    Ok(value.map(<[u8]>::to_vec))
+}
+
+/// Verifies an account proof, as returned in `accountProof` by `eth_getProof`, against the state
+/// root.
+///
+/// Returns `None` if the proof shows that the account does not exist.
+pub fn verify_account_proof<P: AsRef<[u8]>>(
+    state_root: &[u8; 32],
+    address: &[u8; 20],
+    proof: &[P],
+) -> Result<Option<TrieAccount>, ProofError> {
+    let value = match verify_proof(state_root, &keccak256(address), proof)? {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    let items = rlp::decode_list(&value).map_err(ProofError::MalformedValue)?;
+    let (nonce, balance, storage_root, code_hash) = match *items.as_slice() {
+        [Item::Bytes(nonce), Item::Bytes(balance), Item::Bytes(storage_root), Item::Bytes(code_hash)] => {
+            (nonce, balance, storage_root, code_hash)
+        }
+        _ => return Err(ProofError::MalformedValue("account must be a list of 4 strings")),
+    };
+
+    let mut nonce_be = [0; 8];
+    decode_uint(nonce, &mut nonce_be)?;
+    let mut account = TrieAccount { nonce: u64::from_be_bytes(nonce_be), ..Default::default() };
+    decode_uint(balance, &mut account.balance)?;
+    account.storage_root = storage_root
+        .try_into()
+        .map_err(|_| ProofError::MalformedValue("storage root must be 32 bytes"))?;
+    account.code_hash = code_hash
+        .try_into()
+        .map_err(|_| ProofError::MalformedValue("code hash must be 32 bytes"))?;
+    Ok(Some(account))
+}
+
+/// Verifies a storage proof, as returned in `storageProof` by `eth_getProof`, against the
+/// account's storage root.
+///
+/// Returns the 256-bit big-endian value of the slot, which is zero if the proof shows that the
+/// slot is not in the trie.
+pub fn verify_storage_proof<P: AsRef<[u8]>>(
+    storage_root: &[u8; 32],
+    slot: &[u8; 32],
+    proof: &[P],
+) -> Result<[u8; 32], ProofError> {
+    let mut word = [0; 32];
+    if let Some(value) = verify_proof(storage_root, &keccak256(slot), proof)? {
+        let mut buf = value.as_slice();
+        let value = match rlp::decode_item(&mut buf).map_err(ProofError::MalformedValue)? {
+            Item::Bytes(value) if buf.is_empty() => value,
+            _ => return Err(ProofError::MalformedValue("storage value must be a single string")),
+        };
+        decode_uint(value, &mut word)?;
+    }
+    Ok(word)
+}
+
+/// Decodes the flag nibble of a hex-prefix encoded path, returning whether the node is a leaf
+/// and the number of nibbles to skip to get to the path.
+fn decode_hex_prefix(path: &[u8]) -> Result<(bool, usize), &'static str> {
+    let first = *path.first().ok_or("empty node path")?;
+    match first >> 4 {
+        0 | 2 if first & 0x0f == 0 => Ok((first >> 4 == 2, 2)),
+        1 | 3 => Ok((first >> 4 == 3, 1)),
+        _ => Err("invalid hex-prefix flag"),
+    }
+}
+
+fn decode_reference(item: Item<'_>) -> Result<NodeRef<'_>, &'static str> {
+    match item {
+        Item::Bytes([]) => Ok(NodeRef::Empty),
+        Item::Bytes(hash) => {
+            hash.try_into().map(NodeRef::Hash).map_err(|_| "invalid node reference")
+        }
+        Item::List(node) if node.len() < 32 => Ok(NodeRef::Inline(node)),
+        Item::List(_) => Err("embedded node must be shorter than 32 bytes"),
+    }
+}
+
+/// Decodes a big-endian integer without leading zeroes into the end of `out`.
+fn decode_uint(value: &[u8], out: &mut [u8]) -> Result<(), ProofError> {
+    if value.first() == Some(&0) {
+        return Err(ProofError::MalformedValue("integer has leading zeroes"));
+    }
+    if value.len() > out.len() {
+        return Err(ProofError::MalformedValue("integer overflow"));
+    }
+    let start = out.len() - value.len();
+    out[start..].copy_from_slice(value);
+    Ok(())
+}
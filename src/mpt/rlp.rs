@@ -0,0 +1,107 @@
+//! [RLP](https://ethereum.org/en/developers/docs/data-structures-and-encoding/rlp/) decoding of
+//! trie nodes, and adapters of the [streaming encoder](crate::rlp) writing into vectors.
+
+use crate::rlp::{encode_list_header, header_length, Encodable};
+use alloc::vec::Vec;
+use digest::Update;
+
+pub(crate) use crate::rlp::{EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+
+/// Appends everything written into it to a vector.
+struct VecSink<'a>(&'a mut Vec<u8>);
+
+impl Update for VecSink<'_> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+/// Appends `bytes` encoded as an RLP string.
+#[inline]
+pub(crate) fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    bytes.encode(&mut VecSink(out));
+}
+
+/// Appends `n` encoded as a big-endian RLP scalar without leading zeroes.
+#[inline]
+pub(crate) fn encode_uint(n: u64, out: &mut Vec<u8>) {
+    n.encode(&mut VecSink(out));
+}
+
+/// Wraps an already encoded list payload in a list header.
+pub(crate) fn wrap_list(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(header_length(payload.len()) + payload.len());
+    encode_list_header(payload.len(), &mut VecSink(&mut out));
+    out.extend_from_slice(payload);
+    out
+}
+
+/// A decoded RLP item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    /// The payload of a string.
+    Bytes(&'a [u8]),
+    /// The full encoding of a list, including its header.
+    List(&'a [u8]),
+}
+
+/// Decodes the item at the start of `buf`, advancing it past the item.
+pub(crate) fn decode_item<'a>(buf: &mut &'a [u8]) -> Result<Item<'a>, &'static str> {
+    let b0 = *buf.first().ok_or("unexpected end of input")?;
+    let (list, header_len, payload_len) = match b0 {
+        0x00..=0x7f => (false, 0, 1),
+        0x80..=0xb7 => {
+            let len = (b0 - EMPTY_STRING_CODE) as usize;
+            if len == 1 && buf.get(1).map_or(false, |&b| b < 0x80) {
+                return Err("single byte below 0x80 must be encoded as itself");
+            }
+            (false, 1, len)
+        }
+        0xb8..=0xbf => (false, 1 + (b0 - 0xb7) as usize, decode_long_len(buf, b0 - 0xb7)?),
+        0xc0..=0xf7 => (true, 1, (b0 - EMPTY_LIST_CODE) as usize),
+        0xf8..=0xff => (true, 1 + (b0 - 0xf7) as usize, decode_long_len(buf, b0 - 0xf7)?),
+    };
+
+    let total = header_len.checked_add(payload_len).ok_or("length overflow")?;
+    if buf.len() < total {
+        return Err("unexpected end of input");
+    }
+    let item = if list { Item::List(&buf[..total]) } else { Item::Bytes(&buf[header_len..total]) };
+    *buf = &buf[total..];
+    Ok(item)
+}
+
+fn decode_long_len(buf: &[u8], len_of_len: u8) -> Result<usize, &'static str> {
+    let be = buf.get(1..1 + len_of_len as usize).ok_or("unexpected end of input")?;
+    if be[0] == 0 {
+        return Err("length has leading zeroes");
+    }
+    if be.len() > core::mem::size_of::<usize>() {
+        return Err("length overflow");
+    }
+    let len = be.iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+    if len < 56 {
+        return Err("short length encoded in long form");
+    }
+    Ok(len)
+}
+
+/// Decodes `buf` as exactly one list, returning its items.
+pub(crate) fn decode_list(mut buf: &[u8]) -> Result<Vec<Item<'_>>, &'static str> {
+    let list = match decode_item(&mut buf)? {
+        Item::List(list) => list,
+        Item::Bytes(_) => return Err("expected a list"),
+    };
+    if !buf.is_empty() {
+        return Err("trailing bytes after list");
+    }
+
+    let header_len = if list[0] > 0xf7 { 1 + (list[0] - 0xf7) as usize } else { 1 };
+    let mut payload = &list[header_len..];
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        items.push(decode_item(&mut payload)?);
+    }
+    Ok(items)
+}
@@ -0,0 +1,81 @@
+//! Keccak-f\[800\] permutation, and the Keccak hashing of [ProgPoW](https://eips.ethereum.org/EIPS/eip-1057).
+//!
+//! Keccak-f\[800\] is the member of the Keccak-f family with 32-bit lanes and 22 rounds. Like
+//! [`sha3_asm::keccak_p1600`], it is implemented in Rust.
+
+/// Round constants: the low 32 bits of the first 22 Keccak-f\[1600\] round constants.
+const RC: [u32; 22] = [
+    0x00000001, 0x00008082, 0x0000808a, 0x80008000, 0x0000808b, 0x80000001, 0x80008081, 0x00008009,
+    0x0000008a, 0x00000088, 0x80008009, 0x8000000a, 0x8000808b, 0x0000008b, 0x00008089, 0x00008003,
+    0x00008002, 0x00000080, 0x0000800a, 0x8000000a, 0x80008081, 0x00008080,
+];
+
+/// Rotation offsets of the rho step, in the order of the lanes visited by the pi step.
+const RHO: [u32; 24] =
+    [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+/// Lanes visited by the pi step, starting from lane 1.
+const PI: [usize; 24] =
+    [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+/// Applies the Keccak-f\[800\] permutation to `state`, where lane `(x, y)` is at `x + 5 * y`.
+pub fn keccak_f800(state: &mut [u32; 25]) {
+    for rc in RC {
+        // Theta.
+        let mut c = [0u32; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi.
+        let mut last = state[1];
+        for (&pi, &rho) in PI.iter().zip(&RHO) {
+            let next = state[pi];
+            state[pi] = last.rotate_left(rho);
+            last = next;
+        }
+
+        // Chi.
+        for row in state.chunks_exact_mut(5) {
+            let r = [row[0], row[1], row[2], row[3], row[4]];
+            for x in 0..5 {
+                row[x] = r[x] ^ (!r[(x + 1) % 5] & r[(x + 2) % 5]);
+            }
+        }
+
+        // Iota.
+        state[0] ^= rc;
+    }
+}
+
+/// Hashes a header hash, a 64-bit seed and a digest with a single Keccak-f\[800\] permutation, as
+/// in `keccak_f800_progpow` of ProgPoW 0.9.3 and later.
+///
+/// The header hash and the digest are read as little-endian 32-bit words into lanes 0 to 7 and
+/// 10 to 17, the seed into lanes 8 and 9, low word first, and the first 8 lanes of the result
+/// are returned as little-endian bytes.
+pub fn keccak_f800_progpow(header: &[u8; 32], seed: u64, digest: &[u8; 32]) -> [u8; 32] {
+    let mut state = [0u32; 25];
+    for (lane, word) in state[..8].iter_mut().zip(header.chunks_exact(4)) {
+        *lane = u32::from_le_bytes(word.try_into().unwrap());
+    }
+    state[8] = seed as u32;
+    state[9] = (seed >> 32) as u32;
+    for (lane, word) in state[10..18].iter_mut().zip(digest.chunks_exact(4)) {
+        *lane = u32::from_le_bytes(word.try_into().unwrap());
+    }
+
+    keccak_f800(&mut state);
+
+    let mut out = [0u8; 32];
+    for (word, lane) in out.chunks_exact_mut(4).zip(&state) {
+        word.copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
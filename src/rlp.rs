@@ -0,0 +1,616 @@
+//! Streaming [RLP](https://ethereum.org/en/developers/docs/data-structures-and-encoding/rlp/)
+//! encoding into a hasher.
+//!
+//! Encoding takes two passes: the lengths of list payloads are computed first, so that their
+//! headers can be emitted before their items, then everything is written straight into the sink.
+//! With a [`Keccak256`] sink, headers, transactions and receipts are hashed without ever being
+//! buffered.
+
+use crate::{bloom::Bloom, Digest, Keccak256};
+use digest::Update;
+
+pub(crate) const EMPTY_STRING_CODE: u8 = 0x80;
+pub(crate) const EMPTY_LIST_CODE: u8 = 0xc0;
+
+/// A value which can be RLP-encoded.
+pub trait Encodable {
+    /// Returns the length of the encoding in bytes, including its header.
+    fn length(&self) -> usize;
+
+    /// Writes the encoding into `out`.
+    fn encode<S: Update + ?Sized>(&self, out: &mut S);
+}
+
+/// Returns the Keccak-256 hash of the RLP encoding of `value`.
+#[inline]
+pub fn keccak256_rlp<T: Encodable + ?Sized>(value: &T) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    value.encode(&mut hasher);
+    hasher.finalize().into()
+}
+
+/// Returns the Keccak-256 hash of an [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718) typed
+/// envelope: the type byte followed by the RLP encoding of `payload`.
+#[inline]
+pub fn keccak256_typed<T: Encodable + ?Sized>(ty: u8, payload: &T) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    Update::update(&mut hasher, &[ty]);
+    payload.encode(&mut hasher);
+    hasher.finalize().into()
+}
+
+/// Returns the length of the header of a string or list with a payload of `payload_len` bytes.
+#[inline]
+pub const fn header_length(payload_len: usize) -> usize {
+    if payload_len < 56 {
+        1
+    } else {
+        1 + be_length(payload_len)
+    }
+}
+
+/// Writes the header of a list with a payload of `payload_len` bytes.
+#[inline]
+pub fn encode_list_header<S: Update + ?Sized>(payload_len: usize, out: &mut S) {
+    encode_header(payload_len, EMPTY_LIST_CODE, out);
+}
+
+/// Writes the header of a string with a payload of `payload_len` bytes.
+#[inline]
+pub fn encode_string_header<S: Update + ?Sized>(payload_len: usize, out: &mut S) {
+    encode_header(payload_len, EMPTY_STRING_CODE, out);
+}
+
+fn encode_header<S: Update + ?Sized>(payload_len: usize, offset: u8, out: &mut S) {
+    if payload_len < 56 {
+        out.update(&[offset + payload_len as u8]);
+    } else {
+        let be = payload_len.to_be_bytes();
+        let be = &be[be.len() - be_length(payload_len)..];
+        out.update(&[offset + 55 + be.len() as u8]);
+        out.update(be);
+    }
+}
+
+/// Number of bytes of the big-endian encoding of `n` without leading zeroes.
+#[inline]
+const fn be_length(n: usize) -> usize {
+    (usize::BITS - n.leading_zeros()) as usize / 8 + (n.leading_zeros() % 8 != 0) as usize
+}
+
+/// Returns the length of the encoding of `bytes` as a string.
+#[inline]
+fn string_length(bytes: &[u8]) -> usize {
+    match bytes {
+        [0x00..=0x7f] => 1,
+        _ => header_length(bytes.len()) + bytes.len(),
+    }
+}
+
+#[inline]
+fn encode_string<S: Update + ?Sized>(bytes: &[u8], out: &mut S) {
+    if !matches!(bytes, [0x00..=0x7f]) {
+        encode_string_header(bytes.len(), out);
+    }
+    out.update(bytes);
+}
+
+/// Strips the leading zeroes of a big-endian integer.
+#[inline]
+fn trim(be: &[u8]) -> &[u8] {
+    let zeros = be.iter().take_while(|&&b| b == 0).count();
+    &be[zeros..]
+}
+
+/// A big-endian unsigned integer of any size, such as a 256-bit value or difficulty, which is
+/// encoded without leading zeroes.
+///
+/// Byte arrays and slices are encoded as strings, as is, which is what hashes and addresses need.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Uint<'a>(pub &'a [u8]);
+
+impl Encodable for Uint<'_> {
+    #[inline]
+    fn length(&self) -> usize {
+        string_length(trim(self.0))
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        encode_string(trim(self.0), out);
+    }
+}
+
+/// A list of items of the same type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct List<'a, T>(pub &'a [T]);
+
+impl<T: Encodable> List<'_, T> {
+    #[inline]
+    fn payload_length(&self) -> usize {
+        self.0.iter().map(Encodable::length).sum()
+    }
+}
+
+impl<T: Encodable> Encodable for List<'_, T> {
+    #[inline]
+    fn length(&self) -> usize {
+        let payload_len = self.payload_length();
+        header_length(payload_len) + payload_len
+    }
+
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        encode_list_header(self.payload_length(), out);
+        for item in self.0 {
+            item.encode(out);
+        }
+    }
+}
+
+impl<T: Encodable + ?Sized> Encodable for &T {
+    #[inline]
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        (**self).encode(out)
+    }
+}
+
+/// Encodes nothing when `None`. This is only meant for trailing fields which are absent from
+/// older encodings, such as the fields added to block headers by hard forks.
+impl<T: Encodable> Encodable for Option<T> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.as_ref().map_or(0, Encodable::length)
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        if let Some(value) = self {
+            value.encode(out);
+        }
+    }
+}
+
+impl Encodable for [u8] {
+    #[inline]
+    fn length(&self) -> usize {
+        string_length(self)
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        encode_string(self, out);
+    }
+}
+
+impl<const N: usize> Encodable for [u8; N] {
+    #[inline]
+    fn length(&self) -> usize {
+        string_length(self)
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        encode_string(self, out);
+    }
+}
+
+impl Encodable for str {
+    #[inline]
+    fn length(&self) -> usize {
+        string_length(self.as_bytes())
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        encode_string(self.as_bytes(), out);
+    }
+}
+
+impl Encodable for bool {
+    #[inline]
+    fn length(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        out.update(&[if *self { 0x01 } else { EMPTY_STRING_CODE }]);
+    }
+}
+
+impl Encodable for Bloom {
+    #[inline]
+    fn length(&self) -> usize {
+        self.0.length()
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        self.0.encode(out);
+    }
+}
+
+macro_rules! impl_uint {
+    ($($t:ty),* $(,)?) => {$(
+        impl Encodable for $t {
+            #[inline]
+            fn length(&self) -> usize {
+                Uint(&self.to_be_bytes()).length()
+            }
+
+            #[inline]
+            fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+                Uint(&self.to_be_bytes()).encode(out);
+            }
+        }
+    )*};
+}
+
+impl_uint!(u8, u16, u32, u64, u128, usize);
+
+/// Implements [`Encodable`] for a struct encoded as the list of the given fields.
+macro_rules! impl_list {
+    ($ty:ty, |$this:ident| [$($field:expr),* $(,)?]) => {
+        impl $ty {
+            #[inline]
+            fn payload_length(&self) -> usize {
+                let $this = self;
+                0 $(+ Encodable::length(&$field))*
+            }
+        }
+
+        impl Encodable for $ty {
+            #[inline]
+            fn length(&self) -> usize {
+                let payload_len = self.payload_length();
+                header_length(payload_len) + payload_len
+            }
+
+            fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+                let $this = self;
+                encode_list_header(self.payload_length(), out);
+                $(Encodable::encode(&$field, out);)*
+            }
+        }
+    };
+}
+
+/// A block header, with the optional fields added by later hard forks.
+///
+/// Optional fields are encoded only when present, and are cumulative: a field may only be
+/// `Some` if all the fields before it are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Header<'a> {
+    /// Hash of the parent block header.
+    pub parent_hash: [u8; 32],
+    /// Hash of the list of ommer headers.
+    pub ommers_hash: [u8; 32],
+    /// Address of the block producer.
+    pub beneficiary: [u8; 20],
+    /// Root of the state trie.
+    pub state_root: [u8; 32],
+    /// Root of the transaction trie.
+    pub transactions_root: [u8; 32],
+    /// Root of the receipt trie.
+    pub receipts_root: [u8; 32],
+    /// Bloom filter of the logs of the block.
+    pub logs_bloom: Bloom,
+    /// Difficulty, as a big-endian 256-bit integer.
+    pub difficulty: [u8; 32],
+    /// Block number.
+    pub number: u64,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Gas used.
+    pub gas_used: u64,
+    /// Timestamp in seconds.
+    pub timestamp: u64,
+    /// Extra data.
+    pub extra_data: &'a [u8],
+    /// Mix digest, or `prevRandao` after the merge.
+    pub mix_hash: [u8; 32],
+    /// Proof-of-work nonce.
+    pub nonce: [u8; 8],
+    /// Base fee per gas, since London.
+    pub base_fee_per_gas: Option<u64>,
+    /// Root of the withdrawal trie, since Shanghai.
+    pub withdrawals_root: Option<[u8; 32]>,
+    /// Blob gas used, since Cancun.
+    pub blob_gas_used: Option<u64>,
+    /// Excess blob gas, since Cancun.
+    pub excess_blob_gas: Option<u64>,
+    /// Root of the parent beacon block, since Cancun.
+    pub parent_beacon_block_root: Option<[u8; 32]>,
+    /// Hash of the execution layer requests, since Prague.
+    pub requests_hash: Option<[u8; 32]>,
+}
+
+impl_list!(Header<'_>, |h| [
+    h.parent_hash,
+    h.ommers_hash,
+    h.beneficiary,
+    h.state_root,
+    h.transactions_root,
+    h.receipts_root,
+    h.logs_bloom,
+    Uint(&h.difficulty),
+    h.number,
+    h.gas_limit,
+    h.gas_used,
+    h.timestamp,
+    h.extra_data,
+    h.mix_hash,
+    h.nonce,
+    h.base_fee_per_gas,
+    h.withdrawals_root,
+    h.blob_gas_used,
+    h.excess_blob_gas,
+    h.parent_beacon_block_root,
+    h.requests_hash,
+]);
+
+impl Header<'_> {
+    /// Returns the hash of the header.
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256_rlp(self)
+    }
+}
+
+/// The destination of a transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxKind {
+    /// Contract creation, encoded as the empty string.
+    #[default]
+    Create,
+    /// Call to an address.
+    Call([u8; 20]),
+}
+
+impl Encodable for TxKind {
+    #[inline]
+    fn length(&self) -> usize {
+        match self {
+            Self::Create => 1,
+            Self::Call(to) => to.length(),
+        }
+    }
+
+    #[inline]
+    fn encode<S: Update + ?Sized>(&self, out: &mut S) {
+        match self {
+            Self::Create => out.update(&[EMPTY_STRING_CODE]),
+            Self::Call(to) => to.encode(out),
+        }
+    }
+}
+
+/// An entry of an [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) access list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AccessListItem<'a> {
+    /// Accessed address.
+    pub address: [u8; 20],
+    /// Accessed storage keys.
+    pub storage_keys: &'a [[u8; 32]],
+}
+
+impl_list!(AccessListItem<'_>, |item| [item.address, List(item.storage_keys)]);
+
+/// A signed legacy transaction, whose hash is the hash of its RLP encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LegacyTransaction<'a> {
+    /// Nonce of the sender.
+    pub nonce: u64,
+    /// Gas price.
+    pub gas_price: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Destination.
+    pub to: TxKind,
+    /// Value, as a big-endian 256-bit integer.
+    pub value: [u8; 32],
+    /// Calldata or init code.
+    pub input: &'a [u8],
+    /// Signature `v`, which includes the chain ID with
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155).
+    pub v: u64,
+    /// Signature `r`.
+    pub r: [u8; 32],
+    /// Signature `s`.
+    pub s: [u8; 32],
+}
+
+impl_list!(LegacyTransaction<'_>, |tx| [
+    tx.nonce,
+    tx.gas_price,
+    tx.gas_limit,
+    tx.to,
+    Uint(&tx.value),
+    tx.input,
+    tx.v,
+    Uint(&tx.r),
+    Uint(&tx.s),
+]);
+
+impl LegacyTransaction<'_> {
+    /// Returns the hash of the transaction.
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256_rlp(self)
+    }
+}
+
+/// A signed [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) transaction, of type 1.
+///
+/// The [`Encodable`] implementation encodes the payload only, without the type byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Eip2930Transaction<'a> {
+    /// Chain ID.
+    pub chain_id: u64,
+    /// Nonce of the sender.
+    pub nonce: u64,
+    /// Gas price.
+    pub gas_price: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Destination.
+    pub to: TxKind,
+    /// Value, as a big-endian 256-bit integer.
+    pub value: [u8; 32],
+    /// Calldata or init code.
+    pub input: &'a [u8],
+    /// Access list.
+    pub access_list: &'a [AccessListItem<'a>],
+    /// Signature y-parity.
+    pub y_parity: bool,
+    /// Signature `r`.
+    pub r: [u8; 32],
+    /// Signature `s`.
+    pub s: [u8; 32],
+}
+
+impl_list!(Eip2930Transaction<'_>, |tx| [
+    tx.chain_id,
+    tx.nonce,
+    tx.gas_price,
+    tx.gas_limit,
+    tx.to,
+    Uint(&tx.value),
+    tx.input,
+    List(tx.access_list),
+    tx.y_parity,
+    Uint(&tx.r),
+    Uint(&tx.s),
+]);
+
+impl Eip2930Transaction<'_> {
+    /// Transaction type.
+    pub const TYPE: u8 = 1;
+
+    /// Returns the hash of the transaction.
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256_typed(Self::TYPE, self)
+    }
+}
+
+/// A signed [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) transaction, of type 2.
+///
+/// The [`Encodable`] implementation encodes the payload only, without the type byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Eip1559Transaction<'a> {
+    /// Chain ID.
+    pub chain_id: u64,
+    /// Nonce of the sender.
+    pub nonce: u64,
+    /// Maximum priority fee per gas.
+    pub max_priority_fee_per_gas: u128,
+    /// Maximum fee per gas.
+    pub max_fee_per_gas: u128,
+    /// Gas limit.
+    pub gas_limit: u64,
+    /// Destination.
+    pub to: TxKind,
+    /// Value, as a big-endian 256-bit integer.
+    pub value: [u8; 32],
+    /// Calldata or init code.
+    pub input: &'a [u8],
+    /// Access list.
+    pub access_list: &'a [AccessListItem<'a>],
+    /// Signature y-parity.
+    pub y_parity: bool,
+    /// Signature `r`.
+    pub r: [u8; 32],
+    /// Signature `s`.
+    pub s: [u8; 32],
+}
+
+impl_list!(Eip1559Transaction<'_>, |tx| [
+    tx.chain_id,
+    tx.nonce,
+    tx.max_priority_fee_per_gas,
+    tx.max_fee_per_gas,
+    tx.gas_limit,
+    tx.to,
+    Uint(&tx.value),
+    tx.input,
+    List(tx.access_list),
+    tx.y_parity,
+    Uint(&tx.r),
+    Uint(&tx.s),
+]);
+
+impl Eip1559Transaction<'_> {
+    /// Transaction type.
+    pub const TYPE: u8 = 2;
+
+    /// Returns the hash of the transaction.
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256_typed(Self::TYPE, self)
+    }
+}
+
+/// A log emitted by a transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Log<'a> {
+    /// Address of the emitting contract.
+    pub address: [u8; 20],
+    /// Topics.
+    pub topics: &'a [[u8; 32]],
+    /// Data.
+    pub data: &'a [u8],
+}
+
+impl_list!(Log<'_>, |log| [log.address, List(log.topics), log.data]);
+
+/// A post-Byzantium transaction receipt.
+///
+/// The [`Encodable`] implementation encodes the payload only, without the type byte; see
+/// [`encode_2718`](Self::encode_2718) for the encoding stored in the receipt trie.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Receipt<'a> {
+    /// Type of the transaction, `0` for legacy transactions.
+    pub tx_type: u8,
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// Gas used by the block up to and including this transaction.
+    pub cumulative_gas_used: u64,
+    /// Bloom filter of the logs.
+    pub logs_bloom: Bloom,
+    /// Logs.
+    pub logs: &'a [Log<'a>],
+}
+
+impl_list!(Receipt<'_>, |r| [r.success, r.cumulative_gas_used, r.logs_bloom, List(r.logs)]);
+
+impl Receipt<'_> {
+    /// Returns the length of the [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718) encoding.
+    #[inline]
+    pub fn length_2718(&self) -> usize {
+        (self.tx_type != 0) as usize + self.length()
+    }
+
+    /// Writes the [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718) encoding: the type byte,
+    /// unless the transaction is legacy, followed by the RLP encoding.
+    pub fn encode_2718<S: Update + ?Sized>(&self, out: &mut S) {
+        if self.tx_type != 0 {
+            out.update(&[self.tx_type]);
+        }
+        self.encode(out);
+    }
+
+    /// Returns the Keccak-256 hash of the [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718)
+    /// encoding.
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        self.encode_2718(&mut hasher);
+        hasher.finalize().into()
+    }
+}
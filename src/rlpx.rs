@@ -0,0 +1,108 @@
+//! Frame MACs of the [RLPx](https://github.com/ethereum/devp2p/blob/master/rlpx.md) transport
+//! protocol.
+//!
+//! Each side of a connection keeps two running Keccak-256 states, one over the frames it sends
+//! (egress) and one over the frames it receives (ingress). A MAC is the first 16 bytes of the
+//! current digest of a state, which keeps absorbing data afterwards.
+//!
+//! The MAC updates encrypt one block with AES-256 keyed with the MAC secret, which is not
+//! implemented by this crate; it is passed in as `encrypt`.
+
+use crate::{Digest, Keccak256};
+
+/// A running egress or ingress MAC state.
+#[derive(Clone, Debug, Default)]
+pub struct Mac {
+    hasher: Keccak256,
+}
+
+impl Mac {
+    /// Creates a MAC state from the handshake: `keccak256.init((mac_secret ^ nonce) || packet)`.
+    ///
+    /// See [`egress_ingress`](Self::egress_ingress) for the nonce and packet of each state.
+    pub fn new(mac_secret: &[u8; 32], nonce: &[u8; 32], packet: &[u8]) -> Self {
+        let mut key = *mac_secret;
+        key.iter_mut().zip(nonce).for_each(|(k, n)| *k ^= n);
+        let mut hasher = Keccak256::new();
+        hasher.update(key);
+        hasher.update(packet);
+        Self { hasher }
+    }
+
+    /// Creates the egress and ingress MAC states of one side of a connection.
+    ///
+    /// The egress state of the initiator is seeded with the recipient's nonce and the `auth`
+    /// packet, and its ingress state with the initiator's nonce and the `ack` packet; the
+    /// recipient uses the opposite assignment. Packets are the full EIP-8 encodings, including
+    /// their size prefix.
+    pub fn egress_ingress(
+        initiator: bool,
+        mac_secret: &[u8; 32],
+        initiator_nonce: &[u8; 32],
+        recipient_nonce: &[u8; 32],
+        auth: &[u8],
+        ack: &[u8],
+    ) -> (Self, Self) {
+        let a = Self::new(mac_secret, recipient_nonce, auth);
+        let b = Self::new(mac_secret, initiator_nonce, ack);
+        if initiator {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Absorbs raw data.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Returns the first 16 bytes of the current digest.
+    #[inline]
+    pub fn digest(&self) -> [u8; 16] {
+        let (core, buffer) = self.hasher.clone().decompose();
+        core.peek_finalize(&buffer)[..16].try_into().unwrap()
+    }
+
+    /// Absorbs the ciphertext of a frame header, and returns the header MAC:
+    ///
+    /// ```text
+    /// header-mac-seed = aes(mac-secret, digest) ^ header-ciphertext
+    /// update(header-mac-seed)
+    /// header-mac = digest
+    /// ```
+    pub fn update_header<E>(&mut self, header_ciphertext: &[u8; 16], encrypt: E) -> [u8; 16]
+    where
+        E: FnOnce(&[u8; 16]) -> [u8; 16],
+    {
+        self.update_with_seed(header_ciphertext, encrypt)
+    }
+
+    /// Absorbs the ciphertext of a frame body, and returns the frame MAC:
+    ///
+    /// ```text
+    /// update(frame-ciphertext)
+    /// frame-mac-seed = aes(mac-secret, digest) ^ digest
+    /// update(frame-mac-seed)
+    /// frame-mac = digest
+    /// ```
+    pub fn update_body<E>(&mut self, frame_ciphertext: &[u8], encrypt: E) -> [u8; 16]
+    where
+        E: FnOnce(&[u8; 16]) -> [u8; 16],
+    {
+        self.update(frame_ciphertext);
+        let seed = self.digest();
+        self.update_with_seed(&seed, encrypt)
+    }
+
+    fn update_with_seed<E>(&mut self, seed: &[u8; 16], encrypt: E) -> [u8; 16]
+    where
+        E: FnOnce(&[u8; 16]) -> [u8; 16],
+    {
+        let mut block = encrypt(&self.digest());
+        block.iter_mut().zip(seed).for_each(|(b, s)| *b ^= s);
+        self.update(&block);
+        self.digest()
+    }
+}
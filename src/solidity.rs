@@ -0,0 +1,338 @@
+//! Streaming `keccak256(abi.encodePacked(...))` and `keccak256(abi.encode(...))`.
+
+use crate::Keccak256;
+use alloc::vec::Vec;
+use digest::Digest;
+
+type Word = [u8; 32];
+
+/// Solidity ABI encoding of the values pushed into a [`SolidityHasher`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    /// `abi.encodePacked`.
+    Packed,
+    /// `abi.encode`.
+    Abi,
+}
+
+/// A deferred `abi.encode` argument.
+#[derive(Clone, Debug)]
+enum Token<'a> {
+    /// A static value, encoded in place.
+    Word(Word),
+    /// `bytes` or `string`, encoded in the tail.
+    Bytes(&'a [u8]),
+    /// A dynamic array of static values, encoded in the tail.
+    Array(Vec<Word>),
+}
+
+impl Token<'_> {
+    fn tail_len(&self) -> usize {
+        match self {
+            Self::Word(_) => 0,
+            Self::Bytes(bytes) => 32 + (bytes.len() + 31) / 32 * 32,
+            Self::Array(words) => 32 + words.len() * 32,
+        }
+    }
+}
+
+/// Keccak-256 hasher which writes the Solidity ABI encoding of typed values directly into the
+/// sponge, computing `keccak256(abi.encodePacked(...))` or `keccak256(abi.encode(...))` without
+/// building the encoding in memory.
+///
+/// With `abi.encode`, the head of the encoding contains the offsets of the `bytes`, `string` and
+/// array values, which depend on the arguments that follow. Static values are hashed as soon as
+/// they are pushed until the first dynamic value; after that, values are kept until
+/// [`finalize`](Self::finalize), borrowing `bytes` and `string` data rather than copying it.
+#[derive(Clone, Debug)]
+pub struct SolidityHasher<'a> {
+    hasher: Keccak256,
+    encoding: Encoding,
+    tokens: Vec<Token<'a>>,
+    /// Number of head words already hashed.
+    hashed_words: usize,
+}
+
+impl<'a> SolidityHasher<'a> {
+    /// Creates a hasher for `keccak256(abi.encodePacked(...))`.
+    #[inline]
+    pub fn packed() -> Self {
+        Self::new(Encoding::Packed)
+    }
+
+    /// Creates a hasher for `keccak256(abi.encode(...))`.
+    #[inline]
+    pub fn abi() -> Self {
+        Self::new(Encoding::Abi)
+    }
+
+    #[inline]
+    fn new(encoding: Encoding) -> Self {
+        Self { hasher: Keccak256::new(), encoding, tokens: Vec::new(), hashed_words: 0 }
+    }
+
+    /// Pushes an `address`.
+    pub fn push_address(&mut self, address: &[u8; 20]) -> &mut Self {
+        match self.encoding {
+            Encoding::Packed => self.hasher.update(address),
+            Encoding::Abi => self.push_word(address_word(address)),
+        }
+        self
+    }
+
+    /// Pushes a `bool`.
+    pub fn push_bool(&mut self, value: bool) -> &mut Self {
+        match self.encoding {
+            Encoding::Packed => self.hasher.update([value as u8]),
+            Encoding::Abi => self.push_word(bool_word(value)),
+        }
+        self
+    }
+
+    /// Pushes a `bytes32`.
+    #[inline]
+    pub fn push_bytes32(&mut self, value: &[u8; 32]) -> &mut Self {
+        self.push_fixed_bytes(value)
+    }
+
+    /// Pushes a `bytesN`, where `N` is in `1..=32`.
+    pub fn push_fixed_bytes<const N: usize>(&mut self, value: &[u8; N]) -> &mut Self {
+        assert!((1..=32).contains(&N), "invalid fixed bytes size: {N}");
+        match self.encoding {
+            Encoding::Packed => self.hasher.update(value),
+            Encoding::Abi => {
+                let mut word = [0; 32];
+                word[..N].copy_from_slice(value);
+                self.push_word(word);
+            }
+        }
+        self
+    }
+
+    /// Pushes a `uintBITS`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is not a multiple of 8 in `8..=256`, or if `value` does not fit in `BITS`
+    /// bits.
+    pub fn push_uint<const BITS: usize>(&mut self, value: impl SolUint) -> &mut Self {
+        let word = checked_uint_word::<BITS>(value.to_word());
+        self.push_int_word::<BITS>(word)
+    }
+
+    /// Pushes an `intBITS`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is not a multiple of 8 in `8..=256`, or if `value` does not fit in `BITS`
+    /// bits.
+    pub fn push_int<const BITS: usize>(&mut self, value: impl SolInt) -> &mut Self {
+        let word = checked_int_word::<BITS>(value.to_word());
+        self.push_int_word::<BITS>(word)
+    }
+
+    fn push_int_word<const BITS: usize>(&mut self, word: Word) -> &mut Self {
+        match self.encoding {
+            Encoding::Packed => self.hasher.update(&word[32 - BITS / 8..]),
+            Encoding::Abi => self.push_word(word),
+        }
+        self
+    }
+
+    /// Pushes a dynamic `bytes`.
+    pub fn push_bytes(&mut self, data: &'a [u8]) -> &mut Self {
+        match self.encoding {
+            Encoding::Packed => self.hasher.update(data),
+            Encoding::Abi => self.tokens.push(Token::Bytes(data)),
+        }
+        self
+    }
+
+    /// Pushes a `string`.
+    #[inline]
+    pub fn push_string(&mut self, s: &'a str) -> &mut Self {
+        self.push_bytes(s.as_bytes())
+    }
+
+    /// Pushes an `address[]`.
+    pub fn push_address_array(&mut self, addresses: &[[u8; 20]]) -> &mut Self {
+        self.push_array(addresses.iter().map(address_word))
+    }
+
+    /// Pushes a `bool[]`.
+    pub fn push_bool_array(&mut self, values: &[bool]) -> &mut Self {
+        self.push_array(values.iter().map(|&value| bool_word(value)))
+    }
+
+    /// Pushes a `bytes32[]`.
+    pub fn push_bytes32_array(&mut self, values: &[[u8; 32]]) -> &mut Self {
+        self.push_array(values.iter().copied())
+    }
+
+    /// Pushes a `uintBITS[]`.
+    ///
+    /// # Panics
+    ///
+    /// See [`push_uint`](Self::push_uint).
+    pub fn push_uint_array<const BITS: usize, T: SolUint>(&mut self, values: &[T]) -> &mut Self {
+        self.push_array(values.iter().map(|value| checked_uint_word::<BITS>(value.to_word())))
+    }
+
+    /// Pushes an `intBITS[]`.
+    ///
+    /// # Panics
+    ///
+    /// See [`push_int`](Self::push_int).
+    pub fn push_int_array<const BITS: usize, T: SolInt>(&mut self, values: &[T]) -> &mut Self {
+        self.push_array(values.iter().map(|value| checked_int_word::<BITS>(value.to_word())))
+    }
+
+    /// Pushes a dynamic array of static values. Array elements are padded to 32 bytes in both
+    /// encodings.
+    fn push_array(&mut self, words: impl ExactSizeIterator<Item = Word>) -> &mut Self {
+        match self.encoding {
+            Encoding::Packed => words.for_each(|word| self.hasher.update(word)),
+            Encoding::Abi => self.tokens.push(Token::Array(words.collect())),
+        }
+        self
+    }
+
+    /// Pushes a static value into the head of the `abi.encode` encoding.
+    fn push_word(&mut self, word: Word) {
+        if self.tokens.is_empty() {
+            self.hasher.update(word);
+            self.hashed_words += 1;
+        } else {
+            self.tokens.push(Token::Word(word));
+        }
+    }
+
+    /// Returns the hash of the encoding of all the pushed values.
+    pub fn finalize(self) -> [u8; 32] {
+        let Self { mut hasher, tokens, hashed_words, .. } = self;
+
+        // Head.
+        let mut offset = (hashed_words + tokens.len()) * 32;
+        for token in &tokens {
+            match token {
+                Token::Word(word) => hasher.update(word),
+                _ => {
+                    hasher.update(uint_word(offset as u128));
+                    offset += token.tail_len();
+                }
+            }
+        }
+
+        // Tail.
+        for token in &tokens {
+            match token {
+                Token::Word(_) => {}
+                Token::Bytes(bytes) => {
+                    hasher.update(uint_word(bytes.len() as u128));
+                    hasher.update(bytes);
+                    hasher.update(&[0; 32][..(32 - bytes.len() % 32) % 32]);
+                }
+                Token::Array(words) => {
+                    hasher.update(uint_word(words.len() as u128));
+                    words.iter().for_each(|word| hasher.update(word));
+                }
+            }
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// An unsigned integer which can be pushed as a Solidity `uintN`.
+pub trait SolUint {
+    /// Returns the value as a 256-bit big-endian word.
+    fn to_word(&self) -> [u8; 32];
+}
+
+/// A signed integer which can be pushed as a Solidity `intN`.
+pub trait SolInt {
+    /// Returns the value as a sign-extended 256-bit big-endian two's complement word.
+    fn to_word(&self) -> [u8; 32];
+}
+
+/// A 256-bit big-endian integer.
+impl SolUint for [u8; 32] {
+    #[inline]
+    fn to_word(&self) -> [u8; 32] {
+        *self
+    }
+}
+
+/// A 256-bit big-endian two's complement integer.
+impl SolInt for [u8; 32] {
+    #[inline]
+    fn to_word(&self) -> [u8; 32] {
+        *self
+    }
+}
+
+macro_rules! impl_sol_int {
+    ($tr:ident: $($t:ty),* $(,)?) => {$(
+        impl $tr for $t {
+            #[inline]
+            fn to_word(&self) -> [u8; 32] {
+                const SIZE: usize = core::mem::size_of::<$t>();
+                #[allow(unused_comparisons)]
+                let fill = if *self < 0 { 0xff } else { 0 };
+                let mut word = [fill; 32];
+                word[32 - SIZE..].copy_from_slice(&self.to_be_bytes());
+                word
+            }
+        }
+    )*};
+}
+
+impl_sol_int!(SolUint: u8, u16, u32, u64, u128, usize);
+impl_sol_int!(SolInt: i8, i16, i32, i64, i128, isize);
+
+#[inline]
+fn address_word(address: &[u8; 20]) -> Word {
+    let mut word = [0; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+#[inline]
+fn bool_word(value: bool) -> Word {
+    let mut word = [0; 32];
+    word[31] = value as u8;
+    word
+}
+
+#[inline]
+fn uint_word(value: u128) -> Word {
+    let mut word = [0; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+#[inline]
+#[track_caller]
+fn assert_bits<const BITS: usize>() {
+    assert!(BITS % 8 == 0 && (8..=256).contains(&BITS), "invalid integer size: {BITS}");
+}
+
+#[track_caller]
+fn checked_uint_word<const BITS: usize>(word: Word) -> Word {
+    assert_bits::<BITS>();
+    assert!(word[..32 - BITS / 8].iter().all(|&b| b == 0), "value does not fit in uint{BITS}");
+    word
+}
+
+#[track_caller]
+fn checked_int_word<const BITS: usize>(word: Word) -> Word {
+    assert_bits::<BITS>();
+    // All the bytes above the value, and the sign bit of the value, must match the sign.
+    let fill = if word[0] & 0x80 != 0 { 0xff } else { 0 };
+    let sign_byte = word[32 - BITS / 8];
+    assert!(
+        word[..32 - BITS / 8].iter().all(|&b| b == fill) && (sign_byte & 0x80 != 0) == (fill != 0),
+        "value does not fit in int{BITS}"
+    );
+    word
+}
@@ -0,0 +1,460 @@
+//! 256-level sparse Merkle tree, committing to a map from 32-byte keys to 32-byte values.
+//!
+//! The path of a key goes from its most significant bit at the root down to its least
+//! significant bit at the leaf. Leaves are the values themselves, and the zero value is the
+//! empty leaf, so values should be hashes. Nodes are hashed as `keccak256(left . right)`, and
+//! empty subtrees hash to precomputed default hashes, so only the nodes on the paths of the
+//! non-empty leaves are stored.
+
+use crate::keccak256;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Depth of the tree, and size of the keys in bits.
+pub const DEPTH: usize = 256;
+
+/// Height and key prefix of a node. The prefix is the key of any leaf below the node with its
+/// `height` least significant bits cleared.
+type NodeKey = (u16, [u8; 32]);
+
+/// A compressed Merkle proof of the value at a key, which is zero for non-inclusion proofs.
+///
+/// Only the siblings which are not the root of an empty subtree are included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// Bit `h` of this big-endian 256-bit bitmap is set if the sibling at height `h` is included
+    /// in `siblings`.
+    pub bitmap: [u8; 32],
+    /// The non-default siblings, from the leaf up to the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl SparseMerkleProof {
+    /// Returns the root computed from the value at `key` and the proof, or `None` if the number
+    /// of siblings does not match the bitmap.
+    pub fn compute_root(&self, key: &[u8; 32], value: &[u8; 32]) -> Option<[u8; 32]> {
+        let mut siblings = self.siblings.iter();
+        let mut node = *value;
+        for (h, zero) in ZERO_HASHES[..DEPTH].iter().enumerate() {
+            let sibling = if bit(&self.bitmap, h) { siblings.next()? } else { zero };
+            node = if bit(key, h) { hash_node(sibling, &node) } else { hash_node(&node, sibling) };
+        }
+        match siblings.next() {
+            Some(_) => None,
+            None => Some(node),
+        }
+    }
+
+    /// Returns `true` if the proof shows that the value at `key` is `value` in the tree with the
+    /// given `root`. A zero `value` proves that `key` is not in the tree.
+    #[inline]
+    pub fn verify(&self, root: &[u8; 32], key: &[u8; 32], value: &[u8; 32]) -> bool {
+        self.compute_root(key, value) == Some(*root)
+    }
+}
+
+/// 256-level sparse Merkle tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerkleTree {
+    /// The nodes which are not the root of an empty subtree, including the leaves.
+    nodes: BTreeMap<NodeKey, [u8; 32]>,
+}
+
+impl Default for SparseMerkleTree {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleTree {
+    /// Creates an empty tree.
+    #[inline]
+    pub fn new() -> Self {
+        Self { nodes: BTreeMap::new() }
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> [u8; 32] {
+        self.node(DEPTH, &[0; 32])
+    }
+
+    /// Returns the roots of empty subtrees, indexed by height, from the empty leaf up to the
+    /// root of the empty tree.
+    #[inline]
+    pub fn zero_hashes(&self) -> &[[u8; 32]] {
+        &ZERO_HASHES
+    }
+
+    /// Returns the value at `key`, which is zero if `key` is not in the tree.
+    #[inline]
+    pub fn get(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.node(0, key)
+    }
+
+    /// Returns the number of non-empty leaves.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.range((0, [0; 32])..=(0, [0xff; 32])).count()
+    }
+
+    /// Returns `true` if all the leaves are empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Sets the value at `key`. Setting the zero value removes `key` from the tree.
+    #[inline]
+    pub fn update(&mut self, key: [u8; 32], value: [u8; 32]) {
+        self.update_batch([(key, value)]);
+    }
+
+    /// Sets the values at many keys, then rehashes the paths of all the updated keys together,
+    /// so that the nodes shared by several paths are hashed once.
+    ///
+    /// If a key is given more than once, the last value is kept.
+    pub fn update_batch<I: IntoIterator<Item = ([u8; 32], [u8; 32])>>(&mut self, updates: I) {
+        let mut prefixes = Vec::new();
+        for (key, value) in updates {
+            self.set_node(0, key, value);
+            prefixes.push(key);
+        }
+
+        for h in 1..=DEPTH {
+            // The parents of the updated nodes at the previous level.
+            for prefix in &mut prefixes {
+                set_bit(prefix, h - 1, false);
+            }
+            prefixes.sort_unstable();
+            prefixes.dedup();
+
+            for prefix in &prefixes {
+                let mut right = *prefix;
+                set_bit(&mut right, h - 1, true);
+                let node = hash_node(&self.node(h - 1, prefix), &self.node(h - 1, &right));
+                self.set_node(h, *prefix, node);
+            }
+        }
+    }
+
+    /// Returns a proof of the value at `key`, which is an inclusion proof if `key` is in the tree
+    /// and a non-inclusion proof otherwise.
+    pub fn proof(&self, key: &[u8; 32]) -> SparseMerkleProof {
+        let mut proof = SparseMerkleProof::default();
+        let mut prefix = *key;
+        for h in 0..DEPTH {
+            let mut sibling = prefix;
+            set_bit(&mut sibling, h, !bit(&prefix, h));
+            if let Some(node) = self.nodes.get(&(h as u16, sibling)) {
+                set_bit(&mut proof.bitmap, h, true);
+                proof.siblings.push(*node);
+            }
+            set_bit(&mut prefix, h, false);
+        }
+        proof
+    }
+
+    /// Returns the node at height `h` with the given prefix.
+    #[inline]
+    fn node(&self, h: usize, prefix: &[u8; 32]) -> [u8; 32] {
+        self.nodes.get(&(h as u16, *prefix)).copied().unwrap_or(ZERO_HASHES[h])
+    }
+
+    /// Stores a node, or removes it if it is the root of an empty subtree.
+    #[inline]
+    fn set_node(&mut self, h: usize, prefix: [u8; 32], node: [u8; 32]) {
+        if node == ZERO_HASHES[h] {
+            self.nodes.remove(&(h as u16, prefix));
+        } else {
+            self.nodes.insert((h as u16, prefix), node);
+        }
+    }
+}
+
+/// Returns bit `i` of a big-endian 256-bit integer, counting from the least significant bit.
+#[inline]
+fn bit(bytes: &[u8; 32], i: usize) -> bool {
+    bytes[31 - i / 8] >> (i % 8) & 1 == 1
+}
+
+#[inline]
+fn set_bit(bytes: &mut [u8; 32], i: usize, value: bool) {
+    let mask = 1 << (i % 8);
+    if value {
+        bytes[31 - i / 8] |= mask;
+    } else {
+        bytes[31 - i / 8] &= !mask;
+    }
+}
+
+#[inline]
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    keccak256(&buf)
+}
+
+/// The roots of empty subtrees, indexed by height: `ZERO_HASHES[h + 1]` is
+/// `keccak256(ZERO_HASHES[h] . ZERO_HASHES[h])`.
+#[rustfmt::skip]
+const ZERO_HASHES: [[u8; 32]; DEPTH + 1] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0xad, 0x32, 0x28, 0xb6, 0x76, 0xf7, 0xd3, 0xcd, 0x42, 0x84, 0xa5, 0x44, 0x3f, 0x17, 0xf1, 0x96, 0x2b, 0x36, 0xe4, 0x91, 0xb3, 0x0a, 0x40, 0xb2, 0x40, 0x58, 0x49, 0xe5, 0x97, 0xba, 0x5f, 0xb5],
+    [0xb4, 0xc1, 0x19, 0x51, 0x95, 0x7c, 0x6f, 0x8f, 0x64, 0x2c, 0x4a, 0xf6, 0x1c, 0xd6, 0xb2, 0x46, 0x40, 0xfe, 0xc6, 0xdc, 0x7f, 0xc6, 0x07, 0xee, 0x82, 0x06, 0xa9, 0x9e, 0x92, 0x41, 0x0d, 0x30],
+    [0x21, 0xdd, 0xb9, 0xa3, 0x56, 0x81, 0x5c, 0x3f, 0xac, 0x10, 0x26, 0xb6, 0xde, 0xc5, 0xdf, 0x31, 0x24, 0xaf, 0xba, 0xdb, 0x48, 0x5c, 0x9b, 0xa5, 0xa3, 0xe3, 0x39, 0x8a, 0x04, 0xb7, 0xba, 0x85],
+    [0xe5, 0x87, 0x69, 0xb3, 0x2a, 0x1b, 0xea, 0xf1, 0xea, 0x27, 0x37, 0x5a, 0x44, 0x09, 0x5a, 0x0d, 0x1f, 0xb6, 0x64, 0xce, 0x2d, 0xd3, 0x58, 0xe7, 0xfc, 0xbf, 0xb7, 0x8c, 0x26, 0xa1, 0x93, 0x44],
+    [0x0e, 0xb0, 0x1e, 0xbf, 0xc9, 0xed, 0x27, 0x50, 0x0c, 0xd4, 0xdf, 0xc9, 0x79, 0x27, 0x2d, 0x1f, 0x09, 0x13, 0xcc, 0x9f, 0x66, 0x54, 0x0d, 0x7e, 0x80, 0x05, 0x81, 0x11, 0x09, 0xe1, 0xcf, 0x2d],
+    [0x88, 0x7c, 0x22, 0xbd, 0x87, 0x50, 0xd3, 0x40, 0x16, 0xac, 0x3c, 0x66, 0xb5, 0xff, 0x10, 0x2d, 0xac, 0xdd, 0x73, 0xf6, 0xb0, 0x14, 0xe7, 0x10, 0xb5, 0x1e, 0x80, 0x22, 0xaf, 0x9a, 0x19, 0x68],
+    [0xff, 0xd7, 0x01, 0x57, 0xe4, 0x80, 0x63, 0xfc, 0x33, 0xc9, 0x7a, 0x05, 0x0f, 0x7f, 0x64, 0x02, 0x33, 0xbf, 0x64, 0x6c, 0xc9, 0x8d, 0x95, 0x24, 0xc6, 0xb9, 0x2b, 0xcf, 0x3a, 0xb5, 0x6f, 0x83],
+    [0x98, 0x67, 0xcc, 0x5f, 0x7f, 0x19, 0x6b, 0x93, 0xba, 0xe1, 0xe2, 0x7e, 0x63, 0x20, 0x74, 0x24, 0x45, 0xd2, 0x90, 0xf2, 0x26, 0x38, 0x27, 0x49, 0x8b, 0x54, 0xfe, 0xc5, 0x39, 0xf7, 0x56, 0xaf],
+    [0xce, 0xfa, 0xd4, 0xe5, 0x08, 0xc0, 0x98, 0xb9, 0xa7, 0xe1, 0xd8, 0xfe, 0xb1, 0x99, 0x55, 0xfb, 0x02, 0xba, 0x96, 0x75, 0x58, 0x50, 0x78, 0x71, 0x09, 0x69, 0xd3, 0x44, 0x0f, 0x50, 0x54, 0xe0],
+    [0xf9, 0xdc, 0x3e, 0x7f, 0xe0, 0x16, 0xe0, 0x50, 0xef, 0xf2, 0x60, 0x33, 0x4f, 0x18, 0xa5, 0xd4, 0xfe, 0x39, 0x1d, 0x82, 0x09, 0x23, 0x19, 0xf5, 0x96, 0x4f, 0x2e, 0x2e, 0xb7, 0xc1, 0xc3, 0xa5],
+    [0xf8, 0xb1, 0x3a, 0x49, 0xe2, 0x82, 0xf6, 0x09, 0xc3, 0x17, 0xa8, 0x33, 0xfb, 0x8d, 0x97, 0x6d, 0x11, 0x51, 0x7c, 0x57, 0x1d, 0x12, 0x21, 0xa2, 0x65, 0xd2, 0x5a, 0xf7, 0x78, 0xec, 0xf8, 0x92],
+    [0x34, 0x90, 0xc6, 0xce, 0xeb, 0x45, 0x0a, 0xec, 0xdc, 0x82, 0xe2, 0x82, 0x93, 0x03, 0x1d, 0x10, 0xc7, 0xd7, 0x3b, 0xf8, 0x5e, 0x57, 0xbf, 0x04, 0x1a, 0x97, 0x36, 0x0a, 0xa2, 0xc5, 0xd9, 0x9c],
+    [0xc1, 0xdf, 0x82, 0xd9, 0xc4, 0xb8, 0x74, 0x13, 0xea, 0xe2, 0xef, 0x04, 0x8f, 0x94, 0xb4, 0xd3, 0x55, 0x4c, 0xea, 0x73, 0xd9, 0x2b, 0x0f, 0x7a, 0xf9, 0x6e, 0x02, 0x71, 0xc6, 0x91, 0xe2, 0xbb],
+    [0x5c, 0x67, 0xad, 0xd7, 0xc6, 0xca, 0xf3, 0x02, 0x25, 0x6a, 0xde, 0xdf, 0x7a, 0xb1, 0x14, 0xda, 0x0a, 0xcf, 0xe8, 0x70, 0xd4, 0x49, 0xa3, 0xa4, 0x89, 0xf7, 0x81, 0xd6, 0x59, 0xe8, 0xbe, 0xcc],
+    [0xda, 0x7b, 0xce, 0x9f, 0x4e, 0x86, 0x18, 0xb6, 0xbd, 0x2f, 0x41, 0x32, 0xce, 0x79, 0x8c, 0xdc, 0x7a, 0x60, 0xe7, 0xe1, 0x46, 0x0a, 0x72, 0x99, 0xe3, 0xc6, 0x34, 0x2a, 0x57, 0x96, 0x26, 0xd2],
+    [0x27, 0x33, 0xe5, 0x0f, 0x52, 0x6e, 0xc2, 0xfa, 0x19, 0xa2, 0x2b, 0x31, 0xe8, 0xed, 0x50, 0xf2, 0x3c, 0xd1, 0xfd, 0xf9, 0x4c, 0x91, 0x54, 0xed, 0x3a, 0x76, 0x09, 0xa2, 0xf1, 0xff, 0x98, 0x1f],
+    [0xe1, 0xd3, 0xb5, 0xc8, 0x07, 0xb2, 0x81, 0xe4, 0x68, 0x3c, 0xc6, 0xd6, 0x31, 0x5c, 0xf9, 0x5b, 0x9a, 0xde, 0x86, 0x41, 0xde, 0xfc, 0xb3, 0x23, 0x72, 0xf1, 0xc1, 0x26, 0xe3, 0x98, 0xef, 0x7a],
+    [0x5a, 0x2d, 0xce, 0x0a, 0x8a, 0x7f, 0x68, 0xbb, 0x74, 0x56, 0x0f, 0x8f, 0x71, 0x83, 0x7c, 0x2c, 0x2e, 0xbb, 0xcb, 0xf7, 0xff, 0xfb, 0x42, 0xae, 0x18, 0x96, 0xf1, 0x3f, 0x7c, 0x74, 0x79, 0xa0],
+    [0xb4, 0x6a, 0x28, 0xb6, 0xf5, 0x55, 0x40, 0xf8, 0x94, 0x44, 0xf6, 0x3d, 0xe0, 0x37, 0x8e, 0x3d, 0x12, 0x1b, 0xe0, 0x9e, 0x06, 0xcc, 0x9d, 0xed, 0x1c, 0x20, 0xe6, 0x58, 0x76, 0xd3, 0x6a, 0xa0],
+    [0xc6, 0x5e, 0x96, 0x45, 0x64, 0x47, 0x86, 0xb6, 0x20, 0xe2, 0xdd, 0x2a, 0xd6, 0x48, 0xdd, 0xfc, 0xbf, 0x4a, 0x7e, 0x5b, 0x1a, 0x3a, 0x4e, 0xcf, 0xe7, 0xf6, 0x46, 0x67, 0xa3, 0xf0, 0xb7, 0xe2],
+    [0xf4, 0x41, 0x85, 0x88, 0xed, 0x35, 0xa2, 0x45, 0x8c, 0xff, 0xeb, 0x39, 0xb9, 0x3d, 0x26, 0xf1, 0x8d, 0x2a, 0xb1, 0x3b, 0xdc, 0xe6, 0xae, 0xe5, 0x8e, 0x7b, 0x99, 0x35, 0x9e, 0xc2, 0xdf, 0xd9],
+    [0x5a, 0x9c, 0x16, 0xdc, 0x00, 0xd6, 0xef, 0x18, 0xb7, 0x93, 0x3a, 0x6f, 0x8d, 0xc6, 0x5c, 0xcb, 0x55, 0x66, 0x71, 0x38, 0x77, 0x6f, 0x7d, 0xea, 0x10, 0x10, 0x70, 0xdc, 0x87, 0x96, 0xe3, 0x77],
+    [0x4d, 0xf8, 0x4f, 0x40, 0xae, 0x0c, 0x82, 0x29, 0xd0, 0xd6, 0x06, 0x9e, 0x5c, 0x8f, 0x39, 0xa7, 0xc2, 0x99, 0x67, 0x7a, 0x09, 0xd3, 0x67, 0xfc, 0x7b, 0x05, 0xe3, 0xbc, 0x38, 0x0e, 0xe6, 0x52],
+    [0xcd, 0xc7, 0x25, 0x95, 0xf7, 0x4c, 0x7b, 0x10, 0x43, 0xd0, 0xe1, 0xff, 0xba, 0xb7, 0x34, 0x64, 0x8c, 0x83, 0x8d, 0xfb, 0x05, 0x27, 0xd9, 0x71, 0xb6, 0x02, 0xbc, 0x21, 0x6c, 0x96, 0x19, 0xef],
+    [0x0a, 0xbf, 0x5a, 0xc9, 0x74, 0xa1, 0xed, 0x57, 0xf4, 0x05, 0x0a, 0xa5, 0x10, 0xdd, 0x9c, 0x74, 0xf5, 0x08, 0x27, 0x7b, 0x39, 0xd7, 0x97, 0x3b, 0xb2, 0xdf, 0xcc, 0xc5, 0xee, 0xb0, 0x61, 0x8d],
+    [0xb8, 0xcd, 0x74, 0x04, 0x6f, 0xf3, 0x37, 0xf0, 0xa7, 0xbf, 0x2c, 0x8e, 0x03, 0xe1, 0x0f, 0x64, 0x2c, 0x18, 0x86, 0x79, 0x8d, 0x71, 0x80, 0x6a, 0xb1, 0xe8, 0x88, 0xd9, 0xe5, 0xee, 0x87, 0xd0],
+    [0x83, 0x8c, 0x56, 0x55, 0xcb, 0x21, 0xc6, 0xcb, 0x83, 0x31, 0x3b, 0x5a, 0x63, 0x11, 0x75, 0xdf, 0xf4, 0x96, 0x37, 0x72, 0xcc, 0xe9, 0x10, 0x81, 0x88, 0xb3, 0x4a, 0xc8, 0x7c, 0x81, 0xc4, 0x1e],
+    [0x66, 0x2e, 0xe4, 0xdd, 0x2d, 0xd7, 0xb2, 0xbc, 0x70, 0x79, 0x61, 0xb1, 0xe6, 0x46, 0xc4, 0x04, 0x76, 0x69, 0xdc, 0xb6, 0x58, 0x4f, 0x0d, 0x8d, 0x77, 0x0d, 0xaf, 0x5d, 0x7e, 0x7d, 0xeb, 0x2e],
+    [0x38, 0x8a, 0xb2, 0x0e, 0x25, 0x73, 0xd1, 0x71, 0xa8, 0x81, 0x08, 0xe7, 0x9d, 0x82, 0x0e, 0x98, 0xf2, 0x6c, 0x0b, 0x84, 0xaa, 0x8b, 0x2f, 0x4a, 0xa4, 0x96, 0x8d, 0xbb, 0x81, 0x8e, 0xa3, 0x22],
+    [0x93, 0x23, 0x7c, 0x50, 0xba, 0x75, 0xee, 0x48, 0x5f, 0x4c, 0x22, 0xad, 0xf2, 0xf7, 0x41, 0x40, 0x0b, 0xdf, 0x8d, 0x6a, 0x9c, 0xc7, 0xdf, 0x7e, 0xca, 0xe5, 0x76, 0x22, 0x16, 0x65, 0xd7, 0x35],
+    [0x84, 0x48, 0x81, 0x8b, 0xb4, 0xae, 0x45, 0x62, 0x84, 0x9e, 0x94, 0x9e, 0x17, 0xac, 0x16, 0xe0, 0xbe, 0x16, 0x68, 0x8e, 0x15, 0x6b, 0x5c, 0xf1, 0x5e, 0x09, 0x8c, 0x62, 0x7c, 0x00, 0x56, 0xa9],
+    [0x27, 0xae, 0x5b, 0xa0, 0x8d, 0x72, 0x91, 0xc9, 0x6c, 0x8c, 0xbd, 0xdc, 0xc1, 0x48, 0xbf, 0x48, 0xa6, 0xd6, 0x8c, 0x79, 0x74, 0xb9, 0x43, 0x56, 0xf5, 0x37, 0x54, 0xef, 0x61, 0x71, 0xd7, 0x57],
+    [0xbf, 0x55, 0x8b, 0xeb, 0xd2, 0xce, 0xec, 0x7f, 0x3c, 0x5d, 0xce, 0x04, 0xa4, 0x78, 0x2f, 0x88, 0xc2, 0xc6, 0x03, 0x6a, 0xe7, 0x8e, 0xe2, 0x06, 0xd0, 0xbc, 0x52, 0x89, 0xd2, 0x04, 0x61, 0xa2],
+    [0xe2, 0x19, 0x08, 0xc2, 0x96, 0x8c, 0x06, 0x99, 0x04, 0x0a, 0x6f, 0xd8, 0x66, 0xa5, 0x77, 0xa9, 0x9a, 0x9d, 0x2e, 0xc8, 0x87, 0x45, 0xc8, 0x15, 0xfd, 0x4a, 0x47, 0x2c, 0x78, 0x92, 0x44, 0xda],
+    [0xae, 0x82, 0x4d, 0x72, 0xdd, 0xc2, 0x72, 0xaa, 0xb6, 0x8a, 0x8c, 0x30, 0x22, 0xe3, 0x6f, 0x10, 0x45, 0x44, 0x37, 0xc1, 0x88, 0x6f, 0x3f, 0xf9, 0x92, 0x7b, 0x64, 0xf2, 0x32, 0xdf, 0x41, 0x4f],
+    [0x27, 0xe4, 0x29, 0xa4, 0xbe, 0xf3, 0x08, 0x3b, 0xc3, 0x1a, 0x67, 0x1d, 0x04, 0x6e, 0xa5, 0xc1, 0xf5, 0xb8, 0xc3, 0x09, 0x4d, 0x72, 0x86, 0x8d, 0x9d, 0xfd, 0xc1, 0x2c, 0x73, 0x34, 0xac, 0x5f],
+    [0x74, 0x3c, 0xc5, 0xc3, 0x65, 0xa9, 0xa6, 0xa1, 0x5c, 0x1f, 0x24, 0x0a, 0xc2, 0x58, 0x80, 0xc7, 0xa9, 0xd1, 0xde, 0x29, 0x06, 0x96, 0xcb, 0x76, 0x60, 0x74, 0xa1, 0xd8, 0x3d, 0x92, 0x78, 0x16],
+    [0x4a, 0xdc, 0xf6, 0x16, 0xc3, 0xbf, 0xab, 0xf6, 0x39, 0x99, 0xa0, 0x19, 0x66, 0xc9, 0x98, 0xb7, 0xbb, 0x57, 0x27, 0x74, 0x03, 0x5a, 0x63, 0xea, 0xd4, 0x9d, 0xa7, 0x3b, 0x59, 0x87, 0xf3, 0x47],
+    [0x75, 0x78, 0x66, 0x45, 0xd0, 0xc5, 0xdd, 0x7c, 0x04, 0xa2, 0xf8, 0xa7, 0x5d, 0xca, 0xe0, 0x85, 0x21, 0x36, 0x52, 0xf5, 0xbc, 0xe3, 0xea, 0x8b, 0x9b, 0x9b, 0xed, 0xd1, 0xca, 0xb3, 0xc5, 0xe9],
+    [0xb8, 0x8b, 0x15, 0x2c, 0x9b, 0x8a, 0x7b, 0x79, 0x63, 0x7d, 0x35, 0x91, 0x18, 0x48, 0xb0, 0xc4, 0x1e, 0x7c, 0xc7, 0xcc, 0xa2, 0xab, 0x4f, 0xe9, 0xa1, 0x5f, 0x9c, 0x38, 0xbb, 0x4b, 0xb9, 0x39],
+    [0x0c, 0x4e, 0x2d, 0x8c, 0xe8, 0x34, 0xff, 0xd7, 0xa6, 0xcd, 0x85, 0xd7, 0x11, 0x3d, 0x45, 0x21, 0xab, 0xb8, 0x57, 0x77, 0x48, 0x45, 0xc4, 0x29, 0x1e, 0x6f, 0x6d, 0x01, 0x0d, 0x97, 0xe3, 0x18],
+    [0x5b, 0xc7, 0x99, 0xd8, 0x3e, 0x3b, 0xb3, 0x15, 0x01, 0xb3, 0xda, 0x78, 0x66, 0x80, 0xdf, 0x30, 0xfb, 0xc1, 0x8e, 0xb4, 0x1c, 0xbc, 0xe6, 0x11, 0xe8, 0xc0, 0xe9, 0xc7, 0x2f, 0x69, 0x57, 0x1c],
+    [0xa1, 0x0d, 0x3e, 0xf8, 0x57, 0xd0, 0x4d, 0x9c, 0x03, 0xea, 0xd7, 0xc6, 0x31, 0x7d, 0x79, 0x7a, 0x09, 0x0f, 0xa1, 0x27, 0x1a, 0xd9, 0xc7, 0xad, 0xdf, 0xbc, 0xb4, 0x12, 0xe9, 0x64, 0x3d, 0x4f],
+    [0xb3, 0x3b, 0x18, 0x09, 0xc4, 0x26, 0x23, 0xf4, 0x74, 0x05, 0x5f, 0xa9, 0x40, 0x0a, 0x20, 0x27, 0xa7, 0xa8, 0x85, 0xc8, 0xdf, 0xa4, 0xef, 0xe2, 0x06, 0x66, 0xb4, 0xee, 0x27, 0xd7, 0x52, 0x9c],
+    [0x13, 0x4d, 0x7f, 0x28, 0xd5, 0x3f, 0x17, 0x5f, 0x6b, 0xf4, 0xb6, 0x2f, 0xaa, 0x21, 0x10, 0xd5, 0xb7, 0x6f, 0x0f, 0x77, 0x0c, 0x15, 0xe6, 0x28, 0x18, 0x1c, 0x1f, 0xcc, 0x18, 0xf9, 0x70, 0xa9],
+    [0xc3, 0x4d, 0x24, 0xb2, 0xfc, 0x8c, 0x50, 0xca, 0x9c, 0x07, 0xa7, 0x15, 0x6e, 0xf4, 0xe5, 0xff, 0x4b, 0xdf, 0x00, 0x2e, 0xda, 0x0b, 0x11, 0xc1, 0xd3, 0x59, 0xd0, 0xb5, 0x9a, 0x54, 0x68, 0x07],
+    [0x04, 0xdb, 0xb9, 0xdb, 0x63, 0x14, 0x57, 0x87, 0x9b, 0x27, 0xe0, 0xdf, 0xdb, 0xe5, 0x01, 0x58, 0xfd, 0x9c, 0xf9, 0xb4, 0xcf, 0x77, 0x60, 0x5c, 0x4a, 0xc4, 0xc9, 0x5b, 0xd6, 0x5f, 0xc9, 0xf6],
+    [0xf9, 0x29, 0x5a, 0x68, 0x66, 0x47, 0xcb, 0x99, 0x90, 0x90, 0x81, 0x9c, 0xda, 0x70, 0x08, 0x20, 0xc2, 0x82, 0xc6, 0x13, 0xce, 0xdc, 0xd2, 0x18, 0x54, 0x0b, 0xbc, 0x6f, 0x37, 0xb0, 0x1c, 0x65],
+    [0x67, 0xc4, 0xa1, 0xea, 0x62, 0x4f, 0x09, 0x2a, 0x3a, 0x5c, 0xca, 0x2d, 0x6f, 0x0f, 0x0d, 0xb2, 0x31, 0x97, 0x2f, 0xce, 0x62, 0x7f, 0x0e, 0xcc, 0xa0, 0xde, 0xe6, 0x0f, 0x17, 0x55, 0x1c, 0x5f],
+    [0x8f, 0xda, 0xeb, 0x5a, 0xb5, 0x60, 0xb2, 0xce, 0xb7, 0x81, 0xcd, 0xb3, 0x39, 0x36, 0x1a, 0x0f, 0xbe, 0xe1, 0xb9, 0xdf, 0xfa, 0xd5, 0x91, 0x15, 0x13, 0x8c, 0x8d, 0x6a, 0x70, 0xdd, 0xa9, 0xcc],
+    [0xc1, 0xbf, 0x0b, 0xbd, 0xd7, 0xfe, 0xe1, 0x57, 0x64, 0x84, 0x5d, 0xb8, 0x75, 0xf6, 0x43, 0x25, 0x59, 0xff, 0x8d, 0xbc, 0x90, 0x55, 0x32, 0x44, 0x31, 0xbc, 0x34, 0xe5, 0xb9, 0x3d, 0x15, 0xda],
+    [0x30, 0x73, 0x17, 0x84, 0x9e, 0xcc, 0xd9, 0x0c, 0x0c, 0x7b, 0x98, 0x87, 0x0b, 0x93, 0x17, 0xc1, 0x5a, 0x59, 0x59, 0xdc, 0xfb, 0x84, 0xc7, 0x6d, 0xcc, 0x90, 0x8c, 0x4f, 0xe6, 0xba, 0x92, 0x12],
+    [0x63, 0x39, 0xbf, 0x06, 0xe4, 0x58, 0xf6, 0x64, 0x6d, 0xf5, 0xe8, 0x3b, 0xa7, 0xc3, 0xd3, 0x5b, 0xc2, 0x63, 0xb3, 0x22, 0x2c, 0x8e, 0x90, 0x40, 0x06, 0x88, 0x47, 0x74, 0x9c, 0xa8, 0xe8, 0xf9],
+    [0x50, 0x45, 0xe4, 0x34, 0x2a, 0xeb, 0x52, 0x1e, 0xb3, 0xa5, 0x58, 0x7e, 0xc2, 0x68, 0xed, 0x3a, 0xa6, 0xfa, 0xf3, 0x2b, 0x62, 0xb0, 0xbc, 0x41, 0xa9, 0xd5, 0x49, 0x52, 0x1f, 0x40, 0x6f, 0xc3],
+    [0x08, 0x60, 0x1d, 0x83, 0xcd, 0xd3, 0x4b, 0x5f, 0x7b, 0x8d, 0xf6, 0x3e, 0x7b, 0x9a, 0x16, 0x51, 0x9d, 0x35, 0x47, 0x3d, 0x0b, 0x89, 0xc3, 0x17, 0xbe, 0xed, 0x3d, 0x3d, 0x94, 0x24, 0xb2, 0x53],
+    [0x84, 0xe3, 0x5c, 0x5d, 0x92, 0x17, 0x13, 0x76, 0xca, 0xe5, 0xc8, 0x63, 0x00, 0x82, 0x2d, 0x72, 0x9c, 0xd3, 0xa8, 0x47, 0x95, 0x83, 0xbe, 0xf0, 0x95, 0x27, 0x02, 0x7d, 0xba, 0x5f, 0x11, 0x26],
+    [0x3c, 0x5c, 0xbb, 0xeb, 0x38, 0x34, 0xb7, 0xa5, 0xc1, 0xcb, 0xa9, 0xaa, 0x5f, 0xee, 0x0c, 0x95, 0xec, 0x3f, 0x17, 0xa3, 0x3e, 0xc3, 0xd8, 0x04, 0x7f, 0xff, 0x79, 0x91, 0x87, 0xf5, 0xae, 0x20],
+    [0x40, 0xbb, 0xe9, 0x13, 0xc2, 0x26, 0xc3, 0x4c, 0x9f, 0xbe, 0x43, 0x89, 0xdd, 0x72, 0x89, 0x84, 0x25, 0x7a, 0x81, 0x68, 0x92, 0xb3, 0xca, 0xe3, 0xe4, 0x31, 0x91, 0xdd, 0x29, 0x1f, 0x0e, 0xb5],
+    [0x14, 0xaf, 0x53, 0x85, 0xbc, 0xbb, 0x1e, 0x47, 0x38, 0xbb, 0xae, 0x81, 0x06, 0x04, 0x6e, 0x6e, 0x2f, 0xca, 0x42, 0x87, 0x5a, 0xa5, 0xc0, 0x00, 0xc5, 0x82, 0x58, 0x77, 0x42, 0xbc, 0xc7, 0x48],
+    [0x72, 0xf2, 0x96, 0x56, 0x80, 0x3c, 0x2f, 0x4b, 0xe1, 0x77, 0xb1, 0xb8, 0xdd, 0x2a, 0x51, 0x37, 0x89, 0x2b, 0x08, 0x0b, 0x02, 0x21, 0x00, 0xfd, 0xe4, 0xe9, 0x6d, 0x93, 0xef, 0x8c, 0x96, 0xff],
+    [0xd0, 0x6f, 0x27, 0x06, 0x1c, 0x73, 0x4d, 0x78, 0x25, 0xb4, 0x68, 0x65, 0xd0, 0x0a, 0xa9, 0x00, 0xe5, 0xcc, 0x3a, 0x36, 0x72, 0x08, 0x0e, 0x52, 0x71, 0x71, 0xe1, 0x17, 0x1a, 0xa5, 0x03, 0x8a],
+    [0x28, 0x20, 0x39, 0x85, 0xb5, 0xf2, 0xd8, 0x77, 0x09, 0x17, 0x16, 0x78, 0x16, 0x97, 0x39, 0xf9, 0x57, 0xd2, 0x74, 0x5f, 0x4b, 0xfa, 0x5c, 0xc9, 0x1e, 0x2b, 0x4b, 0xd9, 0xbf, 0x48, 0x3b, 0x40],
+    [0x0a, 0x16, 0x29, 0x46, 0xe5, 0x61, 0x58, 0xba, 0xc0, 0x67, 0x3e, 0x6d, 0xd3, 0xbd, 0xfd, 0xc1, 0xe4, 0xa0, 0xe7, 0x74, 0x4a, 0x12, 0x0f, 0xdb, 0x64, 0x00, 0x50, 0xc8, 0xd7, 0xab, 0xe1, 0xc6],
+    [0xe2, 0xc3, 0xed, 0x40, 0x52, 0xee, 0xb1, 0xd6, 0x05, 0x14, 0xb4, 0xc3, 0x8e, 0xce, 0x8d, 0x73, 0xa2, 0x7f, 0x37, 0xfa, 0x5b, 0x36, 0xdc, 0xbf, 0x33, 0x8e, 0x70, 0xde, 0x95, 0x79, 0x8c, 0xaa],
+    [0x92, 0x6b, 0xf5, 0x20, 0xe7, 0xf4, 0x53, 0xdb, 0x47, 0x5d, 0xa4, 0x2b, 0x99, 0x4d, 0x94, 0x47, 0xde, 0x1f, 0x93, 0xee, 0x91, 0x50, 0x2a, 0x64, 0x74, 0x8e, 0x37, 0x1e, 0xd0, 0xd1, 0x20, 0x7e],
+    [0x75, 0x16, 0xde, 0x2f, 0x59, 0x95, 0xcf, 0xaf, 0xac, 0xfd, 0x70, 0xe1, 0xa2, 0x06, 0x72, 0x41, 0x74, 0x03, 0x88, 0xd3, 0x24, 0x34, 0x3a, 0x1e, 0xb5, 0xe7, 0x1a, 0x10, 0xf6, 0xbb, 0x32, 0x98],
+    [0xea, 0x54, 0x66, 0xfc, 0x04, 0xd6, 0x01, 0xab, 0x58, 0x31, 0x58, 0xb9, 0xc6, 0x62, 0x6c, 0xfb, 0xb6, 0x66, 0x40, 0xd7, 0xdc, 0x22, 0x9a, 0xfa, 0xa5, 0x9f, 0xd5, 0x2f, 0xf4, 0x15, 0x18, 0x0b],
+    [0x80, 0x6f, 0x45, 0xe8, 0x8b, 0x00, 0x8f, 0x79, 0xb4, 0x75, 0x85, 0xbd, 0xf3, 0x22, 0xbe, 0x73, 0x56, 0x0c, 0xb0, 0x9d, 0x6c, 0xac, 0x65, 0x53, 0x4e, 0x87, 0x64, 0xba, 0xe6, 0x8a, 0x60, 0x7e],
+    [0x84, 0x56, 0x10, 0x77, 0x23, 0xbf, 0x83, 0xd4, 0xa6, 0x7b, 0xe0, 0x58, 0x60, 0xc6, 0x73, 0x0f, 0x85, 0x40, 0xe4, 0x9c, 0xa0, 0xf5, 0x15, 0x34, 0x4c, 0xa8, 0x3a, 0x07, 0x6c, 0x62, 0x2f, 0xf5],
+    [0x98, 0x37, 0xf7, 0x94, 0x82, 0x7f, 0x98, 0xf0, 0x48, 0x58, 0x7b, 0x3f, 0x2d, 0x2b, 0x67, 0x81, 0x7b, 0x34, 0x82, 0x10, 0x97, 0xcc, 0x60, 0x06, 0x9d, 0xd1, 0x5d, 0x62, 0xee, 0x52, 0x27, 0x3d],
+    [0x0c, 0xfb, 0xd2, 0x78, 0x1f, 0x72, 0x95, 0x5d, 0xf5, 0xe3, 0x3f, 0xc4, 0xb3, 0x04, 0xfc, 0x36, 0x2f, 0xb1, 0x63, 0x79, 0x74, 0x57, 0x5f, 0x16, 0x67, 0x68, 0xf5, 0x6d, 0xa0, 0x9b, 0x1f, 0xc9],
+    [0x98, 0xfd, 0xc3, 0xf9, 0xb8, 0x23, 0x00, 0x5b, 0x50, 0x70, 0x65, 0xb5, 0x8c, 0x62, 0x2a, 0xac, 0x45, 0xef, 0xed, 0xa4, 0x17, 0x06, 0x77, 0x56, 0x68, 0xb3, 0x7a, 0x23, 0x0d, 0x8a, 0xff, 0x9e],
+    [0xdf, 0x0b, 0x5e, 0x5d, 0xb8, 0x97, 0x3f, 0xa1, 0x36, 0xe3, 0xfb, 0xa2, 0xfa, 0xab, 0xd1, 0x66, 0x77, 0x49, 0x42, 0x66, 0xa1, 0xbd, 0xc6, 0xb0, 0xa8, 0xdd, 0x7a, 0xa1, 0x87, 0xb5, 0x60, 0xb2],
+    [0xe6, 0x7e, 0xfc, 0x71, 0x67, 0x06, 0x8b, 0x5c, 0xa5, 0xfd, 0xde, 0xec, 0xd6, 0x87, 0x27, 0xdc, 0xe6, 0xb0, 0x39, 0x62, 0xf3, 0x56, 0xdf, 0x86, 0xed, 0xf3, 0x25, 0x81, 0xc5, 0xe2, 0xf1, 0x42],
+    [0xac, 0x69, 0x25, 0x25, 0xa1, 0x4b, 0x46, 0x9f, 0xa4, 0xc0, 0x06, 0x9e, 0xff, 0x41, 0x10, 0x01, 0xaa, 0xc6, 0xc4, 0x2d, 0x34, 0x38, 0xf0, 0x43, 0xeb, 0x9c, 0x31, 0x15, 0xec, 0x79, 0xb5, 0x46],
+    [0x42, 0x0f, 0x9e, 0x2a, 0x54, 0x12, 0x1e, 0xef, 0x63, 0x53, 0x0c, 0x80, 0x90, 0x9e, 0xae, 0xd6, 0x1f, 0xff, 0xac, 0xb8, 0xaf, 0x31, 0x07, 0x4d, 0xf7, 0x95, 0xe8, 0xd1, 0x6d, 0x9d, 0x77, 0xc0],
+    [0x57, 0x2b, 0x22, 0x0b, 0xc6, 0xda, 0x4c, 0xc9, 0x90, 0xac, 0xf6, 0xcb, 0x2e, 0x3c, 0x8a, 0x42, 0x64, 0x08, 0xcb, 0x90, 0xc1, 0x29, 0x8e, 0x38, 0x69, 0xcd, 0x55, 0x66, 0x06, 0x25, 0xb2, 0xc3],
+    [0x3c, 0x2d, 0xd8, 0x35, 0xb3, 0xf3, 0x7d, 0x72, 0x59, 0x2e, 0xe7, 0x6e, 0xbc, 0xed, 0x8b, 0x1a, 0x15, 0x31, 0x0b, 0x82, 0x4a, 0x0d, 0x62, 0x40, 0x92, 0x63, 0xf1, 0x59, 0x4d, 0xa5, 0x21, 0x71],
+    [0x10, 0xd9, 0xf6, 0xbb, 0xcc, 0x5f, 0xcd, 0x9c, 0xf7, 0xa8, 0xfc, 0xd3, 0x7a, 0x14, 0xdc, 0x7d, 0x71, 0x9f, 0xad, 0xbc, 0x7f, 0x75, 0xf9, 0x8e, 0xbe, 0xbb, 0x07, 0x19, 0x39, 0x7c, 0x50, 0xc1],
+    [0x33, 0x24, 0xb6, 0xaf, 0x8c, 0x3c, 0x1a, 0x13, 0x49, 0x06, 0x34, 0x58, 0x24, 0xea, 0x56, 0x11, 0x5f, 0xf5, 0xd4, 0x6e, 0x86, 0x33, 0x94, 0xed, 0xec, 0x5b, 0x4e, 0x60, 0x89, 0xe9, 0x9d, 0x95],
+    [0xd7, 0x0c, 0x64, 0x60, 0xf4, 0x98, 0xb1, 0x0e, 0xb3, 0xa4, 0xe7, 0x39, 0xe1, 0x8f, 0x8e, 0xd1, 0x01, 0x10, 0xd8, 0x9f, 0xb3, 0x5d, 0xe3, 0x50, 0xc1, 0xe0, 0x7f, 0xf7, 0xa3, 0x00, 0xf9, 0xfb],
+    [0x21, 0xdf, 0xaa, 0x81, 0x64, 0xb3, 0x1e, 0x8d, 0x7c, 0x6d, 0xc1, 0xe1, 0xd2, 0x93, 0x44, 0x00, 0x1b, 0xe3, 0xca, 0x30, 0xc6, 0x44, 0x6b, 0x89, 0x03, 0xf5, 0x47, 0x65, 0x53, 0xd9, 0x4c, 0x63],
+    [0x0d, 0x10, 0x64, 0xe0, 0xc1, 0x61, 0x8c, 0x57, 0x51, 0x7f, 0xc1, 0x6a, 0x4c, 0x2f, 0x36, 0x0c, 0xd9, 0x08, 0x94, 0x64, 0x79, 0x4d, 0x8d, 0x90, 0x7c, 0x85, 0xd0, 0xa2, 0x86, 0xa0, 0x2d, 0xa6],
+    [0x3c, 0x29, 0xcc, 0x10, 0x06, 0xa2, 0x06, 0x2d, 0x4a, 0xc5, 0x2a, 0xf1, 0x24, 0xa4, 0x2a, 0x0d, 0x93, 0x7f, 0x12, 0x02, 0x60, 0x43, 0x4e, 0xb3, 0xb2, 0x34, 0xc7, 0x4f, 0xde, 0x82, 0x56, 0xcc],
+    [0xba, 0x6b, 0xd2, 0x2d, 0x33, 0xb9, 0xfe, 0x20, 0x10, 0xee, 0xcc, 0x76, 0x63, 0x98, 0x3e, 0x56, 0xd3, 0x9a, 0x9b, 0x3a, 0x6e, 0xf9, 0xba, 0x7a, 0xd2, 0x52, 0xad, 0x47, 0xbb, 0x32, 0x68, 0xc4],
+    [0xe5, 0xae, 0xd5, 0xfd, 0x23, 0x36, 0x86, 0x2f, 0xf3, 0x3e, 0x16, 0x48, 0xd0, 0xfa, 0x05, 0x85, 0x20, 0xdb, 0xe0, 0x03, 0x04, 0x9d, 0xfe, 0x24, 0x33, 0xd6, 0xf9, 0xe3, 0x30, 0x5a, 0xa0, 0x8d],
+    [0x72, 0x75, 0x27, 0x5a, 0x0e, 0xa9, 0x7b, 0xde, 0x92, 0xcd, 0x92, 0xfd, 0xd5, 0x7d, 0x47, 0x2b, 0x4d, 0xb9, 0x85, 0xd2, 0x81, 0x49, 0x51, 0xc8, 0x83, 0x73, 0xf3, 0xbb, 0xdc, 0xa8, 0x41, 0x31],
+    [0x17, 0xe6, 0xb3, 0xaa, 0x58, 0x1f, 0x97, 0x2e, 0x33, 0x14, 0x45, 0xb8, 0xc6, 0x61, 0x89, 0x41, 0x78, 0xae, 0x88, 0x52, 0x20, 0x1c, 0x8d, 0xf3, 0x47, 0x06, 0xd1, 0xdf, 0xb6, 0xc9, 0x2f, 0x75],
+    [0xae, 0xac, 0x70, 0xa8, 0x53, 0x97, 0x03, 0x0d, 0x84, 0x19, 0x3a, 0x54, 0xa6, 0xe5, 0x96, 0x75, 0x0e, 0x6a, 0xb3, 0x92, 0x6b, 0x53, 0x0c, 0x12, 0x7a, 0x17, 0xc9, 0xe2, 0x4b, 0x20, 0xb8, 0xce],
+    [0xbc, 0x12, 0x43, 0x6b, 0xa2, 0xb2, 0xce, 0xe8, 0x58, 0x6c, 0xc4, 0x71, 0x45, 0x22, 0xd1, 0x86, 0x6f, 0xa0, 0xe9, 0x49, 0xbd, 0xe4, 0x2a, 0xd7, 0xd9, 0x64, 0x5c, 0xdd, 0x8f, 0xe5, 0x8b, 0xa0],
+    [0x49, 0xc4, 0x0f, 0xe4, 0x83, 0x4a, 0x5e, 0x78, 0xff, 0x1a, 0x24, 0xb3, 0x7b, 0x6e, 0x40, 0x11, 0x9f, 0x52, 0x0d, 0xe7, 0x3a, 0x0c, 0xb5, 0x14, 0x79, 0xd9, 0x1d, 0x73, 0xcd, 0xeb, 0xab, 0x8f],
+    [0xd8, 0xdf, 0x82, 0x4c, 0x86, 0x18, 0x14, 0x31, 0x1d, 0xfc, 0x86, 0x4f, 0x00, 0x25, 0x3a, 0xce, 0x3f, 0x67, 0x45, 0xcb, 0xc9, 0x56, 0x28, 0x06, 0x18, 0x6f, 0x74, 0xac, 0x95, 0x78, 0x57, 0xc1],
+    [0xdb, 0xf0, 0xc1, 0xa5, 0x31, 0x6e, 0x43, 0x76, 0x2e, 0x4a, 0x4e, 0xa4, 0x43, 0xb9, 0x45, 0x8e, 0x97, 0xe8, 0xa6, 0xe7, 0x99, 0x14, 0x21, 0xbb, 0x2e, 0xb1, 0xf8, 0xf3, 0x46, 0x80, 0x4d, 0x2b],
+    [0xa9, 0x4c, 0x80, 0xc7, 0x90, 0x17, 0xfd, 0x7f, 0xf0, 0xe1, 0x34, 0xc5, 0xbd, 0xee, 0x69, 0x86, 0x7a, 0x58, 0x0c, 0xd0, 0xaf, 0xf9, 0xf1, 0xd6, 0x7f, 0x36, 0xa9, 0x78, 0x5c, 0x02, 0xe9, 0x20],
+    [0x96, 0x6e, 0x6f, 0x6d, 0x7f, 0x64, 0x44, 0x67, 0xcf, 0xe2, 0x8d, 0xc9, 0xf9, 0xdb, 0x78, 0xd5, 0xa0, 0x25, 0xad, 0x51, 0x17, 0xfc, 0x56, 0x41, 0x18, 0x47, 0x24, 0x59, 0x0e, 0x76, 0x54, 0xe4],
+    [0x92, 0x9b, 0xdd, 0x7f, 0xee, 0xe9, 0x22, 0x94, 0x94, 0xa3, 0xea, 0xfc, 0xc2, 0xee, 0xea, 0x3a, 0x5f, 0x51, 0xdb, 0x20, 0xf2, 0x6f, 0xdb, 0x8a, 0x9d, 0x13, 0x34, 0x45, 0x34, 0xd8, 0xf1, 0xfb],
+    [0x81, 0xb2, 0x4f, 0x93, 0xbd, 0xe1, 0x39, 0x5b, 0x53, 0x89, 0x44, 0xe7, 0x40, 0x85, 0x5d, 0x14, 0x4d, 0x99, 0x6f, 0xce, 0xba, 0xab, 0x44, 0x76, 0x37, 0xfb, 0x47, 0xa7, 0xe2, 0xb7, 0x4e, 0x20],
+    [0x04, 0xfa, 0x58, 0x4f, 0x5a, 0x85, 0x2d, 0x22, 0xed, 0x2a, 0x18, 0x34, 0x96, 0x4d, 0xa6, 0xd2, 0x67, 0xe7, 0xbc, 0xa3, 0xd9, 0xf3, 0xa9, 0x51, 0x3c, 0x6c, 0x07, 0x7f, 0xec, 0x5a, 0x95, 0x01],
+    [0x84, 0x7e, 0xb5, 0x42, 0xc8, 0x07, 0x8d, 0xae, 0x0f, 0x41, 0x95, 0xc0, 0xfc, 0x6f, 0x00, 0x34, 0x18, 0x51, 0xf2, 0x6f, 0x58, 0x96, 0x3e, 0xfc, 0x59, 0xe5, 0x1c, 0x42, 0xbf, 0xe6, 0x3b, 0xfc],
+    [0x1c, 0x54, 0x1f, 0x19, 0xd0, 0x4d, 0xfa, 0xec, 0x84, 0x2b, 0x7d, 0x79, 0x0b, 0x18, 0x59, 0x04, 0xde, 0x53, 0x50, 0x3e, 0xa4, 0xcc, 0x6f, 0x78, 0xfd, 0x3f, 0x17, 0x54, 0x90, 0x82, 0xa6, 0xad],
+    [0x10, 0x38, 0xdd, 0xe7, 0xa3, 0x7f, 0x37, 0xba, 0xb1, 0x44, 0x46, 0x6e, 0xe2, 0x9a, 0xfe, 0xd9, 0x61, 0x79, 0xe9, 0xf3, 0x24, 0xae, 0xa5, 0x8d, 0x4f, 0xe9, 0x91, 0x03, 0x6f, 0x1e, 0x2b, 0xee],
+    [0x7a, 0xb4, 0xe5, 0x29, 0xbc, 0xc4, 0x84, 0xec, 0xfa, 0xb2, 0x3a, 0xee, 0x96, 0x5a, 0xc0, 0xc3, 0x4d, 0xa4, 0x62, 0xd3, 0x14, 0xb3, 0x42, 0x71, 0xa5, 0xd5, 0x47, 0x68, 0x39, 0x9d, 0x75, 0x82],
+    [0x58, 0xc3, 0x1c, 0x13, 0x7d, 0xc3, 0xfc, 0x67, 0x0a, 0x6a, 0x08, 0xbe, 0xa8, 0x93, 0xf6, 0x34, 0x0e, 0x80, 0xad, 0x7d, 0x5f, 0x74, 0x4c, 0x90, 0xd8, 0x7f, 0x6f, 0xac, 0x83, 0xff, 0x44, 0x10],
+    [0x1c, 0x64, 0x57, 0xdf, 0xbe, 0xd1, 0xd1, 0x98, 0x2b, 0x43, 0x03, 0xe9, 0xc8, 0xf2, 0xaa, 0x8e, 0xfc, 0x3b, 0x36, 0xa0, 0xb7, 0x3b, 0x24, 0x00, 0x75, 0x03, 0xda, 0x39, 0x36, 0xb9, 0x64, 0x3b],
+    [0xaf, 0x04, 0xef, 0xc3, 0xd2, 0x1f, 0xa0, 0xdb, 0xd3, 0xd0, 0x96, 0x98, 0x59, 0xf4, 0x56, 0xbe, 0x53, 0xbc, 0x85, 0x5d, 0x66, 0x71, 0x53, 0xe9, 0x6a, 0x10, 0x77, 0xc2, 0x58, 0xf5, 0xc4, 0x7c],
+    [0xf6, 0x4f, 0x3a, 0xfe, 0x48, 0x25, 0xe2, 0x12, 0xef, 0xd8, 0xd5, 0xa0, 0x9b, 0x38, 0xc2, 0x49, 0x07, 0x15, 0x4b, 0x96, 0x2e, 0x87, 0xc1, 0x34, 0x94, 0x45, 0x3f, 0xe8, 0x43, 0x60, 0xf5, 0xaf],
+    [0x22, 0x15, 0xba, 0xa1, 0x29, 0x24, 0x3b, 0xb2, 0x48, 0x9b, 0x96, 0xeb, 0x07, 0x9f, 0x9f, 0xc5, 0x2f, 0xfc, 0x5a, 0x75, 0xfe, 0x44, 0xe4, 0xdc, 0x55, 0x25, 0x48, 0x0b, 0x08, 0xce, 0xc1, 0x00],
+    [0xc0, 0x16, 0xd6, 0x6a, 0x19, 0xe8, 0xc0, 0x3a, 0x88, 0xc7, 0xd2, 0xde, 0xb1, 0xc2, 0x26, 0x62, 0x64, 0xd0, 0xde, 0xf2, 0x76, 0x66, 0x8c, 0x15, 0x30, 0xe0, 0xb4, 0xd0, 0x79, 0x7f, 0x5b, 0xbd],
+    [0x21, 0x53, 0x7b, 0x08, 0x13, 0x60, 0x94, 0x08, 0x49, 0x5d, 0x2d, 0xa7, 0x92, 0x42, 0xfd, 0x95, 0x35, 0x00, 0x51, 0xc0, 0x55, 0xb2, 0x82, 0xe8, 0x80, 0x25, 0x1d, 0xc7, 0xef, 0x2c, 0x86, 0x04],
+    [0xce, 0x0e, 0x1a, 0x1e, 0xbd, 0x2d, 0xbf, 0x37, 0x88, 0xe4, 0x62, 0x9d, 0x9e, 0xdb, 0xe2, 0x3d, 0x03, 0x20, 0x59, 0x5c, 0xb4, 0xa2, 0x25, 0x99, 0x68, 0xbd, 0x18, 0x3f, 0xcf, 0xe7, 0x84, 0xf4],
+    [0x4e, 0x31, 0xc8, 0x48, 0x01, 0xb7, 0xc3, 0x0a, 0x7f, 0x6a, 0x11, 0x7b, 0x94, 0x09, 0xae, 0x82, 0x6a, 0x2d, 0x8a, 0xd0, 0x85, 0x6e, 0x90, 0xe5, 0x32, 0x5c, 0x02, 0xa1, 0x71, 0xfd, 0x40, 0x6c],
+    [0xf5, 0x0b, 0xbd, 0xf4, 0x27, 0xe8, 0x00, 0x23, 0x83, 0xef, 0x98, 0x9d, 0xf2, 0x38, 0x98, 0xc8, 0xfa, 0x2f, 0xfd, 0x6c, 0xfb, 0xcd, 0xa7, 0x5d, 0x9c, 0x0f, 0x38, 0x8b, 0x97, 0xb1, 0x8a, 0x37],
+    [0xcd, 0xeb, 0xa8, 0xb8, 0x3f, 0xb1, 0xb0, 0x0a, 0xd0, 0xea, 0x7a, 0x73, 0xd3, 0x3d, 0x5b, 0xfc, 0x63, 0xab, 0xbd, 0x02, 0x09, 0xa3, 0xa2, 0x5f, 0xc1, 0xc6, 0xf6, 0x12, 0xfc, 0xcd, 0x4b, 0x9e],
+    [0xec, 0x75, 0x0d, 0x05, 0xb6, 0xca, 0x92, 0x1b, 0x7d, 0xbf, 0xc5, 0xc8, 0x0e, 0x42, 0x7c, 0x89, 0xd2, 0xa0, 0x74, 0x6f, 0xfe, 0x88, 0x43, 0x91, 0x44, 0x5e, 0xb5, 0x87, 0x00, 0x54, 0x83, 0x74],
+    [0x86, 0xd5, 0x5a, 0x18, 0x6b, 0x3d, 0x97, 0x78, 0xb3, 0x12, 0xe5, 0x9b, 0xf6, 0x88, 0x39, 0x02, 0x07, 0x2e, 0x07, 0x8e, 0x05, 0x38, 0x78, 0x53, 0xe6, 0xa9, 0xda, 0xae, 0x02, 0xfa, 0x0c, 0xc3],
+    [0x39, 0x39, 0x08, 0xf0, 0xcf, 0x05, 0xa5, 0x9f, 0x17, 0x14, 0x70, 0x46, 0xa5, 0x1d, 0xe9, 0xe3, 0x37, 0x8b, 0x89, 0x88, 0xb7, 0x77, 0xf9, 0x7c, 0xd4, 0x8f, 0xbb, 0xd7, 0x6b, 0x4d, 0x30, 0x2f],
+    [0xbb, 0xe6, 0xe8, 0xee, 0xec, 0xa8, 0x13, 0x7f, 0x1b, 0xff, 0xc7, 0x40, 0x26, 0x4e, 0x3f, 0x51, 0x07, 0x83, 0x73, 0xa7, 0x4a, 0xad, 0x94, 0xbb, 0x7e, 0x06, 0x31, 0x8d, 0x04, 0x70, 0xfc, 0x17],
+    [0xaf, 0x78, 0x7d, 0x99, 0x46, 0x67, 0x65, 0x34, 0xa6, 0xae, 0x3b, 0x90, 0x52, 0xa0, 0x19, 0xe2, 0xec, 0x31, 0x5e, 0xa1, 0x06, 0x7c, 0x0c, 0xcf, 0xbe, 0x02, 0xd9, 0x3c, 0x28, 0x6c, 0x90, 0x82],
+    [0x8a, 0x9c, 0xd7, 0xb8, 0xb7, 0xfb, 0x4f, 0x8b, 0xc6, 0x89, 0x2d, 0xd9, 0x86, 0x20, 0x63, 0xdd, 0x80, 0x49, 0xc7, 0xa2, 0xba, 0x18, 0x69, 0xe9, 0x17, 0xac, 0x40, 0x58, 0xc4, 0xfa, 0xfa, 0x89],
+    [0x96, 0x2b, 0xf9, 0x3a, 0x87, 0x1b, 0xae, 0x8e, 0xcc, 0xa5, 0x39, 0x23, 0x5f, 0xd6, 0x4e, 0x11, 0xd2, 0x5f, 0xf2, 0x89, 0x2c, 0x6b, 0x56, 0x96, 0x98, 0x42, 0x47, 0xca, 0x1a, 0x06, 0xea, 0x6f],
+    [0xa6, 0x19, 0x32, 0x13, 0x1d, 0x5b, 0x86, 0xa8, 0x11, 0x22, 0xca, 0x2d, 0x99, 0xd9, 0xe8, 0xca, 0x85, 0xa8, 0x82, 0x3d, 0x38, 0x3e, 0x4d, 0xd5, 0x29, 0xce, 0x61, 0x67, 0xb3, 0x9b, 0x1b, 0x7a],
+    [0x0f, 0xfd, 0x58, 0x97, 0x71, 0x6f, 0x91, 0xd4, 0x81, 0xcb, 0x2f, 0xae, 0x38, 0xc1, 0xa7, 0x15, 0x04, 0x8d, 0x24, 0xf4, 0x9b, 0x48, 0x30, 0xed, 0x6e, 0x0f, 0x38, 0xdf, 0x40, 0x0a, 0xca, 0x73],
+    [0x80, 0xc0, 0xf8, 0x53, 0xe1, 0xe1, 0x15, 0x95, 0xf3, 0x8e, 0xb9, 0xce, 0x93, 0x2e, 0x81, 0xde, 0xca, 0xd7, 0x80, 0x6b, 0x6c, 0xe6, 0x26, 0xe6, 0x46, 0x64, 0xc6, 0x3d, 0x01, 0x61, 0x30, 0x0f],
+    [0xfe, 0x89, 0xd1, 0x59, 0x4b, 0x80, 0x77, 0xed, 0xa3, 0x0c, 0xe8, 0x9c, 0xe3, 0x7d, 0xe5, 0xd4, 0x03, 0x26, 0xd1, 0x3f, 0xa5, 0x9a, 0xf5, 0xb6, 0x5b, 0x05, 0x6d, 0x63, 0x70, 0x86, 0x49, 0x3e],
+    [0x94, 0xaa, 0x80, 0x10, 0x13, 0x8c, 0xcd, 0x85, 0x27, 0x71, 0x2e, 0x6b, 0xa0, 0x0d, 0xf6, 0x32, 0xf1, 0xcb, 0x3a, 0x26, 0xf8, 0xbb, 0x1a, 0x01, 0x48, 0x39, 0x4b, 0x4d, 0xa2, 0x6b, 0x93, 0x89],
+    [0xb5, 0x77, 0x0e, 0x44, 0xa2, 0x4c, 0xf2, 0xe9, 0xc0, 0x28, 0x44, 0x1c, 0x87, 0xdf, 0x80, 0xf9, 0xcd, 0xd2, 0x68, 0x99, 0xf2, 0xa9, 0x5d, 0x14, 0x9a, 0xe4, 0x24, 0x22, 0x49, 0x12, 0x61, 0xcf],
+    [0xcc, 0xb2, 0xe6, 0x5c, 0x2b, 0x00, 0x7f, 0xc4, 0x8a, 0x92, 0x94, 0x59, 0xb9, 0xe5, 0x5c, 0x7c, 0xae, 0x24, 0x3a, 0x17, 0x68, 0xdb, 0x34, 0xf5, 0x63, 0xc2, 0xf9, 0x00, 0xd9, 0xa4, 0xa8, 0xad],
+    [0x30, 0x28, 0x98, 0xc8, 0x97, 0xfe, 0x68, 0x21, 0xc3, 0x03, 0x4b, 0xd4, 0x15, 0x82, 0x1e, 0xa2, 0x49, 0xaf, 0x11, 0x58, 0xaa, 0x1e, 0x01, 0x22, 0x3e, 0x9f, 0x2f, 0x7b, 0xda, 0xbe, 0x2a, 0x91],
+    [0xe2, 0xe7, 0x7f, 0x6b, 0xe8, 0x0b, 0xc9, 0x7c, 0x46, 0x57, 0x71, 0xde, 0x80, 0x9c, 0xd1, 0xd5, 0x1d, 0x18, 0xb0, 0xc6, 0x83, 0x39, 0x6f, 0x66, 0x7d, 0xc3, 0x45, 0xa8, 0x0a, 0x01, 0x3d, 0x2f],
+    [0xf2, 0x3c, 0xc0, 0xa6, 0x57, 0xd3, 0xee, 0x03, 0x22, 0x60, 0x73, 0xd9, 0x9c, 0x4b, 0xf3, 0x6f, 0x87, 0x57, 0x97, 0x27, 0xaa, 0x1d, 0xde, 0x29, 0xbe, 0x3b, 0x56, 0x08, 0x1c, 0x92, 0x3e, 0xcf],
+    [0x37, 0x22, 0xd7, 0x96, 0x4d, 0x96, 0xed, 0x3c, 0xae, 0x69, 0x95, 0x7a, 0xc1, 0xb1, 0x7c, 0x98, 0xea, 0x77, 0x9c, 0x76, 0x1a, 0x4f, 0xb8, 0x58, 0x54, 0x32, 0x48, 0x39, 0xa4, 0x5a, 0xdc, 0x49],
+    [0x9d, 0xc6, 0x8a, 0xbb, 0xb9, 0x97, 0xd0, 0x22, 0xd6, 0x85, 0x6a, 0xce, 0x0a, 0x1a, 0xc4, 0x65, 0x8c, 0x6a, 0x50, 0xc2, 0xd4, 0x9f, 0x03, 0x64, 0x12, 0x2d, 0xe4, 0x7b, 0x67, 0x4b, 0x69, 0x87],
+    [0x1b, 0x6e, 0x7c, 0x1b, 0xb1, 0x6f, 0xcb, 0xd9, 0x54, 0xfb, 0x34, 0x35, 0x70, 0xf8, 0xaf, 0x72, 0x0e, 0xf1, 0x9c, 0x99, 0xa4, 0xfd, 0x9e, 0x25, 0x38, 0x5e, 0x55, 0xad, 0xf4, 0x4c, 0x54, 0x79],
+    [0xe0, 0xd3, 0xd8, 0x07, 0x75, 0xc5, 0x6a, 0x2c, 0x2f, 0x85, 0x1a, 0x5d, 0x85, 0x94, 0x74, 0x81, 0x17, 0x43, 0x65, 0xda, 0x46, 0x14, 0x1c, 0x42, 0x39, 0x27, 0x43, 0x67, 0x5d, 0x6e, 0xcd, 0x3a],
+    [0xb5, 0x24, 0x9b, 0x48, 0x47, 0x70, 0x5e, 0x8d, 0x30, 0xab, 0x13, 0x29, 0x8e, 0x63, 0xfe, 0x82, 0x35, 0xbe, 0x99, 0xb9, 0x19, 0xa8, 0x22, 0x7e, 0x48, 0x3e, 0xf0, 0x90, 0x61, 0x89, 0x7d, 0x5c],
+    [0x02, 0xe8, 0x5c, 0xd2, 0x71, 0x8f, 0x3c, 0x51, 0x34, 0x7a, 0x43, 0x7a, 0x63, 0x9c, 0xd8, 0xbd, 0xa8, 0x01, 0x2a, 0x03, 0x11, 0x6b, 0xfc, 0xe7, 0xbc, 0x25, 0x27, 0xa1, 0xb4, 0xbc, 0xbe, 0x3e],
+    [0xd3, 0x51, 0x24, 0x78, 0x76, 0x75, 0x61, 0x7f, 0xe7, 0x8a, 0x0d, 0x0d, 0xc3, 0xed, 0xb8, 0x67, 0xfe, 0x65, 0xb9, 0xe5, 0xab, 0x21, 0xbc, 0xd3, 0x97, 0xcd, 0x8c, 0xce, 0x80, 0x4a, 0xdd, 0x64],
+    [0xc7, 0x76, 0xa4, 0x51, 0x5d, 0x2a, 0xd8, 0x78, 0xe0, 0xef, 0xca, 0x9b, 0x85, 0xd8, 0x35, 0xf7, 0x2d, 0x8b, 0x49, 0x93, 0xf9, 0xd8, 0x11, 0xd6, 0xb1, 0x66, 0xab, 0x5b, 0xb9, 0xfc, 0x8a, 0xea],
+    [0xb7, 0x41, 0x07, 0x01, 0xf8, 0x47, 0x9f, 0x08, 0xf8, 0x6a, 0x2e, 0x0b, 0x44, 0x79, 0x24, 0x4f, 0xf3, 0xdd, 0x53, 0x87, 0xd5, 0x8a, 0x70, 0x3f, 0x9a, 0x01, 0x5f, 0xa4, 0xd2, 0x00, 0xd8, 0x9a],
+    [0x8b, 0x22, 0xca, 0xb7, 0x76, 0x87, 0xd1, 0x1e, 0x22, 0xa9, 0xa8, 0xa1, 0x43, 0x5d, 0x84, 0x72, 0x15, 0x1e, 0x02, 0xa5, 0x8f, 0x76, 0xdf, 0xc4, 0x3b, 0x72, 0xb2, 0x51, 0xd1, 0x71, 0xb8, 0x8e],
+    [0xdd, 0x40, 0x1d, 0xef, 0xb6, 0xe3, 0x7b, 0x2c, 0xf2, 0xa4, 0xac, 0x58, 0xcb, 0x32, 0x43, 0xb9, 0x51, 0xe4, 0x96, 0x1c, 0xee, 0x45, 0x33, 0xbd, 0xed, 0x90, 0x2f, 0xc7, 0xa4, 0xd8, 0xe1, 0x3c],
+    [0xeb, 0x9b, 0x3e, 0xf3, 0x7f, 0x57, 0x87, 0x32, 0x43, 0x29, 0x3a, 0xf6, 0xb9, 0x15, 0xc7, 0x58, 0xf5, 0xd5, 0x54, 0xef, 0xee, 0xc1, 0x0b, 0x4a, 0xa6, 0xb3, 0xdd, 0xd4, 0x95, 0x0d, 0x2e, 0x4c],
+    [0x1e, 0xe5, 0xf6, 0x53, 0x41, 0x7f, 0xec, 0xfa, 0xbb, 0x72, 0x9e, 0x87, 0x54, 0x9d, 0x52, 0xfa, 0x9c, 0x57, 0xd0, 0x4f, 0x7a, 0xaa, 0x52, 0x27, 0xf7, 0x51, 0xaa, 0x80, 0xb8, 0xdc, 0x8c, 0xf7],
+    [0x72, 0x41, 0x22, 0x37, 0x9f, 0xae, 0x39, 0xa1, 0x09, 0xc8, 0xfa, 0x30, 0xff, 0x3e, 0xb5, 0xdd, 0x2e, 0xe9, 0xa1, 0xa8, 0x45, 0xfd, 0x9d, 0x25, 0x35, 0xac, 0xd5, 0x56, 0x57, 0x41, 0x0b, 0xee],
+    [0xdb, 0x2e, 0xea, 0x90, 0x6c, 0x67, 0x9d, 0xa2, 0x0d, 0xfa, 0xaf, 0xf3, 0xee, 0x92, 0x90, 0x5f, 0x9a, 0xaf, 0xa3, 0xa7, 0xb5, 0xa2, 0xc0, 0x1f, 0x1f, 0x68, 0x5e, 0x36, 0x2b, 0x58, 0xac, 0xb6],
+    [0xe6, 0xd4, 0x94, 0x0f, 0x6f, 0xfa, 0xbf, 0xb7, 0x54, 0xcd, 0x67, 0x58, 0x8e, 0xfd, 0x18, 0x86, 0x45, 0xca, 0xef, 0x89, 0x29, 0x49, 0xbd, 0x5d, 0x66, 0x82, 0x58, 0x01, 0x8b, 0x56, 0xa1, 0x66],
+    [0x7e, 0x63, 0x6e, 0xe3, 0xd2, 0x91, 0x41, 0x57, 0xa3, 0x99, 0x63, 0xc3, 0x08, 0xe9, 0x3a, 0x49, 0x2e, 0xa8, 0x6c, 0xb7, 0x82, 0xd9, 0x6e, 0x47, 0x77, 0x37, 0x75, 0xf5, 0x44, 0xcb, 0xbe, 0xd9],
+    [0x75, 0x92, 0x1e, 0x88, 0x68, 0x01, 0x63, 0xd2, 0x0f, 0x21, 0xb0, 0xc5, 0xff, 0x61, 0xd1, 0xb9, 0x7b, 0xdb, 0xc6, 0xd5, 0xc6, 0x9e, 0x74, 0x60, 0x90, 0xc2, 0xbc, 0x6c, 0xc2, 0x7d, 0x03, 0xed],
+    [0x6e, 0x96, 0x13, 0x32, 0xa6, 0x31, 0xa4, 0x59, 0x00, 0xd5, 0xc2, 0x11, 0x13, 0x60, 0xdc, 0xff, 0x56, 0x31, 0xb5, 0x8c, 0x0f, 0xc3, 0xd3, 0xef, 0x8e, 0x2d, 0x57, 0xfe, 0xd5, 0xca, 0x15, 0xaa],
+    [0x2c, 0xce, 0xb4, 0xb8, 0x4c, 0xe1, 0x51, 0xdf, 0x28, 0x73, 0xc6, 0x6c, 0x6e, 0x18, 0xc3, 0x87, 0x39, 0xa4, 0x6d, 0x06, 0xb7, 0x55, 0x41, 0xaf, 0x46, 0x76, 0x82, 0x15, 0xb6, 0xd5, 0x0d, 0xe9],
+    [0xa8, 0x13, 0x41, 0xfe, 0x8a, 0xee, 0x60, 0x03, 0x70, 0xcc, 0x48, 0x52, 0xb1, 0x00, 0x8a, 0xaa, 0x84, 0x6e, 0x58, 0xba, 0x63, 0x56, 0xc0, 0x96, 0xe2, 0xdf, 0x04, 0xcd, 0x97, 0x6f, 0x8e, 0x46],
+    [0x61, 0x5a, 0xfd, 0xde, 0x27, 0x56, 0xb9, 0x2a, 0xe1, 0x01, 0x2b, 0xe3, 0xe0, 0x39, 0x38, 0x56, 0x45, 0x7c, 0x9d, 0xef, 0x83, 0xfc, 0xf9, 0x3b, 0xeb, 0xc6, 0x7f, 0x6e, 0x9d, 0x8a, 0xc1, 0x6c],
+    [0x25, 0x66, 0xe5, 0x09, 0xd2, 0x7f, 0xe0, 0xc0, 0x0e, 0xf2, 0xc3, 0xf7, 0x65, 0x71, 0xa1, 0xde, 0x60, 0x05, 0x0d, 0x20, 0x95, 0x65, 0x54, 0xcc, 0xee, 0x77, 0x80, 0x0c, 0x18, 0x86, 0x28, 0x48],
+    [0x83, 0xa8, 0xa7, 0x36, 0x81, 0x57, 0x26, 0xb5, 0x1d, 0x74, 0xb5, 0xe1, 0x7a, 0xa4, 0x0c, 0xd1, 0x90, 0xdc, 0x8d, 0xf1, 0xfe, 0xa1, 0xd0, 0x03, 0xa6, 0x3d, 0x38, 0x3b, 0xef, 0xb7, 0x8c, 0x54],
+    [0x3f, 0xd9, 0xe1, 0x53, 0xba, 0x4a, 0x11, 0xb3, 0x04, 0x72, 0x9c, 0x10, 0xf1, 0xaa, 0xdb, 0x2c, 0x1c, 0xe9, 0x10, 0x7d, 0xfc, 0xbc, 0x11, 0x6a, 0x63, 0x86, 0xbb, 0x23, 0x2a, 0xf6, 0x7d, 0x51],
+    [0x48, 0x70, 0x9a, 0xf2, 0xd9, 0x4b, 0xc1, 0x0e, 0x73, 0x01, 0x0c, 0xfb, 0xe7, 0xc4, 0xf6, 0xd0, 0xc2, 0xba, 0xb4, 0xae, 0x8b, 0x47, 0xe4, 0x55, 0x0a, 0x61, 0x39, 0x54, 0x06, 0xc4, 0x5d, 0xe6],
+    [0xc5, 0x7c, 0x32, 0x76, 0x26, 0x4b, 0x95, 0x53, 0xda, 0x22, 0x4c, 0x0d, 0x1a, 0x17, 0xb6, 0x8b, 0x3c, 0x56, 0xa4, 0xb1, 0x56, 0x62, 0xd3, 0x6e, 0x10, 0xa3, 0x5c, 0x69, 0xb1, 0x76, 0x67, 0xce],
+    [0x23, 0xd9, 0x88, 0xb4, 0x12, 0x45, 0x83, 0x81, 0xa1, 0xe7, 0xcc, 0x46, 0x94, 0xc7, 0x96, 0x50, 0x79, 0x16, 0x0b, 0x14, 0x75, 0x40, 0x20, 0x94, 0xe3, 0x6c, 0x5e, 0xfc, 0xb5, 0xe2, 0xf9, 0x09],
+    [0x09, 0xdc, 0x15, 0x7b, 0xa0, 0x05, 0xa4, 0xdd, 0xff, 0xe6, 0x37, 0xc6, 0xac, 0xfd, 0xfb, 0xf0, 0x0d, 0xd6, 0xa8, 0x41, 0xbb, 0x68, 0xb0, 0x71, 0xb1, 0x0e, 0x78, 0x0a, 0xa4, 0x0c, 0xf8, 0x5f],
+    [0x82, 0x63, 0xf9, 0xed, 0x50, 0xc7, 0x82, 0xf0, 0x09, 0xa5, 0x66, 0xc2, 0xb3, 0x9d, 0x81, 0x90, 0x06, 0x0b, 0x94, 0x3a, 0x72, 0xeb, 0x5a, 0x29, 0x32, 0x95, 0xde, 0x57, 0x81, 0xeb, 0x3f, 0x97],
+    [0xe9, 0x8d, 0x07, 0xdb, 0x4c, 0x54, 0x98, 0x0b, 0xe0, 0xd3, 0x16, 0x3d, 0x82, 0x9a, 0xba, 0x97, 0xba, 0x00, 0x21, 0xf9, 0x2c, 0x01, 0x4b, 0xfd, 0x75, 0x07, 0x3b, 0xda, 0x8c, 0xbf, 0x31, 0x35],
+    [0xdf, 0x0e, 0xa6, 0x86, 0xd3, 0xbe, 0x86, 0xfe, 0xeb, 0xdf, 0xe4, 0xe5, 0x73, 0x5f, 0x5e, 0x29, 0xf8, 0x73, 0xdc, 0xcc, 0xed, 0xf0, 0x32, 0xdb, 0xe8, 0x7e, 0xbb, 0x4c, 0xe2, 0x88, 0xe7, 0xd2],
+    [0x5d, 0xc0, 0x69, 0x93, 0x95, 0x71, 0xe9, 0x89, 0x53, 0x96, 0x81, 0x37, 0x49, 0x1a, 0xe2, 0x84, 0xcb, 0xef, 0x1c, 0x85, 0x68, 0xba, 0x52, 0xa6, 0xca, 0xf1, 0x6b, 0xe6, 0x68, 0x67, 0x04, 0xb5],
+    [0xbd, 0x0b, 0x16, 0xf1, 0xfa, 0x0b, 0xc9, 0x34, 0x78, 0x90, 0xe6, 0x2b, 0x04, 0x24, 0x84, 0xa9, 0x6d, 0x93, 0x5c, 0x3f, 0x4e, 0x4f, 0xe2, 0x6f, 0x97, 0x8a, 0x9f, 0xae, 0x85, 0x7b, 0x29, 0xde],
+    [0x49, 0x3f, 0x8e, 0x90, 0xbe, 0x01, 0xb8, 0x57, 0x20, 0xfc, 0xe7, 0x8d, 0x9a, 0x0b, 0x17, 0x23, 0xf9, 0x20, 0x7f, 0xa4, 0x0a, 0x13, 0x8a, 0x28, 0x9c, 0x2c, 0x87, 0x4f, 0x77, 0xd4, 0x41, 0x93],
+    [0x70, 0x73, 0xea, 0x24, 0xf0, 0x5b, 0x6e, 0x6b, 0xea, 0x9e, 0xba, 0xa3, 0xfb, 0x1d, 0x09, 0xa2, 0x7e, 0xc6, 0xbe, 0x64, 0xc7, 0xb2, 0x3b, 0xa4, 0x7e, 0xa3, 0x15, 0x39, 0xb7, 0x41, 0x74, 0x57],
+    [0x7e, 0xaf, 0x8b, 0xeb, 0x11, 0x44, 0x57, 0x7b, 0xc0, 0x02, 0xa8, 0x20, 0x69, 0x05, 0xfc, 0xa6, 0xcb, 0x9a, 0x92, 0xd4, 0x66, 0x9a, 0x5e, 0x0a, 0x2b, 0x3e, 0x3e, 0xc3, 0xfe, 0xc9, 0x26, 0x39],
+    [0xbd, 0xe9, 0x23, 0x86, 0x81, 0x5c, 0x07, 0x0d, 0xea, 0x06, 0x67, 0x41, 0x3a, 0x08, 0x31, 0x05, 0x09, 0xd7, 0xc9, 0xb2, 0x8b, 0xb1, 0x36, 0x2e, 0x27, 0xb9, 0x97, 0x16, 0x90, 0xe5, 0xc0, 0xc6],
+    [0xeb, 0x16, 0x65, 0x68, 0x65, 0xd3, 0x18, 0x68, 0x0b, 0x7b, 0x0d, 0x5a, 0x85, 0x61, 0xd3, 0xdd, 0x6a, 0xf5, 0x69, 0x25, 0x97, 0x0e, 0x4c, 0x9a, 0x4c, 0x26, 0x7c, 0x81, 0xc3, 0xb6, 0x36, 0xda],
+    [0xcb, 0x6b, 0xf9, 0x6f, 0xa1, 0x9d, 0xe9, 0x1d, 0xe4, 0xb5, 0x18, 0x8c, 0xce, 0x06, 0xbe, 0x29, 0x39, 0xec, 0x44, 0x51, 0xe1, 0x87, 0x3e, 0x48, 0x0d, 0x1e, 0x07, 0xbd, 0x3d, 0xfe, 0x91, 0x8f],
+    [0xef, 0x2e, 0x80, 0x42, 0x2d, 0x46, 0x79, 0x0a, 0x04, 0x31, 0xee, 0x33, 0xd8, 0xaa, 0x54, 0x9d, 0x6d, 0x42, 0x51, 0xb1, 0xd6, 0x64, 0xc7, 0x05, 0x8b, 0x45, 0x06, 0x8c, 0x3a, 0x97, 0x6e, 0x7c],
+    [0x9f, 0x4c, 0x26, 0xb4, 0x01, 0x57, 0xd6, 0x1d, 0xd2, 0xa2, 0xeb, 0x64, 0xf6, 0xa7, 0x4d, 0x65, 0x52, 0x50, 0xbc, 0x06, 0x47, 0x52, 0x7b, 0x20, 0x21, 0x6d, 0x83, 0x6a, 0x7d, 0x48, 0xbc, 0x45],
+    [0x3d, 0x86, 0x16, 0x14, 0xe2, 0x07, 0x0c, 0x13, 0xbb, 0x97, 0xde, 0x44, 0xf7, 0x59, 0x72, 0xec, 0xd5, 0xd8, 0x1e, 0x20, 0x25, 0xfe, 0x71, 0x27, 0xe6, 0xed, 0x3f, 0x9a, 0x2f, 0x99, 0x2c, 0x96],
+    [0x69, 0x25, 0xcb, 0xe0, 0x04, 0x72, 0xcc, 0x50, 0x78, 0x4a, 0xe9, 0x41, 0x7c, 0x15, 0xe5, 0x0c, 0x0b, 0xd3, 0x08, 0x5b, 0x55, 0x5f, 0xfb, 0xa9, 0x16, 0x0c, 0xd5, 0x6c, 0xe0, 0xe0, 0x2f, 0xf9],
+    [0x2c, 0x07, 0xea, 0x35, 0x27, 0x20, 0x79, 0x23, 0xe9, 0x6a, 0x91, 0x2c, 0x2d, 0xcf, 0x39, 0x1b, 0x1e, 0x38, 0x86, 0xb2, 0x21, 0x19, 0xb1, 0x63, 0x5c, 0x2c, 0x2d, 0x62, 0xcc, 0x61, 0x49, 0x5f],
+    [0x46, 0xfe, 0x25, 0x9e, 0xa5, 0x7d, 0x17, 0x99, 0x2f, 0xc6, 0x58, 0xf8, 0x0a, 0x94, 0xaa, 0x2f, 0x37, 0xb6, 0x3e, 0x60, 0x95, 0xde, 0x91, 0x8b, 0xa6, 0x99, 0xa8, 0xfa, 0xe6, 0x4f, 0xcd, 0x2b],
+    [0x70, 0xfc, 0x67, 0xc4, 0x2f, 0x26, 0x05, 0x05, 0x86, 0x53, 0xe3, 0x56, 0x56, 0xc9, 0x60, 0xc6, 0x99, 0x88, 0xe2, 0xd9, 0xf8, 0x18, 0xb7, 0xd4, 0x98, 0xa9, 0x24, 0xc3, 0x1a, 0x6a, 0x0d, 0x16],
+    [0x9f, 0x13, 0xf1, 0x90, 0x42, 0x82, 0x8f, 0x79, 0xc1, 0x78, 0x00, 0xf6, 0xdc, 0x62, 0x4a, 0xc7, 0x63, 0x82, 0xde, 0x4d, 0xca, 0x5e, 0x19, 0x48, 0xf1, 0x5e, 0x10, 0x1e, 0x4d, 0x6c, 0x96, 0xf4],
+    [0x44, 0x8b, 0x7d, 0x43, 0xa6, 0x74, 0xb8, 0x93, 0xbe, 0xe7, 0xf4, 0xbc, 0xaf, 0x22, 0xeb, 0x92, 0xb8, 0xf1, 0x3e, 0xac, 0xe3, 0x6c, 0xb3, 0x4a, 0xfd, 0x56, 0x8a, 0x47, 0x2d, 0x25, 0x13, 0x5e],
+    [0xdb, 0xcb, 0x85, 0xbd, 0x4d, 0xc1, 0xf4, 0x26, 0x0b, 0xf7, 0xaf, 0xb2, 0x4b, 0x26, 0x4b, 0x25, 0xf6, 0x39, 0x63, 0xd8, 0x4c, 0x19, 0x37, 0x68, 0x7e, 0xc9, 0xa3, 0x84, 0x04, 0x6a, 0xf8, 0xb2],
+    [0x5a, 0x9c, 0x09, 0x7d, 0x83, 0x5e, 0x29, 0xc2, 0x0a, 0x05, 0xeb, 0xea, 0x6b, 0x7f, 0x23, 0xf5, 0xe2, 0xd1, 0x26, 0x3e, 0xb1, 0xbd, 0x93, 0x77, 0x9e, 0xfb, 0x87, 0x1e, 0x43, 0x15, 0xd1, 0xf7],
+    [0xdb, 0xed, 0x46, 0x2b, 0x6a, 0x1b, 0x14, 0xea, 0x34, 0x09, 0xd1, 0x92, 0xcb, 0x40, 0x82, 0xcb, 0xef, 0xf3, 0x68, 0x7a, 0x3c, 0x0f, 0xda, 0x1e, 0x83, 0x9e, 0xf5, 0x95, 0x6b, 0x3c, 0x23, 0x46],
+    [0xcb, 0x56, 0xb1, 0x9a, 0x81, 0x55, 0x23, 0xdf, 0x24, 0x11, 0x77, 0x88, 0xd9, 0x79, 0x2f, 0xa6, 0x8f, 0xe0, 0x11, 0xff, 0xc3, 0xa5, 0x91, 0x0e, 0x99, 0x89, 0x91, 0x03, 0x8e, 0xcd, 0xba, 0x36],
+    [0xa1, 0x3e, 0x3f, 0x55, 0x6f, 0x0f, 0xff, 0x4f, 0xd3, 0x5c, 0x06, 0x98, 0xd8, 0x76, 0x00, 0x15, 0xd6, 0xea, 0xa6, 0x22, 0x7a, 0xec, 0x56, 0xd3, 0xff, 0x68, 0xef, 0x22, 0x3c, 0x69, 0x47, 0xa9],
+    [0xeb, 0x0c, 0xe7, 0x2e, 0x31, 0xfb, 0x96, 0x9c, 0x4f, 0xd0, 0x10, 0xbc, 0xea, 0x7a, 0xf9, 0xaf, 0x01, 0x68, 0x5e, 0x19, 0x8c, 0xae, 0x9b, 0x13, 0xbd, 0x38, 0x80, 0x61, 0x66, 0x00, 0x7e, 0xf2],
+    [0x06, 0x41, 0x62, 0xcc, 0x07, 0xcb, 0x62, 0x34, 0x13, 0x7f, 0xe3, 0xac, 0x38, 0x22, 0x44, 0x42, 0xb2, 0x6d, 0x76, 0x7c, 0x95, 0x0a, 0xc8, 0x43, 0x88, 0xa7, 0x15, 0x86, 0x2a, 0x5f, 0xff, 0xf2],
+    [0x67, 0xd7, 0x9d, 0x88, 0xb9, 0x21, 0x8c, 0xa1, 0xb4, 0xe7, 0xd7, 0xc1, 0x78, 0xae, 0xa1, 0x70, 0xfc, 0x0e, 0xdd, 0x4a, 0xfd, 0x6a, 0x26, 0x46, 0x98, 0xbf, 0xa8, 0x07, 0x91, 0xd5, 0xcd, 0x71],
+    [0xa2, 0x82, 0x70, 0xf8, 0x41, 0xb9, 0x2d, 0xc8, 0x69, 0x8e, 0xd5, 0x61, 0x3e, 0xca, 0xf1, 0x1f, 0x5d, 0x57, 0x7c, 0x02, 0x3a, 0x68, 0x03, 0x95, 0x9b, 0xdb, 0x6a, 0x60, 0x80, 0x92, 0x73, 0xb6],
+    [0x44, 0x7f, 0x12, 0xf0, 0x5a, 0x19, 0x8a, 0x6f, 0x28, 0xaf, 0xea, 0x20, 0xd0, 0x7b, 0x4a, 0xe3, 0xfc, 0x50, 0xaf, 0xc2, 0x12, 0xce, 0xb0, 0xa5, 0xd1, 0x1e, 0xe0, 0x87, 0xd6, 0x67, 0x43, 0x46],
+    [0xeb, 0x03, 0x4e, 0xba, 0xd3, 0xd8, 0x26, 0x83, 0xcf, 0x59, 0x4e, 0x99, 0x71, 0xa4, 0x5b, 0x6b, 0x67, 0xd1, 0x9c, 0x99, 0x57, 0x08, 0x6e, 0x08, 0xfa, 0xe4, 0x1c, 0x31, 0x50, 0x48, 0x7f, 0xac],
+    [0x70, 0x42, 0x5f, 0xd0, 0xb7, 0x66, 0x3d, 0x80, 0xec, 0x96, 0x05, 0xb7, 0x47, 0x4e, 0xe5, 0x94, 0xbe, 0x89, 0x41, 0x84, 0x23, 0xda, 0xb2, 0x83, 0x78, 0xe6, 0xc8, 0x7e, 0x66, 0x6f, 0x28, 0xb0],
+    [0xb8, 0x99, 0x31, 0xf7, 0x38, 0x4a, 0xed, 0xdb, 0x5c, 0x13, 0x6a, 0x67, 0x9d, 0x54, 0x46, 0x40, 0x07, 0xe2, 0xd8, 0x28, 0xd4, 0x74, 0x1b, 0xec, 0x62, 0x6f, 0xf9, 0x2a, 0xeb, 0x4b, 0x12, 0xd4],
+    [0x7c, 0xe2, 0x43, 0x23, 0x38, 0x24, 0xb0, 0x69, 0x8d, 0x95, 0xf4, 0xec, 0xb3, 0x49, 0x7e, 0xb5, 0xa8, 0xdf, 0x44, 0x35, 0xb6, 0xee, 0xac, 0xde, 0x23, 0x15, 0xa0, 0x27, 0xf0, 0x0a, 0xd7, 0x8f],
+    [0xc0, 0xaa, 0x37, 0xae, 0xb7, 0x10, 0xe6, 0x5a, 0xe4, 0x22, 0x65, 0x2f, 0x38, 0xf1, 0x1f, 0x80, 0x70, 0xf9, 0x01, 0x00, 0x51, 0x92, 0xb8, 0xc7, 0x82, 0x23, 0xae, 0x37, 0x9b, 0x48, 0xab, 0xeb],
+    [0x6f, 0x25, 0x4b, 0xc1, 0xe0, 0x76, 0xa7, 0xdf, 0x76, 0x78, 0x48, 0x01, 0xdd, 0xe0, 0xf1, 0x9c, 0x0e, 0x50, 0x5e, 0xd7, 0x37, 0xe3, 0xe7, 0x57, 0xf3, 0x72, 0xd2, 0xd4, 0x7f, 0xb2, 0x52, 0xda],
+    [0x42, 0x33, 0x47, 0x22, 0x1f, 0xcd, 0x75, 0x74, 0x92, 0x7b, 0xa8, 0x88, 0xae, 0xa0, 0x64, 0xac, 0xbf, 0x41, 0x43, 0x72, 0xd5, 0xa6, 0x2a, 0xd1, 0xe1, 0x61, 0x17, 0xe3, 0x99, 0x3c, 0xd6, 0x77],
+    [0x67, 0x3b, 0xab, 0xc2, 0xac, 0xcf, 0x35, 0xa0, 0x4f, 0xfc, 0x95, 0xb8, 0x76, 0x35, 0x02, 0x13, 0xac, 0xfd, 0x7a, 0x25, 0x78, 0x86, 0x57, 0xc1, 0xd0, 0x38, 0x93, 0x41, 0xa5, 0x3e, 0x25, 0x67],
+    [0x3f, 0xfb, 0x44, 0xd3, 0x7d, 0xe9, 0xdc, 0xd2, 0x89, 0x93, 0x91, 0x6a, 0xe5, 0x80, 0xf7, 0x0d, 0x61, 0x06, 0xcf, 0x4e, 0x70, 0xa4, 0xd3, 0x4b, 0xc6, 0x64, 0x8c, 0x7b, 0xa6, 0x77, 0x1b, 0xa4],
+    [0x93, 0x32, 0x82, 0x4e, 0xf3, 0x68, 0xc2, 0x47, 0x8c, 0xe6, 0x74, 0xdf, 0x35, 0x84, 0x3f, 0xae, 0xea, 0xce, 0xda, 0x4d, 0x67, 0x76, 0x57, 0x24, 0x8e, 0x66, 0x60, 0x53, 0xee, 0x01, 0xfb, 0x13],
+    [0x70, 0xc1, 0xf3, 0x8a, 0x29, 0x9f, 0x77, 0xe1, 0x4d, 0x6a, 0x6c, 0x6e, 0x0a, 0xfe, 0xa9, 0x4c, 0x32, 0x44, 0x16, 0x39, 0xca, 0xbf, 0xde, 0xa1, 0x00, 0x15, 0xc9, 0xaf, 0x71, 0xeb, 0xab, 0x82],
+    [0x06, 0x1b, 0x9f, 0x4e, 0xc1, 0xea, 0xac, 0xc7, 0x9c, 0xee, 0x97, 0xac, 0x60, 0x14, 0x74, 0x2d, 0xd3, 0x82, 0xae, 0xbc, 0x46, 0x3c, 0x01, 0xb8, 0x7b, 0x28, 0x10, 0x23, 0xa5, 0xbe, 0x99, 0x84],
+    [0x31, 0x02, 0x78, 0x0a, 0x3d, 0xca, 0xa2, 0xd4, 0xfa, 0x9f, 0x80, 0xfb, 0x2d, 0xf8, 0xd9, 0x3c, 0x1f, 0x78, 0xb8, 0xca, 0x6b, 0xf4, 0x2e, 0x70, 0x64, 0xc6, 0x1a, 0x7a, 0xbb, 0xed, 0xac, 0xc4],
+    [0x4c, 0x03, 0xcd, 0x8b, 0xcc, 0x2f, 0xd1, 0x00, 0xb9, 0xd5, 0x5c, 0xf1, 0x73, 0x4d, 0x06, 0x10, 0xba, 0x2e, 0x8c, 0x77, 0xd5, 0x20, 0xee, 0x12, 0x28, 0x09, 0x09, 0x4f, 0x9a, 0xea, 0x4a, 0x25],
+    [0x0e, 0x24, 0x94, 0x88, 0xcc, 0x9a, 0xab, 0xae, 0xe9, 0xd5, 0x13, 0xbb, 0xdb, 0x62, 0x3c, 0x78, 0xa7, 0x27, 0x34, 0xf4, 0x90, 0xd2, 0x2c, 0x4c, 0xce, 0x73, 0xb3, 0x6a, 0xb7, 0xaf, 0x46, 0xb2],
+    [0x2b, 0x01, 0x08, 0xa7, 0xf4, 0xd9, 0xf1, 0xb5, 0xd6, 0xb5, 0x86, 0x8d, 0x0b, 0xb4, 0x9c, 0xc4, 0xef, 0xe0, 0x1c, 0x05, 0x11, 0xef, 0xb9, 0xfa, 0x6c, 0xaf, 0xe2, 0x63, 0x0b, 0xbf, 0x8e, 0x7f],
+    [0xf4, 0x86, 0x0f, 0xc9, 0x4c, 0x5e, 0x40, 0x43, 0xf5, 0x04, 0xcb, 0xe0, 0xc8, 0x7d, 0x2e, 0x5f, 0xc8, 0xfd, 0x6f, 0x0e, 0xe2, 0xc7, 0x30, 0xa8, 0xe5, 0xfe, 0x5e, 0xac, 0x9a, 0xb9, 0xb4, 0xd8],
+    [0x06, 0xb9, 0x19, 0x47, 0x24, 0xbc, 0xa5, 0x21, 0x2e, 0xe2, 0xfa, 0x67, 0xd9, 0xb3, 0xba, 0xde, 0x90, 0x58, 0x1e, 0x82, 0xc3, 0x37, 0xef, 0xba, 0x29, 0x57, 0x5e, 0x76, 0x58, 0x84, 0x83, 0xd0],
+    [0x8c, 0xfa, 0x68, 0xa2, 0xf4, 0xd3, 0x15, 0xa7, 0x0c, 0x88, 0x91, 0x72, 0x11, 0xd6, 0x7e, 0x7d, 0x5d, 0x00, 0xe8, 0xd7, 0x54, 0x0d, 0x15, 0x93, 0xd1, 0x58, 0x8b, 0x04, 0x4a, 0x70, 0x8c, 0xc6],
+    [0xbd, 0x16, 0xb7, 0x56, 0xd8, 0x98, 0x48, 0x59, 0x39, 0x4b, 0x07, 0x6a, 0x60, 0x00, 0x39, 0xe2, 0x75, 0x04, 0x2d, 0xd6, 0xf4, 0xf1, 0xd8, 0x3e, 0xde, 0x4d, 0x6c, 0xf1, 0xee, 0xea, 0xe0, 0xa6],
+    [0x49, 0x90, 0x15, 0x48, 0xb8, 0xf0, 0xb3, 0x85, 0xf5, 0xd4, 0xb6, 0x6e, 0xce, 0x4d, 0x86, 0xd7, 0x08, 0x8d, 0x31, 0x87, 0x4a, 0xcf, 0x74, 0xa6, 0x73, 0x98, 0xa5, 0xec, 0x54, 0x42, 0xd4, 0x21],
+    [0x56, 0x34, 0x98, 0x7e, 0x43, 0x62, 0x60, 0xd9, 0x65, 0x5b, 0xb5, 0x20, 0x76, 0xce, 0x39, 0x4d, 0x75, 0x07, 0x82, 0x7d, 0x78, 0x86, 0xdb, 0x32, 0x09, 0xd8, 0xa4, 0x3b, 0x62, 0x5a, 0x8c, 0x43],
+    [0x0f, 0xab, 0x9c, 0x71, 0xfa, 0x94, 0xb0, 0x34, 0xff, 0x78, 0x29, 0xf8, 0xe5, 0x14, 0x35, 0x71, 0xdf, 0x08, 0x22, 0x64, 0xa8, 0xb1, 0x0f, 0x84, 0xe0, 0x2a, 0x1b, 0xf7, 0xcd, 0x2a, 0x4d, 0xa5],
+    [0x36, 0xb1, 0x46, 0x63, 0x70, 0x84, 0xb9, 0x07, 0xe9, 0xa2, 0x3b, 0x45, 0x4b, 0x20, 0x36, 0x58, 0xe9, 0x82, 0x09, 0x41, 0xaf, 0x6f, 0xe2, 0xc0, 0x3a, 0x2f, 0x5f, 0xcc, 0x32, 0x10, 0xa6, 0x9f],
+    [0x9a, 0xde, 0x6c, 0x91, 0xa2, 0xdc, 0xf1, 0x08, 0x07, 0xdd, 0x96, 0xe2, 0x8c, 0x81, 0xfe, 0x6b, 0xf1, 0x49, 0x53, 0xfb, 0x0f, 0xd1, 0x45, 0x1b, 0xcf, 0x80, 0x84, 0xc3, 0xff, 0x1e, 0x30, 0x74],
+    [0xd9, 0x8e, 0xa6, 0x66, 0xcb, 0xa6, 0x29, 0x93, 0x8b, 0xca, 0x5a, 0x6d, 0xc7, 0x0d, 0xc6, 0x58, 0x6a, 0x42, 0x11, 0xd7, 0x89, 0x0f, 0x83, 0x1a, 0x8f, 0x5f, 0x74, 0xe1, 0x31, 0x6a, 0x03, 0x52],
+    [0xe5, 0x67, 0xee, 0x0f, 0x05, 0x10, 0xcb, 0x5b, 0xd6, 0xc5, 0xc3, 0xc2, 0x56, 0x10, 0x98, 0x63, 0xac, 0x27, 0xf4, 0xfb, 0xaa, 0x2b, 0x04, 0xc7, 0x16, 0x44, 0x3d, 0xda, 0x47, 0x43, 0x9f, 0x19],
+    [0x5a, 0x0b, 0x68, 0x99, 0x72, 0xd5, 0x03, 0xa7, 0x57, 0xda, 0x44, 0x9e, 0xbf, 0x2a, 0x64, 0x9f, 0xf2, 0x2d, 0x96, 0xea, 0xc1, 0x63, 0x71, 0x02, 0x38, 0x64, 0xbd, 0xd2, 0x8d, 0xea, 0xd6, 0xba],
+    [0x63, 0x86, 0x02, 0x35, 0x58, 0x36, 0xf7, 0x98, 0x73, 0x34, 0x7e, 0xd2, 0xbb, 0x03, 0x5c, 0xdb, 0x62, 0x01, 0x91, 0xe6, 0x6b, 0x07, 0xe1, 0x69, 0xc0, 0x2b, 0xd4, 0xef, 0xe4, 0x00, 0x34, 0xfb],
+    [0x3d, 0xe1, 0x9a, 0x51, 0x92, 0x3b, 0xc3, 0x9e, 0xbd, 0x79, 0x67, 0xe1, 0x73, 0xc1, 0xef, 0x75, 0xc6, 0xce, 0x26, 0x32, 0x77, 0x65, 0x06, 0x72, 0x89, 0x2c, 0xbe, 0xcc, 0x7f, 0x08, 0xce, 0x21],
+    [0x62, 0x3f, 0x20, 0x33, 0x18, 0xc1, 0xf6, 0x14, 0x36, 0xb7, 0xee, 0x9c, 0xe4, 0xbd, 0xb9, 0x46, 0xf7, 0x9c, 0x5f, 0x30, 0x5a, 0xb7, 0x9e, 0x0d, 0xb9, 0x1c, 0x31, 0x14, 0x51, 0xf6, 0xc9, 0x0d],
+    [0x3d, 0xc3, 0x71, 0x3d, 0x2f, 0x22, 0x0e, 0xa9, 0xae, 0xeb, 0x28, 0xd9, 0x45, 0x74, 0x77, 0x7e, 0xac, 0x7f, 0x3b, 0xa5, 0x7c, 0x22, 0xf8, 0x0f, 0x5f, 0x9b, 0xe5, 0x9b, 0x34, 0xed, 0x20, 0xb4],
+    [0xcc, 0xed, 0xa4, 0x16, 0xb9, 0xf6, 0x29, 0xcc, 0x37, 0xfc, 0x12, 0x4f, 0xc0, 0x10, 0x5b, 0x6e, 0x94, 0x82, 0xa9, 0x4a, 0xad, 0x92, 0x7f, 0x57, 0xf5, 0xad, 0xb3, 0xb6, 0xf7, 0x66, 0xdf, 0x61],
+    [0xb4, 0x1a, 0xe1, 0x2a, 0xec, 0x65, 0x51, 0x41, 0x2c, 0xbd, 0x89, 0x7d, 0xdb, 0xd8, 0xb3, 0x20, 0x0a, 0x92, 0x0f, 0xb9, 0xe5, 0x0a, 0xb3, 0x0a, 0x17, 0x78, 0x0d, 0xd1, 0xf5, 0x2d, 0xba, 0x84],
+    [0x06, 0xfe, 0x5f, 0xc1, 0x13, 0x2c, 0xaa, 0x34, 0xbd, 0xb1, 0xa9, 0xa0, 0x0a, 0xe8, 0x9b, 0x8b, 0x5f, 0xf2, 0xe1, 0x4f, 0xf7, 0x60, 0x01, 0xb3, 0xc2, 0xc3, 0x3e, 0x4e, 0x22, 0x79, 0x4d, 0x03],
+    [0x2c, 0x2c, 0x10, 0x49, 0xa7, 0x25, 0x8b, 0xb2, 0x6a, 0xa7, 0xb7, 0x30, 0x74, 0x28, 0xd1, 0xb6, 0x91, 0xe2, 0xd1, 0x61, 0xe8, 0xf0, 0x04, 0xb2, 0xea, 0x05, 0xec, 0x5f, 0x79, 0xe9, 0x7d, 0xa6],
+    [0xab, 0x03, 0x09, 0x2d, 0xdd, 0x92, 0xee, 0xda, 0xed, 0x8c, 0xde, 0x9d, 0x6a, 0x14, 0xea, 0x56, 0x80, 0xea, 0x5c, 0xc5, 0xe5, 0x6b, 0x07, 0x6d, 0xb4, 0x7b, 0xb3, 0x1f, 0x41, 0x98, 0xbf, 0x28],
+    [0x65, 0x55, 0xfe, 0xc5, 0x05, 0xce, 0xa1, 0xe2, 0x37, 0xc5, 0x19, 0x6e, 0x4b, 0x1a, 0x1f, 0xd8, 0x05, 0xae, 0x6e, 0xcc, 0x3d, 0x9c, 0xe4, 0x73, 0xdf, 0xcf, 0x9c, 0x4d, 0xfe, 0x71, 0x5d, 0xbf],
+    [0xc7, 0xdd, 0xac, 0x7a, 0x99, 0xa7, 0xb9, 0x91, 0x0b, 0x88, 0x47, 0xb6, 0x70, 0xd5, 0xaf, 0x53, 0xf7, 0x95, 0x8a, 0xf0, 0xa7, 0x96, 0x7b, 0x79, 0xb9, 0xac, 0x00, 0x69, 0xc3, 0x90, 0x24, 0x4f],
+    [0xba, 0x78, 0x70, 0x6f, 0x57, 0xa5, 0x74, 0xf0, 0x63, 0xd5, 0x16, 0x0f, 0xc2, 0x98, 0xe0, 0x5f, 0x2a, 0xab, 0x06, 0xdc, 0x88, 0x7e, 0x79, 0x7f, 0x2a, 0x97, 0x18, 0x14, 0x14, 0x64, 0x3d, 0x42],
+    [0x48, 0x5d, 0xa5, 0x2d, 0x1d, 0x89, 0x00, 0xa6, 0x27, 0xe2, 0xf3, 0xcc, 0x9f, 0x3c, 0xf6, 0xe6, 0x53, 0xb8, 0xef, 0xfe, 0xd5, 0x97, 0x2c, 0xd1, 0x0a, 0x38, 0x99, 0x0f, 0x2f, 0xef, 0xc4, 0x94],
+    [0x3d, 0x2f, 0x6f, 0x07, 0xc3, 0x67, 0x56, 0x18, 0x4e, 0xf2, 0xc6, 0x22, 0x36, 0x5c, 0xba, 0xff, 0xb8, 0xb6, 0x2e, 0x34, 0x0f, 0x38, 0x43, 0x88, 0xbb, 0x5b, 0xee, 0x95, 0xa7, 0xc6, 0xc1, 0xaa],
+    [0xd1, 0x7b, 0x42, 0xde, 0xd5, 0x6f, 0x41, 0x0a, 0x91, 0x6f, 0x90, 0x5a, 0xce, 0x02, 0x19, 0xc3, 0xf5, 0xb8, 0x59, 0xb9, 0xe6, 0x2d, 0xa6, 0xab, 0x44, 0x25, 0x79, 0x2b, 0x1b, 0x6d, 0xae, 0x37],
+    [0x64, 0x10, 0x11, 0x29, 0x82, 0x9c, 0x8c, 0xed, 0xc9, 0xbd, 0x31, 0x50, 0x90, 0xd5, 0x5a, 0xaf, 0x67, 0x25, 0x5d, 0x64, 0xc7, 0x66, 0xf1, 0x5b, 0xb3, 0x8e, 0xbc, 0xd4, 0xf2, 0x21, 0x76, 0x07],
+    [0x88, 0xfe, 0xfa, 0x6a, 0xb2, 0x0b, 0xb4, 0x15, 0xc3, 0xd8, 0x2a, 0x5a, 0x28, 0x9a, 0x41, 0xee, 0x61, 0x1a, 0xac, 0x57, 0x3c, 0x11, 0xd8, 0x84, 0xb5, 0x6e, 0x87, 0x88, 0xf7, 0x69, 0xff, 0xa2],
+    [0xd3, 0x62, 0xf3, 0xb4, 0x3a, 0x26, 0x8c, 0x4f, 0x16, 0x77, 0x14, 0x5d, 0xb5, 0x90, 0x76, 0x9d, 0x5e, 0xdd, 0xce, 0x0e, 0xfc, 0xcf, 0xb9, 0xe8, 0xba, 0x1c, 0x54, 0x05, 0x8f, 0x65, 0x7f, 0xd9],
+    [0x6b, 0xd1, 0x6a, 0xa5, 0x87, 0xf6, 0xd9, 0xde, 0x55, 0xa4, 0x17, 0x12, 0x52, 0xc8, 0x52, 0x61, 0xf5, 0x69, 0x5b, 0xba, 0x8d, 0xdf, 0x34, 0x00, 0x29, 0x75, 0xc3, 0x38, 0x6c, 0x9e, 0xa9, 0xea],
+    [0xed, 0x00, 0xb0, 0xa8, 0x7e, 0x48, 0xf1, 0xfa, 0x04, 0x3a, 0x23, 0xaa, 0x2b, 0xc7, 0x03, 0x67, 0x83, 0xb8, 0x09, 0x2c, 0x24, 0x71, 0xf7, 0xc3, 0xd9, 0x87, 0xf0, 0x9a, 0xb0, 0xb0, 0xe2, 0x2b],
+    [0x75, 0xd0, 0x33, 0x1a, 0x2a, 0x80, 0x3d, 0x94, 0x9f, 0x93, 0xa2, 0xb3, 0x16, 0x8f, 0x76, 0x51, 0x8c, 0x07, 0x5a, 0x8c, 0x4f, 0xca, 0x92, 0x86, 0x72, 0x67, 0x8f, 0x87, 0xfd, 0x6b, 0xda, 0xa6],
+    [0xce, 0x6a, 0xc2, 0x10, 0x0a, 0x49, 0x9f, 0x6c, 0x6f, 0x8a, 0x70, 0x2f, 0xfc, 0xc3, 0xf5, 0xb0, 0xc0, 0xf9, 0x46, 0x1f, 0x11, 0xf3, 0x1a, 0x08, 0xc4, 0x75, 0xbc, 0xb7, 0xb9, 0x89, 0x76, 0x82],
+    [0xa6, 0xb6, 0x78, 0x3e, 0xb4, 0x21, 0xc5, 0x80, 0xbc, 0xd3, 0x42, 0x82, 0x07, 0x61, 0x60, 0x24, 0x9d, 0xe5, 0xa5, 0x19, 0x06, 0x2c, 0x42, 0x90, 0x91, 0x6d, 0xeb, 0x0c, 0xf1, 0x72, 0xcd, 0x72],
+    [0x83, 0xdb, 0xa8, 0x0f, 0x08, 0xb8, 0x44, 0xf2, 0xc8, 0x7d, 0x9b, 0xd3, 0x67, 0x2a, 0x01, 0xdb, 0x06, 0x48, 0xbe, 0x94, 0x7d, 0x80, 0x1b, 0xe9, 0x95, 0x31, 0x1d, 0x82, 0x9a, 0x16, 0xc7, 0x04],
+    [0x5a, 0xba, 0x21, 0xb7, 0x05, 0xa5, 0xb1, 0x19, 0x05, 0x7f, 0x2c, 0x9a, 0x72, 0xf1, 0xd1, 0x12, 0x9c, 0x44, 0x15, 0xcd, 0x8e, 0x6e, 0x5a, 0xf4, 0x38, 0xf5, 0x2b, 0x64, 0xab, 0x4d, 0x19, 0xb3],
+    [0x70, 0xd3, 0xb6, 0xeb, 0xad, 0x9d, 0xb6, 0x0e, 0x0e, 0x1b, 0xb9, 0x99, 0xb5, 0xe7, 0xc7, 0xfe, 0x57, 0x6f, 0x4b, 0x22, 0x78, 0x1f, 0x32, 0x5a, 0xf3, 0xa0, 0x8e, 0xbc, 0x7a, 0x00, 0x4a, 0x1e],
+    [0x31, 0x32, 0xf6, 0x13, 0x90, 0x53, 0x8f, 0xdc, 0x20, 0x49, 0x5f, 0x40, 0x83, 0x33, 0x7b, 0xa8, 0x59, 0x36, 0x95, 0x9c, 0xa5, 0x19, 0x9e, 0x2e, 0xcb, 0xeb, 0xe7, 0x4a, 0x03, 0x80, 0xa2, 0x14],
+    [0x8f, 0xeb, 0xa6, 0xe9, 0xae, 0xf1, 0xd6, 0x38, 0x45, 0xf0, 0xd0, 0x7b, 0x1c, 0xb4, 0x7e, 0x86, 0x4d, 0xb0, 0x44, 0xcf, 0xe3, 0x27, 0x77, 0xdb, 0x31, 0x29, 0xbf, 0x5f, 0x7b, 0x78, 0x12, 0x1f],
+    [0x1d, 0x8d, 0x4c, 0x0d, 0x75, 0xf6, 0x22, 0x98, 0x27, 0xbb, 0x9d, 0x26, 0xfd, 0x53, 0x43, 0x3c, 0x0e, 0xab, 0xf0, 0x3a, 0xa5, 0xa5, 0x10, 0x3c, 0x52, 0x99, 0x2c, 0x14, 0x9c, 0xf1, 0x4a, 0xac],
+    [0x9c, 0x87, 0x6e, 0x32, 0x58, 0x3d, 0x71, 0xeb, 0x49, 0x26, 0x9f, 0x14, 0x73, 0xd6, 0xf1, 0x65, 0x53, 0x72, 0xc8, 0xb1, 0x6b, 0x85, 0xb4, 0x77, 0xe9, 0xb0, 0xf0, 0x27, 0x7c, 0x7e, 0x39, 0xbe],
+    [0x13, 0x96, 0x61, 0x74, 0x58, 0xc5, 0x08, 0x4d, 0xf5, 0x3e, 0xa9, 0xb8, 0x92, 0xb2, 0xf5, 0xc6, 0x28, 0x9f, 0xcb, 0x5c, 0x05, 0x62, 0xb0, 0x5e, 0xb0, 0x7c, 0x05, 0x8b, 0xf3, 0x2b, 0xfe, 0x20],
+    [0x1e, 0xec, 0x97, 0x48, 0xc4, 0x0a, 0xd9, 0xfc, 0x8a, 0xcc, 0x60, 0x22, 0xd1, 0x58, 0x86, 0xe5, 0xbd, 0x1e, 0x45, 0x80, 0xb8, 0x11, 0xe2, 0x37, 0xcf, 0xfc, 0x04, 0x78, 0xc6, 0xe5, 0x3c, 0xaf],
+    [0x42, 0xaf, 0x4d, 0x9f, 0x65, 0x9b, 0xdb, 0x3d, 0x01, 0x86, 0x71, 0x48, 0xf6, 0x09, 0x68, 0x7c, 0x6b, 0xdd, 0x3a, 0x96, 0x44, 0x56, 0x50, 0x17, 0xf2, 0x9f, 0xd3, 0xd9, 0xba, 0x9b, 0x98, 0x24],
+    [0x28, 0x81, 0xd7, 0x18, 0x8f, 0x16, 0x06, 0x8d, 0x47, 0x7b, 0x7d, 0x2c, 0xcd, 0x5d, 0xc1, 0xe2, 0x7e, 0x1c, 0x38, 0xfd, 0x88, 0x21, 0xed, 0x97, 0x72, 0x29, 0xac, 0x1b, 0xd5, 0x3e, 0x1d, 0xae],
+    [0x5f, 0x08, 0x3e, 0x40, 0x58, 0x74, 0xf6, 0xbf, 0xe5, 0xbc, 0x91, 0x93, 0x85, 0xf5, 0x00, 0xae, 0x44, 0x4b, 0x9a, 0x17, 0x11, 0x59, 0xb3, 0xb6, 0x47, 0x6c, 0xad, 0x78, 0xc3, 0x8a, 0x00, 0x77],
+    [0x65, 0xf2, 0xbd, 0x62, 0xad, 0x4a, 0x29, 0xa5, 0x93, 0xe3, 0x8b, 0xcb, 0xaa, 0xc0, 0x68, 0x50, 0x33, 0xd2, 0xcd, 0xb4, 0xa8, 0x12, 0x9d, 0x9e, 0x76, 0x72, 0xa7, 0x5b, 0xa4, 0x28, 0x20, 0xc2],
+    [0xbd, 0xaf, 0x0a, 0x25, 0xa0, 0x62, 0x89, 0x73, 0xd4, 0xdf, 0x7e, 0x4c, 0x05, 0x3c, 0xda, 0x13, 0x18, 0xb3, 0x98, 0x01, 0xd3, 0xf0, 0xfb, 0xa8, 0x27, 0xeb, 0xe7, 0x92, 0xec, 0x12, 0xcb, 0xfa],
+    [0x99, 0x8e, 0xb7, 0x36, 0xda, 0x0f, 0x97, 0x41, 0xc6, 0xcf, 0xc2, 0xda, 0xd9, 0x52, 0x49, 0x17, 0x08, 0xb7, 0xb4, 0xdd, 0xab, 0xb1, 0x5d, 0x37, 0x69, 0x9a, 0xf1, 0x3f, 0xed, 0x29, 0xc3, 0x5d],
+    [0xa7, 0xff, 0x9e, 0x28, 0xff, 0xd3, 0xde, 0xf4, 0x43, 0xd3, 0x24, 0x54, 0x76, 0x88, 0xc2, 0xc4, 0xeb, 0x98, 0xed, 0xf7, 0xda, 0x75, 0x7d, 0x6b, 0xfa, 0x22, 0xbf, 0xf5, 0x5b, 0x9c, 0xe2, 0x4a],
+];
@@ -0,0 +1,63 @@
+//! [Starknet](https://docs.starknet.io/architecture-and-concepts/cryptography/hash-functions/)
+//! Keccak hashing: `sn_keccak`, entry point selectors and storage variable addresses.
+
+use crate::keccak256;
+
+/// Upper bound of storage addresses: `2^251 - 256`, as a big-endian integer.
+pub const ADDR_BOUND: [u8; 32] = {
+    let mut bound = [0xff; 32];
+    bound[0] = 0x07;
+    bound[31] = 0x00;
+    bound
+};
+
+/// Returns the first 250 bits of the Keccak-256 hash of `data`, as a big-endian field element.
+#[inline]
+pub fn sn_keccak(data: &[u8]) -> [u8; 32] {
+    let mut hash = keccak256(data);
+    hash[0] &= 0x03;
+    hash
+}
+
+/// Returns the selector of an entry point, which is the [`sn_keccak`] of its name, or zero for
+/// the default entry points `__default__` and `__l1_default__`.
+#[inline]
+pub fn starknet_selector(name: &str) -> [u8; 32] {
+    match name {
+        "__default__" | "__l1_default__" => [0; 32],
+        _ => sn_keccak(name.as_bytes()),
+    }
+}
+
+/// Returns the address of a storage variable without keys: the [`sn_keccak`] of its name.
+#[inline]
+pub fn storage_var_address(name: &str) -> [u8; 32] {
+    // `sn_keccak` is less than `2^250`, so it is always below `ADDR_BOUND`.
+    sn_keccak(name.as_bytes())
+}
+
+/// Returns the address of the entry at `keys` of a storage variable, e.g. a mapping:
+/// `h(...h(h(sn_keccak(name), keys[0]), keys[1])..., keys[n - 1]) mod ADDR_BOUND`.
+///
+/// `pedersen` is the Starknet Pedersen hash of two big-endian field elements, which is not
+/// implemented by this crate.
+pub fn storage_var_address_with_keys<H>(name: &str, keys: &[[u8; 32]], mut pedersen: H) -> [u8; 32]
+where
+    H: FnMut(&[u8; 32], &[u8; 32]) -> [u8; 32],
+{
+    let mut address = storage_var_address(name);
+    for key in keys {
+        address = pedersen(&address, key);
+    }
+    // Field elements are less than `2 * ADDR_BOUND`, so a single subtraction reduces them.
+    if address >= ADDR_BOUND {
+        let mut borrow = false;
+        for (a, b) in address.iter_mut().zip(&ADDR_BOUND).rev() {
+            let (diff, b1) = a.overflowing_sub(*b);
+            let (diff, b2) = diff.overflowing_sub(borrow as u8);
+            *a = diff;
+            borrow = b1 | b2;
+        }
+    }
+    address
+}
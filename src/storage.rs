@@ -0,0 +1,313 @@
+//! Solidity [storage layout](https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html)
+//! slot calculations.
+
+use crate::{keccak256, Keccak256};
+use core::fmt;
+use digest::Digest;
+
+/// A 256-bit storage slot, as big-endian bytes.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StorageSlot(pub [u8; 32]);
+
+impl fmt::Debug for StorageSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StorageSlot(0x")?;
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl From<[u8; 32]> for StorageSlot {
+    #[inline]
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<StorageSlot> for [u8; 32] {
+    #[inline]
+    fn from(slot: StorageSlot) -> Self {
+        slot.0
+    }
+}
+
+impl From<u64> for StorageSlot {
+    #[inline]
+    fn from(slot: u64) -> Self {
+        Self::new(slot)
+    }
+}
+
+impl AsRef<[u8]> for StorageSlot {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl StorageSlot {
+    /// Slot zero.
+    pub const ZERO: Self = Self([0; 32]);
+
+    /// Creates a slot from a small integer, e.g. a slot assigned by the compiler.
+    #[inline]
+    pub const fn new(slot: u64) -> Self {
+        let be = slot.to_be_bytes();
+        let mut bytes = [0; 32];
+        let mut i = 0;
+        while i < 8 {
+            bytes[24 + i] = be[i];
+            i += 1;
+        }
+        Self(bytes)
+    }
+
+    /// Returns the big-endian bytes of the slot.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the root slot of an [ERC-7201](https://eips.ethereum.org/EIPS/eip-7201) namespace:
+    /// `keccak256(abi.encode(uint256(keccak256(id)) - 1)) & ~bytes32(uint256(0xff))`.
+    pub fn erc7201(namespace_id: &str) -> Self {
+        let (id, _) = Self(keccak256(namespace_id.as_bytes())).overflowing_sub(Self::new(1));
+        let mut root = keccak256(&id.0);
+        root[31] = 0;
+        Self(root)
+    }
+
+    /// Returns the slot of the value at `key` in the mapping stored at this slot:
+    /// `keccak256(h(key) . slot)`.
+    ///
+    /// Nested mappings are resolved by chaining calls, e.g. `slot.mapping(&owner).mapping(&spender)`
+    /// for `mapping(address => mapping(address => uint256))`.
+    pub fn mapping<K: MappingKey + ?Sized>(&self, key: &K) -> Self {
+        let mut hasher = Keccak256::new();
+        key.update_hasher(&mut hasher);
+        hasher.update(self.0);
+        Self(hasher.finalize().into())
+    }
+
+    /// Returns the first slot of the data of the dynamic array, `bytes` or `string` stored at this
+    /// slot: `keccak256(slot)`.
+    ///
+    /// For `bytes` and `string`, this is only used in the long form, see [`BytesStorage`].
+    #[inline]
+    pub fn data(&self) -> Self {
+        Self(keccak256(&self.0))
+    }
+
+    /// Returns the first slot of the element at `index` of the dynamic array stored at this slot,
+    /// where each element occupies `slots_per_element` whole slots.
+    #[inline]
+    pub fn array_element(&self, index: u64, slots_per_element: u64) -> Self {
+        self.data().offset(index as u128 * slots_per_element as u128)
+    }
+
+    /// Returns the slot and the byte offset from the least significant end of the slot of the
+    /// element at `index` of the dynamic array stored at this slot, where elements of
+    /// `element_size` bytes are packed together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element_size` is not in `1..=32`.
+    #[inline]
+    pub fn packed_array_element(&self, index: u64, element_size: usize) -> (Self, usize) {
+        assert!((1..=32).contains(&element_size), "packed elements must be 1 to 32 bytes");
+        let per_slot = (32 / element_size) as u64;
+        let slot = self.data().offset((index / per_slot) as u128);
+        (slot, (index % per_slot) as usize * element_size)
+    }
+
+    /// Decodes the layout of the `bytes` or `string` whose slot holds `value`.
+    ///
+    /// Returns `None` if `value` is not a valid encoding, or the length does not fit in a `u64`.
+    pub fn bytes_storage(&self, value: &[u8; 32]) -> Option<BytesStorage> {
+        if value[31] & 1 == 0 {
+            // The data is left-aligned, followed by zeroes and `len * 2` in the lowest byte.
+            let len = (value[31] / 2) as usize;
+            if len > 31 || value[len..31].iter().any(|&b| b != 0) {
+                return None;
+            }
+            Some(BytesStorage::Short { len })
+        } else {
+            // The slot holds `len * 2 + 1`.
+            if value[..24].iter().any(|&b| b != 0) {
+                return None;
+            }
+            let len = u64::from_be_bytes(value[24..].try_into().unwrap()) >> 1;
+            if len < 32 {
+                return None;
+            }
+            Some(BytesStorage::Long { len, data: self.data() })
+        }
+    }
+
+    /// Returns the slot `n` slots after this one, wrapping around at 2^256.
+    #[inline]
+    pub fn offset(&self, n: u128) -> Self {
+        let mut rhs = [0; 32];
+        rhs[16..].copy_from_slice(&n.to_be_bytes());
+        self.overflowing_add(Self(rhs)).0
+    }
+
+    /// Computes `self + rhs` modulo 2^256, returning whether an overflow occurred.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut out = [0; 32];
+        let mut carry = false;
+        for i in (0..32).rev() {
+            let (sum, c1) = self.0[i].overflowing_add(rhs.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u8);
+            out[i] = sum;
+            carry = c1 | c2;
+        }
+        (Self(out), carry)
+    }
+
+    /// Computes `self - rhs` modulo 2^256, returning whether an underflow occurred.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut out = [0; 32];
+        let mut borrow = false;
+        for i in (0..32).rev() {
+            let (diff, b1) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u8);
+            out[i] = diff;
+            borrow = b1 | b2;
+        }
+        (Self(out), borrow)
+    }
+
+    /// Computes `self + rhs`, returning `None` on overflow.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (slot, false) => Some(slot),
+            (_, true) => None,
+        }
+    }
+}
+
+/// Storage layout of a `bytes` or `string` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BytesStorage {
+    /// Up to 31 bytes stored in the higher-order bytes of the value's own slot.
+    Short {
+        /// The length in bytes.
+        len: usize,
+    },
+    /// 32 bytes or more stored in consecutive slots starting at `data`.
+    Long {
+        /// The length in bytes.
+        len: u64,
+        /// The first data slot, `keccak256(slot)`.
+        data: StorageSlot,
+    },
+}
+
+impl BytesStorage {
+    /// Returns the length in bytes.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        match *self {
+            Self::Short { len } => len as u64,
+            Self::Long { len, .. } => len,
+        }
+    }
+
+    /// Returns `true` if the value is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the data slots of the long form, in order.
+    pub fn data_slots(&self) -> impl Iterator<Item = StorageSlot> {
+        let (data, count) = match *self {
+            Self::Short { .. } => (StorageSlot::ZERO, 0),
+            Self::Long { len, data } => (data, (len + 31) / 32),
+        };
+        (0..count).map(move |i| data.offset(i as u128))
+    }
+}
+
+/// A Solidity mapping key type, which determines how the key is encoded when computing the slot
+/// of a mapping value.
+///
+/// Value types are left-padded to 32 bytes as in `abi.encode`, while `string` and `bytes` keys
+/// are hashed unpadded.
+pub trait MappingKey {
+    /// Feeds the encoding of the key into `hasher`.
+    fn update_hasher(&self, hasher: &mut Keccak256);
+}
+
+/// `address`.
+impl MappingKey for [u8; 20] {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        hasher.update([0; 12]);
+        hasher.update(self);
+    }
+}
+
+/// `bytes32` or `uint256`, as big-endian bytes.
+impl MappingKey for [u8; 32] {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        hasher.update(self);
+    }
+}
+
+/// `uint256`.
+impl MappingKey for StorageSlot {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        hasher.update(self.0);
+    }
+}
+
+impl MappingKey for bool {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        let mut word = [0; 32];
+        word[31] = *self as u8;
+        hasher.update(word);
+    }
+}
+
+/// `string`.
+impl MappingKey for str {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        hasher.update(self);
+    }
+}
+
+/// `bytes`.
+impl MappingKey for [u8] {
+    #[inline]
+    fn update_hasher(&self, hasher: &mut Keccak256) {
+        hasher.update(self);
+    }
+}
+
+macro_rules! impl_mapping_key_int {
+    ($($t:ty),* $(,)?) => {$(
+        impl MappingKey for $t {
+            #[inline]
+            fn update_hasher(&self, hasher: &mut Keccak256) {
+                const SIZE: usize = core::mem::size_of::<$t>();
+                // Sign-extend signed integers.
+                #[allow(unused_comparisons)]
+                let fill = if *self < 0 { 0xff } else { 0 };
+                let mut word = [fill; 32];
+                word[32 - SIZE..].copy_from_slice(&self.to_be_bytes());
+                hasher.update(word);
+            }
+        }
+    )*};
+}
+
+impl_mapping_key_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
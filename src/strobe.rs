@@ -0,0 +1,261 @@
+//! The [STROBE](https://strobe.sourceforge.io/) protocol framework at the 128-bit security level,
+//! over Keccak-f[1600].
+//!
+//! Every operation is framed by its flags and position, so that a sequence of operations is bound
+//! to its structure and not just to the concatenation of its data. This is the construction behind
+//! [Merlin transcripts](crate::transcript::Transcript).
+//!
+//! Operations can be split over several calls by setting `more` on all calls after the first.
+//! Doing so with a different operation than the previous one panics.
+
+use crate::duplex::Duplex;
+use core::fmt;
+
+/// The STROBE rate, in bytes: the 168-byte rate of Keccak at the 128-bit security level, minus
+/// the two padding bytes.
+const R: usize = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_T: u8 = 1 << 3;
+const FLAG_M: u8 = 1 << 4;
+const FLAG_K: u8 = 1 << 5;
+
+/// A received MAC does not match the state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuthError;
+
+impl fmt::Display for AuthError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MAC verification failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthError {}
+
+/// A STROBE-128 context, as of STROBE version 1.0.2.
+#[derive(Clone)]
+pub struct Strobe128 {
+    duplex: Duplex<168>,
+    pos: usize,
+    pos_begin: usize,
+    /// The `I` flag of the first transport operation, which makes this side the initiator (`0`)
+    /// or the responder (`1`).
+    i0: Option<u8>,
+    cur_flags: Option<u8>,
+}
+
+impl fmt::Debug for Strobe128 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Strobe128 { ... }")
+    }
+}
+
+macro_rules! operations {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident, $meta_name:ident, $data:ty, $flags:expr, $process:ident;
+    )*) => {$(
+        $(#[$attr])*
+        #[inline]
+        pub fn $name(&mut self, data: $data, more: bool) {
+            self.begin_op($flags, more);
+            self.$process(data);
+        }
+
+        $(#[$attr])*
+        ///
+        /// This is the meta variant of the operation, for framing data.
+        #[inline]
+        pub fn $meta_name(&mut self, data: $data, more: bool) {
+            self.begin_op(FLAG_M | $flags, more);
+            self.$process(data);
+        }
+    )*};
+}
+
+impl Strobe128 {
+    /// Creates a context, initialized with the customization string `protocol` through a meta-AD
+    /// operation.
+    pub fn new(protocol: &[u8]) -> Self {
+        let mut duplex = Duplex::new();
+        duplex.overwrite(0, &[0x01, R as u8 + 2, 0x01, 0x00, 0x01, 0x60]);
+        duplex.overwrite(6, b"STROBEv1.0.2");
+        duplex.permute();
+
+        let mut strobe = Self { duplex, pos: 0, pos_begin: 0, i0: None, cur_flags: None };
+        strobe.meta_ad(protocol, false);
+        strobe
+    }
+
+    operations! {
+        /// Absorbs associated data.
+        ad, meta_ad, &[u8], FLAG_A, absorb;
+        /// Absorbs a key, overwriting the state.
+        key, meta_key, &[u8], FLAG_A | FLAG_C, overwrite;
+        /// Absorbs a cleartext message sent to the other party.
+        send_clr, meta_send_clr, &[u8], FLAG_A | FLAG_T, absorb;
+        /// Absorbs a cleartext message received from the other party.
+        recv_clr, meta_recv_clr, &[u8], FLAG_I | FLAG_A | FLAG_T, absorb;
+        /// Extracts pseudorandom data. The initial contents of `data` are ignored.
+        prf, meta_prf, &mut [u8], FLAG_I | FLAG_A | FLAG_C, squeeze;
+        /// Encrypts a message sent to the other party in place.
+        send_enc, meta_send_enc, &mut [u8], FLAG_A | FLAG_C | FLAG_T, absorb_and_set;
+        /// Decrypts a message received from the other party in place.
+        recv_enc, meta_recv_enc, &mut [u8], FLAG_I | FLAG_A | FLAG_C | FLAG_T, exchange;
+        /// Computes a MAC to send to the other party. The initial contents of `data` are ignored.
+        send_mac, meta_send_mac, &mut [u8], FLAG_C | FLAG_T, copy_state;
+    }
+
+    /// Verifies a MAC received from the other party, in constant time.
+    ///
+    /// `data` is overwritten with its difference to the expected MAC. MAC checks cannot be
+    /// split over several calls.
+    #[inline]
+    pub fn recv_mac(&mut self, data: &mut [u8]) -> Result<(), AuthError> {
+        self.begin_op(FLAG_I | FLAG_C | FLAG_T, false);
+        self.check_mac(data)
+    }
+
+    /// Verifies a MAC received from the other party, in constant time.
+    ///
+    /// This is the meta variant of [`recv_mac`](Self::recv_mac).
+    #[inline]
+    pub fn meta_recv_mac(&mut self, data: &mut [u8]) -> Result<(), AuthError> {
+        self.begin_op(FLAG_M | FLAG_I | FLAG_C | FLAG_T, false);
+        self.check_mac(data)
+    }
+
+    /// Zeroes `len` bytes of the state, running the permutation as needed, so that earlier states
+    /// cannot be recovered.
+    #[inline]
+    pub fn ratchet(&mut self, len: usize, more: bool) {
+        self.begin_op(FLAG_C, more);
+        self.zero(len);
+    }
+
+    /// Zeroes `len` bytes of the state, running the permutation as needed, so that earlier states
+    /// cannot be recovered.
+    ///
+    /// This is the meta variant of [`ratchet`](Self::ratchet).
+    #[inline]
+    pub fn meta_ratchet(&mut self, len: usize, more: bool) {
+        self.begin_op(FLAG_M | FLAG_C, more);
+        self.zero(len);
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        if more {
+            assert_eq!(
+                self.cur_flags,
+                Some(flags),
+                "`more` can only continue the same operation as the previous one"
+            );
+            return;
+        }
+        self.cur_flags = Some(flags);
+
+        // Toggle the direction so that both parties agree on it.
+        let mut adjusted = flags;
+        if flags & FLAG_T != 0 {
+            adjusted ^= *self.i0.get_or_insert(flags & FLAG_I);
+        }
+
+        let old_begin = self.pos_begin;
+        self.pos_begin = self.pos + 1;
+        self.absorb(&[old_begin as u8, adjusted]);
+
+        if adjusted & (FLAG_C | FLAG_K) != 0 && self.pos != 0 {
+            self.run_f();
+        }
+    }
+
+    fn check_mac(&mut self, data: &mut [u8]) -> Result<(), AuthError> {
+        self.exchange(data);
+        if data.iter().fold(0, |acc, &b| acc | b) == 0 {
+            Ok(())
+        } else {
+            Err(AuthError)
+        }
+    }
+
+    fn run_f(&mut self) {
+        self.duplex.xor_in(self.pos, &[self.pos_begin as u8, 0x04]);
+        self.duplex.xor_in(R + 1, &[0x80]);
+        self.duplex.permute();
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    /// Calls `f` with the state, the position and the range of each part of the `len` bytes of
+    /// data that fits in the current block, running the permutation at the end of every block.
+    #[inline]
+    fn for_each_block<F>(&mut self, len: usize, mut f: F)
+    where
+        F: FnMut(&mut Duplex<168>, usize, core::ops::Range<usize>),
+    {
+        let mut start = 0;
+        while start < len {
+            let n = (R - self.pos).min(len - start);
+            f(&mut self.duplex, self.pos, start..start + n);
+            start += n;
+            self.pos += n;
+            if self.pos == R {
+                self.run_f();
+            }
+        }
+    }
+
+    /// `state ^= data`.
+    fn absorb(&mut self, data: &[u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| duplex.xor_in(pos, &data[range]));
+    }
+
+    /// `state = data`.
+    fn overwrite(&mut self, data: &[u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| duplex.overwrite(pos, &data[range]));
+    }
+
+    /// `state ^= data; data = state`.
+    fn absorb_and_set(&mut self, data: &mut [u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| {
+            duplex.xor_in(pos, &data[range.clone()]);
+            duplex.extract(pos, &mut data[range]);
+        });
+    }
+
+    /// `data = state`.
+    fn copy_state(&mut self, data: &mut [u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| duplex.extract(pos, &mut data[range]));
+    }
+
+    /// `data = state; state = 0`.
+    fn squeeze(&mut self, data: &mut [u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| {
+            duplex.extract(pos, &mut data[range.clone()]);
+            duplex.xor_in(pos, &data[range]);
+        });
+    }
+
+    /// `data ^= state; state ^= data`, which sets the state to the original data.
+    fn exchange(&mut self, data: &mut [u8]) {
+        self.for_each_block(data.len(), |duplex, pos, range| {
+            let mut state = [0u8; R];
+            let state = &mut state[..range.len()];
+            duplex.extract(pos, state);
+            duplex.overwrite(pos, &data[range.clone()]);
+            data[range].iter_mut().zip(state).for_each(|(d, s)| *d ^= *s);
+        });
+    }
+
+    /// `state = 0`.
+    fn zero(&mut self, len: usize) {
+        self.for_each_block(len, |duplex, pos, range| {
+            duplex.overwrite(pos, &[0; R][..range.len()])
+        });
+    }
+}
@@ -0,0 +1,323 @@
+//! Fiat-Shamir transcripts.
+//!
+//! [`Transcript`] is byte-compatible with the transcripts of the
+//! [`merlin`](https://merlin.cool) crate: it frames every message with its label and length on a
+//! [STROBE-128](crate::strobe) context, and derives challenges from everything appended so far.
+//!
+//! [`KeccakTranscript`] derives challenges the way Solidity verifiers do, as
+//! `keccak256(abi.encodePacked(elements...)) % modulus`, with a configurable [`Chaining`] of the
+//! successive challenges.
+
+use crate::{strobe::Strobe128, Keccak256};
+use digest::Digest;
+
+/// The STROBE customization string of Merlin transcripts.
+const MERLIN_PROTOCOL_LABEL: &[u8] = b"Merlin v1.0";
+
+/// Encodes a length as a little-endian `u32`.
+///
+/// # Panics
+///
+/// Panics if `len` does not fit in a `u32`.
+#[inline]
+fn encode_len(len: usize) -> [u8; 4] {
+    u32::try_from(len).expect("length does not fit in a u32").to_le_bytes()
+}
+
+/// A Merlin transcript of a public-coin argument.
+///
+/// Labels are static to make it clear that they are part of the protocol definition rather than
+/// data.
+#[derive(Clone, Debug)]
+pub struct Transcript {
+    strobe: Strobe128,
+}
+
+impl Transcript {
+    /// Creates a transcript for the protocol or application named `label`.
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut transcript = Self { strobe: Strobe128::new(MERLIN_PROTOCOL_LABEL) };
+        transcript.append_message(b"dom-sep", label);
+        transcript
+    }
+
+    /// Appends a message: `meta-AD(label || LE32(message.len())); AD(message)`.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&encode_len(message.len()), true);
+        self.strobe.ad(message, false);
+    }
+
+    /// Appends a `u64` as a little-endian message.
+    #[inline]
+    pub fn append_u64(&mut self, label: &'static [u8], x: u64) {
+        self.append_message(label, &x.to_le_bytes());
+    }
+
+    /// Fills `dest` with a challenge: `meta-AD(label || LE32(dest.len())); PRF(dest)`.
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&encode_len(dest.len()), true);
+        self.strobe.prf(dest, false);
+    }
+
+    /// Forks the transcript into a builder of an RNG bound to the public transcript, to be rekeyed
+    /// with the prover's secrets.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    #[inline]
+    pub fn build_rng(&self) -> TranscriptRngBuilder {
+        TranscriptRngBuilder { strobe: self.strobe.clone() }
+    }
+}
+
+/// A builder of a [`TranscriptRng`], created with [`Transcript::build_rng`].
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+#[derive(Debug)]
+pub struct TranscriptRngBuilder {
+    strobe: Strobe128,
+}
+
+#[cfg(feature = "rand_core")]
+impl TranscriptRngBuilder {
+    /// Rekeys the RNG with witness data: `meta-AD(label || LE32(witness.len())); KEY(witness)`.
+    pub fn rekey_with_witness_bytes(mut self, label: &'static [u8], witness: &[u8]) -> Self {
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&encode_len(witness.len()), true);
+        self.strobe.key(witness, false);
+        self
+    }
+
+    /// Rekeys the RNG with 32 bytes from `rng`, and finalizes it.
+    pub fn finalize<R>(mut self, rng: &mut R) -> TranscriptRng
+    where
+        R: rand_core::RngCore + rand_core::CryptoRng,
+    {
+        let mut random_bytes = [0u8; 32];
+        rng.fill_bytes(&mut random_bytes);
+        self.strobe.meta_ad(b"rng", false);
+        self.strobe.key(&random_bytes, false);
+        TranscriptRng { strobe: self.strobe }
+    }
+}
+
+/// An RNG bound to a transcript, the witness and external randomness.
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+#[derive(Debug)]
+pub struct TranscriptRng {
+    strobe: Strobe128,
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::RngCore for TranscriptRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.strobe.meta_ad(&encode_len(dest.len()), false);
+        self.strobe.prf(dest, false);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::CryptoRng for TranscriptRng {}
+
+/// How a [`KeccakTranscript`] chains its challenges.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Chaining {
+    /// Every challenge hashes the unreduced hash of the previous one, or the initial state, which
+    /// is zero by default, followed by the elements appended since.
+    #[default]
+    Unreduced,
+    /// Every challenge hashes the previous challenge after reduction, if any, followed by the
+    /// elements appended since.
+    Reduced,
+    /// Every challenge hashes the elements appended since the previous one only. The protocol
+    /// appends the challenges it depends on itself, like the `Keccak256Transcript` of snarkjs.
+    None,
+}
+
+/// A Fiat-Shamir transcript matching Solidity verifiers, which derive challenges as
+/// `uint256(keccak256(abi.encodePacked(elements...))) % modulus`.
+///
+/// Field elements and curve point coordinates are appended as 32-byte big-endian words, the EVM
+/// layout. Each challenge hashes the elements appended since the previous challenge, prefixed with
+/// a 32-byte state that depends on the [`Chaining`] of the transcript.
+#[derive(Clone, Debug)]
+pub struct KeccakTranscript {
+    hasher: Keccak256,
+    state: Option<[u8; 32]>,
+    modulus: [u64; 4],
+    chaining: Chaining,
+}
+
+impl KeccakTranscript {
+    /// Creates a transcript whose challenges are reduced modulo `modulus`, a big-endian integer,
+    /// with the [`Chaining::Unreduced`] chaining and a zero initial state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: &[u8; 32]) -> Self {
+        let modulus = to_limbs(modulus);
+        assert!(modulus != [0; 4], "modulus must not be zero");
+        Self::with_parts(Some([0; 32]), modulus, Chaining::Unreduced)
+    }
+
+    /// Sets the chaining of the challenges, discarding the elements appended so far.
+    ///
+    /// The state is reset to zero for [`Chaining::Unreduced`], and cleared otherwise.
+    #[inline]
+    pub fn with_chaining(self, chaining: Chaining) -> Self {
+        let state = match chaining {
+            Chaining::Unreduced => Some([0; 32]),
+            Chaining::Reduced | Chaining::None => None,
+        };
+        Self::with_parts(state, self.modulus, chaining)
+    }
+
+    /// Sets the state prefixed to the next challenge, discarding the elements appended so far.
+    #[inline]
+    pub fn with_state(self, state: [u8; 32]) -> Self {
+        Self::with_parts(Some(state), self.modulus, self.chaining)
+    }
+
+    fn with_parts(state: Option<[u8; 32]>, modulus: [u64; 4], chaining: Chaining) -> Self {
+        let mut hasher = Keccak256::new();
+        if let Some(state) = state {
+            hasher.update(state);
+        }
+        Self { hasher, state, modulus, chaining }
+    }
+
+    /// Returns the state prefixed to the next challenge, if any.
+    #[inline]
+    pub fn state(&self) -> Option<[u8; 32]> {
+        self.state
+    }
+
+    /// Appends a 32-byte word, such as a big-endian field element.
+    #[inline]
+    pub fn append_word(&mut self, word: &[u8; 32]) {
+        self.hasher.update(word);
+    }
+
+    /// Appends an integer as a 32-byte big-endian word, as `uint256`.
+    #[inline]
+    pub fn append_u64(&mut self, x: u64) {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&x.to_be_bytes());
+        self.append_word(&word);
+    }
+
+    /// Appends an affine G1 point as its coordinates `x || y`.
+    #[inline]
+    pub fn append_g1(&mut self, x: &[u8; 32], y: &[u8; 32]) {
+        self.append_word(x);
+        self.append_word(y);
+    }
+
+    /// Appends an affine G2 point over a quadratic extension, with coordinates `x = x0 + x1 * u`
+    /// and `y = y0 + y1 * u` given as `[c0, c1]`.
+    ///
+    /// The words are appended in the layout of the EVM pairing precompile, with the imaginary part
+    /// first: `x1 || x0 || y1 || y0`.
+    #[inline]
+    pub fn append_g2(&mut self, x: &[[u8; 32]; 2], y: &[[u8; 32]; 2]) {
+        self.append_word(&x[1]);
+        self.append_word(&x[0]);
+        self.append_word(&y[1]);
+        self.append_word(&y[0]);
+    }
+
+    /// Appends raw bytes, as `bytes` in `abi.encodePacked`.
+    #[inline]
+    pub fn append_bytes(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    /// Returns the next challenge, reduced modulo the modulus, as a big-endian integer.
+    pub fn challenge(&mut self) -> [u8; 32] {
+        self.next_challenge().1
+    }
+
+    /// Returns the hash of the next challenge before reduction.
+    #[inline]
+    pub fn challenge_unreduced(&mut self) -> [u8; 32] {
+        self.next_challenge().0
+    }
+
+    /// Returns the next challenge before and after reduction, and chains it.
+    fn next_challenge(&mut self) -> ([u8; 32], [u8; 32]) {
+        let hash: [u8; 32] = self.hasher.finalize_reset().into();
+        let reduced = from_limbs(&reduce(to_limbs(&hash), &self.modulus));
+        self.state = match self.chaining {
+            Chaining::Unreduced => Some(hash),
+            Chaining::Reduced => Some(reduced),
+            Chaining::None => None,
+        };
+        if let Some(state) = self.state {
+            self.hasher.update(state);
+        }
+        (hash, reduced)
+    }
+}
+
+/// Returns the big-endian limbs of a big-endian integer.
+#[inline]
+fn to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+#[inline]
+fn from_limbs(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// Returns `x mod m` by binary long division, on big-endian limbs.
+fn reduce(x: [u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let mut r = [0u64; 4];
+    for bit in (0..256).rev() {
+        // `r < m`, so `2r + 1 < 2m` and one subtraction is enough, including when the shift
+        // overflows.
+        let carry = r[0] >> 63;
+        for i in 0..3 {
+            r[i] = (r[i] << 1) | (r[i + 1] >> 63);
+        }
+        r[3] = (r[3] << 1) | ((x[3 - bit / 64] >> (bit % 64)) & 1);
+        if carry != 0 || r >= *m {
+            let mut borrow = false;
+            for i in (0..4).rev() {
+                let (d, b1) = r[i].overflowing_sub(m[i]);
+                let (d, b2) = d.overflowing_sub(borrow as u64);
+                r[i] = d;
+                borrow = b1 | b2;
+            }
+        }
+    }
+    r
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::hex_array;
+use keccak_asm::bloom::{Bloom, BloomInput};
+
+const ADDRESS: [u8; 20] = hex_array(b"ef2d6d194084c2de36e0dabfce45d046b37d1106");
+const TOPIC: [u8; 32] =
+    hex_array(b"02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc");
+const BLOOM: &str = "00000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002020000000000000000000000000000000000000000000008000000001000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+#[test]
+fn accrue() {
+    let mut bloom = Bloom::default();
+    assert!(bloom.is_zero());
+    assert!(!bloom.contains_input(BloomInput::Raw(&ADDRESS)));
+
+    bloom.accrue(BloomInput::Raw(&ADDRESS));
+    bloom.accrue(BloomInput::Raw(&TOPIC));
+    assert_eq!(hex::encode(bloom), BLOOM);
+    assert!(bloom.contains_input(BloomInput::Raw(&ADDRESS)));
+    assert!(bloom.contains_input(BloomInput::Raw(&TOPIC)));
+    assert!(!bloom.contains_input(BloomInput::Raw(b"not in the bloom")));
+
+    assert_eq!(Bloom::from_log(&ADDRESS, &[TOPIC]), bloom);
+    assert_eq!(Bloom::from_logs([(&ADDRESS, &[TOPIC])]), bloom);
+}
+
+#[test]
+fn union() {
+    let a = Bloom::from_log(&ADDRESS, &[]);
+    let b = Bloom::from_log(&[0; 20], &[TOPIC]);
+    let ab = a | b;
+    assert!(ab.contains_bloom(&a));
+    assert!(ab.contains_bloom(&b));
+    assert!(!a.contains_bloom(&ab));
+    assert!(ab.contains_input(BloomInput::Raw(&TOPIC)));
+}
+
+#[test]
+fn wire_form() {
+    let bytes = hex::decode(BLOOM).unwrap();
+    let bloom = Bloom::from_slice(&bytes).unwrap();
+    assert_eq!(bloom.as_bytes()[..], bytes[..]);
+    assert_eq!(Bloom::from(<[u8; 256]>::from(bloom)), bloom);
+    assert!(Bloom::from_slice(&bytes[1..]).is_err());
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+/// Decodes a hex string into an array, in constants.
+pub const fn hex_array<const N: usize>(s: &[u8]) -> [u8; N] {
+    match hex::const_decode_to_array(s) {
+        Ok(a) => a,
+        Err(_) => panic!("invalid hex"),
+    }
+}
+
+/// Returns the `len` bytes `0, 1, ...`, wrapping around.
+pub fn bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+/// Returns the bytes `start, start + 1, ...`, in constants.
+pub const fn sequence<const N: usize>(start: u8) -> [u8; N] {
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = start.wrapping_add(i as u8);
+        i += 1;
+    }
+    out
+}
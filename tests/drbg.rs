@@ -0,0 +1,168 @@
+#![cfg(feature = "rand_core")]
+
+mod common;
+
+use common::sequence;
+use keccak_asm::{
+    drbg::{rand_core::RngCore, DrbgDigest, DrbgError, HashDrbg, HmacDrbg, MAX_REQUEST_BYTES},
+    Sha3_256, Sha3_512,
+};
+
+const ENTROPY: [u8; 32] = sequence(0);
+const RESEED_ENTROPY: [u8; 32] = sequence(0x80);
+const NONCE: [u8; 16] = sequence(0x20);
+const PERSONALIZATION: &[u8] = b"personalization";
+
+/// Expected outputs of a DRBG for the scenarios of [`check`].
+struct Vectors {
+    /// The two outputs of 64 bytes following instantiation with a personalization string.
+    no_reseed: [&'static str; 2],
+    /// The second output of 100 bytes following instantiation without a personalization string
+    /// and a reseed, with additional input.
+    reseed: &'static str,
+    /// An output of 40 bytes with prediction resistance and additional input.
+    prediction_resistance: &'static str,
+}
+
+/// The DRBG interface shared by `HashDrbg` and `HmacDrbg`.
+trait Drbg: RngCore + Sized {
+    fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError>;
+    fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) -> Result<(), DrbgError>;
+    fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), DrbgError>;
+    fn generate_pr(&mut self, out: &mut [u8], entropy: &[u8], add: &[u8]) -> Result<(), DrbgError>;
+    fn reseed_counter(&self) -> u64;
+    fn with_reseed_interval(self, interval: u64) -> Self;
+}
+
+macro_rules! impl_drbg {
+    ($($ty:ident),*) => {$(
+        impl<D: DrbgDigest> Drbg for $ty<D> {
+            fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError> {
+                $ty::new(entropy, nonce, personalization)
+            }
+            fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+                $ty::reseed(self, entropy, additional_input)
+            }
+            fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+                $ty::generate(self, out, additional_input)
+            }
+            fn generate_pr(&mut self, out: &mut [u8], entropy: &[u8], add: &[u8]) -> Result<(), DrbgError> {
+                $ty::generate_with_prediction_resistance(self, out, entropy, add)
+            }
+            fn reseed_counter(&self) -> u64 {
+                $ty::reseed_counter(self)
+            }
+            fn with_reseed_interval(self, interval: u64) -> Self {
+                $ty::with_reseed_interval(self, interval)
+            }
+        }
+    )*};
+}
+
+impl_drbg!(HashDrbg, HmacDrbg);
+
+#[track_caller]
+fn check<T: Drbg>(vectors: &Vectors) {
+    let mut drbg = T::new(&ENTROPY, &NONCE, PERSONALIZATION).unwrap();
+    let mut out = [0; 64];
+    for expected in vectors.no_reseed {
+        drbg.generate(&mut out, &[]).unwrap();
+        assert_eq!(hex::encode(out), expected);
+    }
+    assert_eq!(drbg.reseed_counter(), 3);
+
+    let mut drbg = T::new(&ENTROPY, &NONCE, &[]).unwrap();
+    drbg.reseed(&RESEED_ENTROPY, b"additional 1").unwrap();
+    assert_eq!(drbg.reseed_counter(), 1);
+    let mut out = [0; 100];
+    drbg.generate(&mut out, b"additional 1").unwrap();
+    drbg.generate(&mut out, b"additional 2").unwrap();
+    assert_eq!(hex::encode(out), vectors.reseed);
+
+    let mut drbg = T::new(&ENTROPY, &NONCE, PERSONALIZATION).unwrap();
+    let mut out = [0; 40];
+    drbg.generate_pr(&mut out, &RESEED_ENTROPY, b"additional 1").unwrap();
+    assert_eq!(hex::encode(out), vectors.prediction_resistance);
+
+    // `RngCore` generates without additional input.
+    let mut drbg = T::new(&ENTROPY, &NONCE, PERSONALIZATION).unwrap();
+    let mut out = [0; 64];
+    drbg.fill_bytes(&mut out);
+    assert_eq!(hex::encode(out), vectors.no_reseed[0]);
+    let next = drbg.next_u64();
+    assert_eq!(hex::encode(next.to_le_bytes()), &vectors.no_reseed[1][..16]);
+}
+
+#[track_caller]
+fn check_errors<T: Drbg>() {
+    assert_eq!(T::new(&ENTROPY[..16], &NONCE, &[]).err(), Some(DrbgError::InsufficientEntropy));
+
+    let mut drbg = T::new(&ENTROPY, &NONCE, &[]).unwrap().with_reseed_interval(2);
+    let mut out = [0; 16];
+    drbg.generate(&mut out, &[]).unwrap();
+    drbg.generate(&mut out, &[]).unwrap();
+    assert_eq!(drbg.generate(&mut out, &[]), Err(DrbgError::ReseedRequired));
+    assert!(drbg.try_fill_bytes(&mut out).is_err());
+    assert_eq!(drbg.reseed(&ENTROPY[..16], &[]), Err(DrbgError::InsufficientEntropy));
+    drbg.reseed(&RESEED_ENTROPY, &[]).unwrap();
+    drbg.generate(&mut out, &[]).unwrap();
+
+    let mut out = vec![0; MAX_REQUEST_BYTES + 1];
+    assert_eq!(drbg.generate(&mut out, &[]), Err(DrbgError::RequestTooLarge));
+    // `RngCore` splits large requests.
+    let mut drbg = T::new(&ENTROPY, &NONCE, &[]).unwrap();
+    drbg.fill_bytes(&mut out);
+    assert_eq!(drbg.reseed_counter(), 3);
+}
+
+#[test]
+fn hash_drbg_sha3_256() {
+    check::<HashDrbg<Sha3_256>>(&Vectors {
+        no_reseed: [
+            "b85a8a747c6488c974141c06a7bf6a60a43e12505919e7eb9bc2c1ebfd32c0aba36d37e9610c259ea86dd39f2118ec2fcc5fbf5d7d9ee73dc92ddf9998dc98df",
+            "d6872d2b3e6f79659e363183977d78d3a0731fda6ba8eb1d5c58b84c8fcc32a72f41c0eafc1e3633c464f16e672e0c9b4bd0b37a54ed16e0158b0c36d0c38975",
+        ],
+        reseed: "6ea89f036ebfd1ef5a6bc5b523205a8d59c09a7f57e80f8e6950c0b7e226e1d109a2387db5e2a7d4dfb0b838a0e2bbe35506c71217a8cf754b808d1ddee2cba2265b93c903b8e55d98cd86386d0a252b0d0e04633be0ce5c580641a10cef97dfffdeda2c",
+        prediction_resistance: "8ee4df57537a2d67e9d66f242b64c2c9533c29671ee8523a86da82b0287fbe68f7debc74ea3089f6",
+    });
+    check_errors::<HashDrbg<Sha3_256>>();
+}
+
+#[test]
+fn hash_drbg_sha3_512() {
+    check::<HashDrbg<Sha3_512>>(&Vectors {
+        no_reseed: [
+            "03e3da4de41c1353303b15a01af4d280c1f3aff8c9cfdbfe4f2784152002b23e58da706d0df34e17186f74f1bab3cd0d6b6e55dc9cd06c35f0f1419d3c498789",
+            "5efa52b52e5659da9b4368a420436c36223d3226ec2a18a802d3bbc0104b3d373b2c9b31afc0bd7317d6bbd57f60157c402a96e591e3d0194bc50b273eaa4187",
+        ],
+        reseed: "ce830ddb7c071488aa52f5c332deda9f37d4ce49e0995c971965bfac0f64d772026038e9d8e60ab8e1c273ff8bbfef8184d929be4c365060125b868f76563cc89e250f5bb1dbfbbd6bc72eb1ad45855580a4322905f571fb6d54cec5807821aa65eab6cf",
+        prediction_resistance: "b040b52941a73a83c1fdc0f3c5a4410d5a423193ec77a165794eb8f990e56cd2fda92bdd628508fb",
+    });
+    check_errors::<HashDrbg<Sha3_512>>();
+}
+
+#[test]
+fn hmac_drbg_sha3_256() {
+    check::<HmacDrbg<Sha3_256>>(&Vectors {
+        no_reseed: [
+            "0650f130ed755d7a7605bd840447d01c7d979445818bc1c6180467b8700ff5cd5e4f55bc55b8793d81c581d4ddd26af449cbd8b79e31e94cb9dc921437cdb11f",
+            "6e566623eaf03262e694e1474b21443f2273be3dd56f712bb33536d9207d4ee3c2660510072db86fd7a85c95053b10914cb26232f093fababc83656c746c791e",
+        ],
+        reseed: "d57f7584b4b3aed973f052dab4f91a9d6e02dddcb66c70a59f14d8f38def7b89ca9a3161c002b48f74b303a097866c207aefb732a49f9f3032810eefcb022c011f3bf6818aa2768a4f90a742db80de349ac53ea5a1531c196c21a2550599ceb7f95d824d",
+        prediction_resistance: "64b48092e62038a2c70cd3638b55a90dcc09a473e63f7cc340ddeacd0e85e3f003b31cca6a7e6041",
+    });
+    check_errors::<HmacDrbg<Sha3_256>>();
+}
+
+#[test]
+fn hmac_drbg_sha3_512() {
+    check::<HmacDrbg<Sha3_512>>(&Vectors {
+        no_reseed: [
+            "8c6956e1d0aa7ce8c4dcd36b678b1990b1c4f9cd04728b8e27575af34b80b7b93a331298395ef8716c83229714aa3131a439cd274b14a1d8adf91bf0f656ae08",
+            "fb176db11aef29be66f5afec25b5cc0c149b7c0d0adf9a511394465a8c7b361871bb97cbd197d1808fea2510aaf6f9b6f2a200ab3936ae1737aa9fd8ad4763fe",
+        ],
+        reseed: "e2fb6fcd1cb2b6ecb70319fb69a5d4fca9c3967f330ad1cba61ad91ee7ceba52944b53fa50d0d4871772943facfb634cad0445d73f1e059e7b02aed4d63535862435d484c49354ca164b7e1eabaed68821f681e276573b184a12a24356af7f366575a1fe",
+        prediction_resistance: "8a26ac14b56b2d1efe42525214a8dc37dfee46c7599f57ac66953045470a2a56a3ba623c429261ba",
+    });
+    check_errors::<HmacDrbg<Sha3_512>>();
+}
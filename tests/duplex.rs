@@ -0,0 +1,83 @@
+use keccak_asm::{duplex::Duplex, Digest, Keccak256, Sha3_256};
+
+#[test]
+fn first_call_is_a_sponge() {
+    let mut out = [0; 32];
+    Duplex::<136>::new().duplexing(b"abc", &mut out);
+    assert_eq!(out, Keccak256::digest(b"abc").as_slice());
+
+    let mut duplex = Duplex::<136>::new().with_domain(0x06);
+    assert_eq!(duplex.domain(), 0x06);
+    duplex.duplexing(b"abc", &mut out);
+    assert_eq!(out, Sha3_256::digest(b"abc").as_slice());
+
+    // The domain byte and the final padding bit share the last byte.
+    let input = [0x42; 135];
+    Duplex::<136>::new().duplexing(&input, &mut out);
+    assert_eq!(out, Keccak256::digest(input).as_slice());
+}
+
+#[test]
+fn domain_next_to_last_padding_bit() {
+    // With an input of `RATE - 1` bytes, the domain byte shares the last byte with the final bit.
+    let mut block = [0x42; 136];
+    block[135] = 0x7f ^ 0x80;
+    let mut a = [0; 32];
+    let mut b = [0; 32];
+    Duplex::<136>::new().with_domain(0x7f).duplexing(&block[..135], &mut a);
+    Duplex::<136>::new().duplexing_unpadded(&block, &mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+#[should_panic = "the domain byte must not overlap the last padding bit"]
+fn domain_overlapping_padding() {
+    Duplex::<136>::new().with_domain(0x80);
+}
+
+#[test]
+fn duplexing() {
+    let mut duplex = Duplex::<136>::new();
+    let mut out = [0; 136];
+    duplex.duplexing(b"abc", &mut out[..32]);
+    assert_eq!(
+        hex::encode(&out[..32]),
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+    );
+    duplex.duplexing(b"def", &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "a35523dabfcaf32d2a5d65fa1a445851be5c98e1647035ef9f0fe863b22708d8efe086a8beebe55ef1910250e3ffc7b90017d13a68e18454659adce7d7e36775ac30bdadffbf3d70e34d02c5f126acafaa84acf63e91cc55fd92245fb4240f439caa0c67889961d90352ad7daac2a4c76b2993a62307b6a65566364323258b9cc3e7a336da47fcae"
+    );
+    duplex.duplexing_unpadded(&[0xaa; 136], &mut out[..16]);
+    assert_eq!(hex::encode(&out[..16]), "76150d68210534cdb2f0cef654ddf06f");
+    duplex.duplexing(&[], &mut out[..32]);
+    assert_eq!(
+        hex::encode(&out[..32]),
+        "4c87c957ebca419de869a1ecb07440867e05b6855ecefb7951a95ebeab85f524"
+    );
+}
+
+#[test]
+fn state_access() {
+    let mut duplex = Duplex::<168>::default();
+    duplex.xor_in(3, &[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+    assert_eq!(duplex.state()[0], 0x9a78_5634_1200_0000);
+    assert_eq!(duplex.state()[1], 0xbc);
+    duplex.overwrite(7, &[0xff, 0x01]);
+    assert_eq!(duplex.state()[0], 0xff78_5634_1200_0000);
+    assert_eq!(duplex.state()[1], 0x01);
+    let mut out = [0; 4];
+    duplex.extract(6, &mut out);
+    assert_eq!(out, [0x78, 0xff, 0x01, 0x00]);
+
+    // Unpadded duplexing is an XOR followed by the permutation.
+    let mut other = duplex.clone();
+    let mut a = [0; 8];
+    let mut b = [0; 8];
+    duplex.duplexing_unpadded(&[1, 2, 3], &mut a);
+    other.state_mut()[0] ^= 0x0003_0201;
+    other.permute();
+    other.extract(0, &mut b);
+    assert_eq!(a, b);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "eip712")]
+
+use keccak_asm::{eip191_personal_message_hash, eip712::TypedData};
+
+// https://github.com/ethereum/EIPs/blob/master/assets/eip-712/Example.js
+const MAIL: &str = r#"{
+    "types": {
+        "EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" }
+        ],
+        "Person": [
+            { "name": "name", "type": "string" },
+            { "name": "wallet", "type": "address" }
+        ],
+        "Mail": [
+            { "name": "from", "type": "Person" },
+            { "name": "to", "type": "Person" },
+            { "name": "contents", "type": "string" }
+        ]
+    },
+    "primaryType": "Mail",
+    "domain": {
+        "name": "Ether Mail",
+        "version": "1",
+        "chainId": 1,
+        "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+    },
+    "message": {
+        "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+        "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+        "contents": "Hello, Bob!"
+    }
+}"#;
+
+#[test]
+fn eip191_personal_message() {
+    assert_eq!(
+        hex::encode(eip191_personal_message_hash("hello world")),
+        "d9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68"
+    );
+}
+
+#[test]
+fn eip712_mail() {
+    let data = TypedData::from_json(MAIL).unwrap();
+    assert_eq!(
+        data.encode_type("Mail").unwrap(),
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+    );
+    assert_eq!(
+        hex::encode(data.type_hash("Mail").unwrap()),
+        "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
+    );
+    assert_eq!(
+        hex::encode(data.hash_struct("Mail", &data.message).unwrap()),
+        "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+    );
+    assert_eq!(
+        hex::encode(data.domain_separator().unwrap()),
+        "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+    );
+    assert_eq!(
+        hex::encode(data.signing_hash().unwrap()),
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+    );
+}
+
+#[test]
+fn eip712_implicit_domain_type() {
+    let mut data = TypedData::from_json(MAIL).unwrap();
+    data.types.remove("EIP712Domain");
+    assert_eq!(
+        hex::encode(data.domain_separator().unwrap()),
+        "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+    );
+}
+
+#[test]
+fn eip712_rejects_invalid_values() {
+    let mut data = TypedData::from_json(MAIL).unwrap();
+    data.message["to"]["wallet"] = "0x1234".into();
+    assert!(data.signing_hash().is_err());
+
+    let mut data = TypedData::from_json(MAIL).unwrap();
+    data.domain.insert("chainId".into(), "-1".into());
+    assert!(data.domain_separator().is_err());
+}
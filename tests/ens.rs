@@ -0,0 +1,58 @@
+use keccak_asm::ens::{labelhash, namehash};
+
+#[test]
+fn hashes() {
+    assert_eq!(namehash(""), [0; 32]);
+    assert_eq!(
+        hex::encode(namehash("eth")),
+        "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+    );
+    assert_eq!(
+        hex::encode(namehash("foo.eth")),
+        "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+    );
+    assert_eq!(
+        hex::encode(namehash("vitalik.eth")),
+        "ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"
+    );
+    assert_eq!(
+        hex::encode(labelhash("eth")),
+        "4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0"
+    );
+    assert_eq!(
+        namehash("foo.[4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0]"),
+        namehash("foo.eth")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalized() {
+    use keccak_asm::ens::{namehash_normalized, AsciiNormalizer, AsciiNormalizerError};
+
+    assert_eq!(namehash_normalized("Foo.ETH", &AsciiNormalizer), Ok(namehash("foo.eth")));
+    assert_eq!(
+        namehash_normalized("foo..eth", &AsciiNormalizer),
+        Err(AsciiNormalizerError::EmptyLabel)
+    );
+    assert_eq!(
+        namehash_normalized("föo.eth", &AsciiNormalizer),
+        Err(AsciiNormalizerError::NonAscii)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dns() {
+    use keccak_asm::ens::{dns_encode, EmptyLabelError};
+
+    assert_eq!(dns_encode("").unwrap(), [0]);
+    assert_eq!(dns_encode("foo.eth").unwrap(), b"\x03foo\x03eth\x00");
+    assert_eq!(dns_encode("foo..eth"), Err(EmptyLabelError));
+
+    let long = "a".repeat(256);
+    let encoded = dns_encode(&format!("{long}.eth")).unwrap();
+    assert_eq!(encoded.len(), 1 + 66 + 1 + 3 + 1);
+    let hashed = core::str::from_utf8(&encoded[1..67]).unwrap();
+    assert_eq!(namehash(&format!("{hashed}.eth")), namehash(&format!("{long}.eth")));
+}
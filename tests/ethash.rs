@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::ethash::{cache_size, dataset_size, epoch, seed_hash, Cache};
+
+#[test]
+fn sizes() {
+    assert_eq!(epoch(29_999), 0);
+    assert_eq!(epoch(30_000), 1);
+    assert_eq!(cache_size(0), 16_776_896);
+    assert_eq!(cache_size(1), 16_907_456);
+    assert_eq!(dataset_size(0), 1_073_739_904);
+    assert_eq!(dataset_size(1), 1_082_130_304);
+    assert_eq!(seed_hash(0), [0; 32]);
+    assert_eq!(
+        hex::encode(seed_hash(1)),
+        "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563"
+    );
+}
+
+// https://github.com/ethereum/go-ethereum/blob/v1.13.15/consensus/ethash/algorithm_test.go
+#[test]
+fn hashimoto() {
+    let cache = Cache::with_parameters(1024, 32 * 1024, &[0; 32]);
+    assert_eq!(cache.size(), 1024);
+    assert_eq!(cache.to_bytes().len(), 1024);
+
+    let hash =
+        hex::decode_to_array("c9149cc0386e689d789a1c2f3d5d169a61a6218ed30e74414dc736e442ef3d1f")
+            .unwrap();
+    let mix_digest =
+        hex::decode_to_array("e4073cffaef931d37117cefd9afd27ea0f1cad6a981dd2605c4a1ac97c519800")
+            .unwrap();
+    let output = cache.hashimoto_light(&hash, 0);
+    assert_eq!(output.mix_digest, mix_digest);
+    assert_eq!(
+        hex::encode(output.result),
+        "d3539235ee2e6f8db665c0a72169f55b7f6c605712330b778ec3944f0eb5a557"
+    );
+
+    let difficulty = |d: u8| {
+        let mut difficulty = [0; 32];
+        difficulty[31] = d;
+        difficulty
+    };
+    // The result is above `2^256 / 2`.
+    assert!(cache.verify(&hash, 0, &mix_digest, &difficulty(1)));
+    assert!(!cache.verify(&hash, 0, &mix_digest, &difficulty(2)));
+    assert!(!cache.verify(&hash, 0, &mix_digest, &difficulty(0)));
+    assert!(!cache.verify(&hash, 1, &mix_digest, &difficulty(1)));
+    assert!(!cache.verify(&hash, 0, &[0; 32], &difficulty(1)));
+}
@@ -0,0 +1,89 @@
+//! HMAC-SHA3 with the inputs of the test cases of RFC 4231, except the truncated case 5.
+
+use hmac::{Hmac, Mac, SimpleHmac};
+use keccak_asm::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+const KEY_4: [u8; 25] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+];
+const LONG_DATA: &[u8] = b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.";
+
+const CASES: [(&[u8], &[u8]); 6] = [
+    (&[0x0b; 20], b"Hi There"),
+    (b"Jefe", b"what do ya want for nothing?"),
+    (&[0xaa; 20], &[0xdd; 50]),
+    (&KEY_4, &[0xcd; 50]),
+    (&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First"),
+    (&[0xaa; 131], LONG_DATA),
+];
+
+macro_rules! hmac_test {
+    ($name:ident, $hash:ty, [$($expected:literal),* $(,)?]) => {
+        #[test]
+        fn $name() {
+            let expected = [$($expected),*];
+            for (i, ((key, data), expected)) in CASES.iter().zip(expected).enumerate() {
+                let mut mac = Hmac::<$hash>::new_from_slice(key).unwrap();
+                mac.update(data);
+                assert_eq!(hex::encode(mac.finalize().into_bytes()), expected, "case {}", i + 1);
+
+                let mut mac = SimpleHmac::<$hash>::new_from_slice(key).unwrap();
+                mac.update(data);
+                assert_eq!(hex::encode(mac.finalize().into_bytes()), expected, "case {}", i + 1);
+            }
+        }
+    };
+}
+
+hmac_test!(
+    hmac_sha3_224,
+    Sha3_224,
+    [
+        "3b16546bbc7be2706a031dcafd56373d9884367641d8c59af3c860f7",
+        "7fdb8dd88bd2f60d1b798634ad386811c2cfc85bfaf5d52bbace5e66",
+        "676cfc7d16153638780390692be142d2df7ce924b909c0c08dbfdc1a",
+        "a9d7685a19c4e0dbd9df2556cc8a7d2a7733b67625ce594c78270eeb",
+        "b4a1f04c00287a9b7f6075b313d279b833bc8f75124352d05fb9995f",
+        "05d8cd6d00faea8d1eb68ade28730bbd3cbab6929f0a086b29cd62a0",
+    ]
+);
+
+hmac_test!(
+    hmac_sha3_256,
+    Sha3_256,
+    [
+        "ba85192310dffa96e2a3a40e69774351140bb7185e1202cdcc917589f95e16bb",
+        "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5",
+        "84ec79124a27107865cedd8bd82da9965e5ed8c37b0ac98005a7f39ed58a4207",
+        "57366a45e2305321a4bc5aa5fe2ef8a921f6af8273d7fe7be6cfedb3f0aea6d7",
+        "ed73a374b96c005235f948032f09674a58c0ce555cfc1f223b02356560312c3b",
+        "65c5b06d4c3de32a7aef8763261e49adb6e2293ec8e7c61e8de61701fc63e123",
+    ]
+);
+
+hmac_test!(
+    hmac_sha3_384,
+    Sha3_384,
+    [
+        "68d2dcf7fd4ddd0a2240c8a437305f61fb7334cfb5d0226e1bc27dc10a2e723a20d370b47743130e26ac7e3d532886bd",
+        "f1101f8cbf9766fd6764d2ed61903f21ca9b18f57cf3e1a23ca13508a93243ce48c045dc007f26a21b3f5e0e9df4c20a",
+        "275cd0e661bb8b151c64d288f1f782fb91a8abd56858d72babb2d476f0458373b41b6ab5bf174bec422e53fc3135ac6e",
+        "3a5d7a879702c086bc96d1dd8aa15d9c46446b95521311c606fdc4e308f4b984da2d0f9449b3ba8425ec7fb8c31bc136",
+        "0fc19513bf6bd878037016706a0e57bc528139836b9a42c3d419e498e0e1fb9616fd669138d33a1105e07c72b6953bcc",
+        "026fdf6b50741e373899c9f7d5406d4eb09fc6665636fc1a530029ddf5cf3ca5a900edce01f5f61e2f408cdf2fd3e7e8",
+    ]
+);
+
+hmac_test!(
+    hmac_sha3_512,
+    Sha3_512,
+    [
+        "eb3fbd4b2eaab8f5c504bd3a41465aacec15770a7cabac531e482f860b5ec7ba47ccb2c6f2afce8f88d22b6dc61380f23a668fd3888bb80537c0a0b86407689e",
+        "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024",
+        "309e99f9ec075ec6c6d475eda1180687fcf1531195802a99b5677449a8625182851cb332afb6a89c411325fbcbcd42afcb7b6e5aab7ea42c660f97fd8584bf03",
+        "b27eab1d6e8d87461c29f7f5739dd58e98aa35f8e823ad38c5492a2088fa0281993bbfff9a0e9c6bf121ae9ec9bb09d84a5ebac817182ea974673fb133ca0d1d",
+        "00f751a9e50695b090ed6911a4b65524951cdc15a73a5d58bb55215ea2cd839ac79d2b44a39bafab27e83fde9e11f6340b11d991b1b91bf2eee7fc872426c3a4",
+        "38a456a004bd10d32c9ab8336684112862c3db61adcca31829355eaf46fd5c73d06a1f0d13fec9a652fb3811b577b1b1d1b9789f97ae5b83c6f44dfcf1d67eba",
+    ]
+);
@@ -0,0 +1,124 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::{
+    incremental_merkle::{IncrementalMerkleTree, InvalidStateError, TreeFullError},
+    Digest, Keccak256,
+};
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Computes all the levels of the full tree, from the leaves up to the root.
+fn levels<const DEPTH: usize>(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+    let mut level = leaves.to_vec();
+    level.resize(1 << DEPTH, [0; 32]);
+    let mut levels = vec![level];
+    for _ in 0..DEPTH {
+        let next = levels.last().unwrap().chunks(2).map(|p| hash_node(&p[0], &p[1])).collect();
+        levels.push(next);
+    }
+    levels
+}
+
+#[test]
+fn empty() {
+    let tree = IncrementalMerkleTree::<32>::new();
+    assert!(tree.is_empty());
+    assert_eq!(
+        hex::encode(tree.root()),
+        "27ae5ba08d7291c96c8cbddcc148bf48a6d68c7974b94356f53754ef6171d757"
+    );
+    assert_eq!(IncrementalMerkleTree::<32>::CAPACITY, u32::MAX as u64);
+}
+
+#[test]
+fn append() {
+    let mut tree = IncrementalMerkleTree::<32>::new();
+    assert_eq!(tree.append(keccak(&[0])), Ok(0));
+    assert_eq!(
+        hex::encode(tree.root()),
+        "f7f0b188036bdde17ab27acea1058c017ea86d9ec2a8de6771a281137b038c6a"
+    );
+    for i in 1..5u8 {
+        assert_eq!(tree.append(keccak(&[i])), Ok(i as u64));
+    }
+    assert_eq!(tree.len(), 5);
+    assert_eq!(
+        hex::encode(tree.root()),
+        "e453646966331bbdd28e9a188d54fe049579a54a29802549f8bcf4eb8e7eab8c"
+    );
+}
+
+#[test]
+fn proofs() {
+    const DEPTH: usize = 4;
+    let leaves: Vec<_> = (0..15u8).map(|i| keccak(&[i])).collect();
+    let mut tree = IncrementalMerkleTree::<DEPTH>::new();
+    for (count, leaf) in leaves.iter().enumerate() {
+        if count % 3 == 1 {
+            tree.append(*leaf).unwrap();
+        } else {
+            tree.append_retained(*leaf).unwrap();
+        }
+
+        let levels = levels::<DEPTH>(&leaves[..=count]);
+        let root = levels[DEPTH][0];
+        assert_eq!(tree.root(), root, "{count}");
+        for index in tree.retained() {
+            let proof = tree.proof(index).unwrap();
+            for (h, node) in proof.iter().enumerate() {
+                assert_eq!(*node, levels[h][(index as usize >> h) ^ 1], "{count} {index} {h}");
+            }
+            assert!(IncrementalMerkleTree::verify(&root, &leaves[index as usize], index, &proof));
+            assert!(!IncrementalMerkleTree::verify(
+                &root,
+                &leaves[index as usize],
+                index + 1,
+                &proof
+            ));
+        }
+    }
+
+    assert_eq!(tree.append([0; 32]), Err(TreeFullError));
+    assert!(tree.proof(1).is_none());
+    assert!(tree.forget(3));
+    assert!(!tree.forget(3));
+    assert!(tree.proof(3).is_none());
+    assert_eq!(tree.retained().count(), 9);
+}
+
+#[test]
+fn serialization() {
+    let mut tree = IncrementalMerkleTree::<32>::new();
+    for i in 0..100u8 {
+        if i % 7 == 0 {
+            tree.append_retained(keccak(&[i])).unwrap();
+        } else {
+            tree.append(keccak(&[i])).unwrap();
+        }
+    }
+
+    let bytes = tree.to_bytes();
+    let mut decoded = IncrementalMerkleTree::<32>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, tree);
+
+    // The restored tree keeps updating the retained paths.
+    tree.append([1; 32]).unwrap();
+    decoded.append([1; 32]).unwrap();
+    assert_eq!(decoded.root(), tree.root());
+    assert_eq!(decoded.proof(98), tree.proof(98));
+
+    assert_eq!(
+        IncrementalMerkleTree::<32>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(InvalidStateError)
+    );
+    assert_eq!(IncrementalMerkleTree::<16>::from_bytes(&bytes), Err(InvalidStateError));
+}
@@ -0,0 +1,257 @@
+use keccak_asm::{
+    kdf::{
+        hkdf_sha3_256, kdf_kmac128, kdf_kmac256, one_step_kmac128, one_step_kmac256,
+        one_step_sha3_256, one_step_sha3_512, pbkdf2_sha3_256, FixedInfo, InvalidLength,
+    },
+    Digest, Keccak256,
+};
+
+#[track_caller]
+fn hkdf(ikm: &[u8], salt: &[u8], info: &[u8], len: usize) -> String {
+    let mut out = vec![0; len];
+    hkdf_sha3_256(ikm, salt, info, &mut out).unwrap();
+    hex::encode(out)
+}
+
+#[track_caller]
+fn pbkdf2(password: &[u8], salt: &[u8], rounds: u32, len: usize) -> String {
+    let mut out = vec![0; len];
+    pbkdf2_sha3_256(password, salt, rounds, &mut out);
+    hex::encode(out)
+}
+
+// The inputs of the test cases of RFC 5869.
+#[test]
+fn hkdf_vectors() {
+    let salt: Vec<u8> = (0x00..=0x0c).collect();
+    let info: Vec<u8> = (0xf0..=0xf9).collect();
+    assert_eq!(
+        hkdf(&[0x0b; 22], &salt, &info, 42),
+        "0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed72674900557728f2c9f2c4c179"
+    );
+
+    let ikm: Vec<u8> = (0x00..=0x4f).collect();
+    let salt: Vec<u8> = (0x60..=0xaf).collect();
+    let info: Vec<u8> = (0xb0..=0xff).collect();
+    assert_eq!(
+        hkdf(&ikm, &salt, &info, 82),
+        "3dc251e66c75da6560405ec5ac10e17d851eedfbfdc13feafbec16964c25d021bd971465a3e9c615f27769019e3f0407d84986fb0ba24e729c99834624baa21cb623dc0098f430d52e18bbdf694df4edd8b2"
+    );
+
+    assert_eq!(
+        hkdf(&[0x0b; 22], &[], &[], 42),
+        "bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853c1c1bed63d725e885e78"
+    );
+    assert_eq!(hkdf(&[0x0b; 22], &[0; 32], &[], 42), hkdf(&[0x0b; 22], &[], &[], 42));
+}
+
+#[test]
+fn hkdf_max_length() {
+    let mut out = vec![0; 255 * 32];
+    hkdf_sha3_256(&[0x0b; 22], &[], &[], &mut out).unwrap();
+    assert_eq!(
+        hex::encode(&out[255 * 32 - 32..]),
+        "47c4421c71204225ba9962541151b55232f06ef7a4061a1a9582981aafcf7563"
+    );
+
+    let mut out = vec![0; 255 * 32 + 1];
+    assert_eq!(hkdf_sha3_256(&[0x0b; 22], &[], &[], &mut out), Err(InvalidLength));
+}
+
+// The inputs of the test vectors of RFC 6070.
+#[test]
+fn pbkdf2_vectors() {
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 1, 32),
+        "94613f3ee2ea730e0b06754f3fc816d4f87c9be9cbd8556b5d59b52330e333a8"
+    );
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 2, 32),
+        "4c915baedd1773383e77fcfe38114ca7514010adec24b47290ec170208423f76"
+    );
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 4096, 32),
+        "778b6e237a0f49621549ff70d218d2080756b9fb38d71b5d7ef447fa2254af61"
+    );
+    assert_eq!(
+        pbkdf2(b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096, 40),
+        "7aef8f1ad8c7f12205334f624d4af9e2863121618f7a0b3209bef3934801c39feac24ef0ac6a5c25"
+    );
+    assert_eq!(pbkdf2(b"pass\0word", b"sa\0lt", 4096, 16), "98e5503130ffdd69603da78cbb12e9be");
+}
+
+#[test]
+fn pbkdf2_long_password() {
+    // Passwords longer than a block are hashed first.
+    assert_eq!(
+        pbkdf2(&[b'a'; 200], b"salt", 3, 70),
+        "ba75417e5934cd59823cd69f4f7b977f88b5a9fc57475599ffe2e11905e04589dcc9124d0cf2857266d510ea15614a0d0716d2b1f5963701f6fb362cf0c010c288dce1175bf7"
+    );
+}
+
+#[test]
+#[should_panic = "rounds must be non-zero"]
+fn pbkdf2_zero_rounds() {
+    pbkdf2_sha3_256(b"password", b"salt", 0, &mut [0; 32]);
+}
+
+const Z: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aabbccddeeff00";
+const KEY_DATA_LEN: [u8; 4] = 256u32.to_be_bytes();
+
+fn fixed_info() -> FixedInfo<'static> {
+    FixedInfo::new()
+        .algorithm_id(b"AES-256-GCM")
+        .party_u_info(b"Alice")
+        .party_v_info(b"Bob")
+        .supp_pub_info(&KEY_DATA_LEN)
+}
+
+#[test]
+fn fixed_info_encoding() {
+    let info = fixed_info();
+    let mut hasher = Keccak256::new();
+    info.encode(&mut hasher);
+    let expected =
+        hex::decode("0000000b4145532d3235362d47434d00000005416c69636500000003426f6200000100")
+            .unwrap();
+    assert_eq!(info.len(), expected.len());
+    assert_eq!(hasher.finalize(), Keccak256::digest(&expected));
+    assert!(FixedInfo::new().is_empty());
+    assert_eq!(FixedInfo::new().algorithm_id(b"").len(), 4);
+}
+
+/// A one-step KDF test case.
+///
+/// The expected outputs match the `SSKDF` of OpenSSL 3.5 with the same digest or MAC.
+struct OneStepVector {
+    z: &'static str,
+    salt: Option<&'static [u8]>,
+    fixed_info: FixedInfo<'static>,
+    dkm: &'static str,
+}
+
+#[track_caller]
+fn check_one_step(
+    vectors: &[OneStepVector],
+    kdf: impl Fn(&[u8], Option<&[u8]>, &FixedInfo<'_>, &mut [u8]),
+) {
+    for (count, v) in vectors.iter().enumerate() {
+        let mut dkm = vec![0; v.dkm.len() / 2];
+        kdf(&hex::decode(v.z).unwrap(), v.salt, &v.fixed_info, &mut dkm);
+        assert_eq!(hex::encode(dkm), v.dkm, "COUNT = {count}");
+    }
+}
+
+#[test]
+fn one_step_sha3() {
+    let vectors = [
+        OneStepVector {
+            z: Z,
+            salt: None,
+            fixed_info: fixed_info(),
+            dkm: "8857044bf615564b0d44f585d01e89706bfab678cfb2805f59daeacfa34dc485",
+        },
+        OneStepVector {
+            z: Z,
+            salt: None,
+            fixed_info: fixed_info(),
+            dkm: "8857044bf615564b0d44f585d01e89706bfab678cfb2805f59daeacfa34dc485ec9b70985cc708a72e3d6717a40ae2221a438812786422febdf6d3fb3358e313cfdfeecc75fcdab628bc37621ff9e4f3",
+        },
+    ];
+    check_one_step(&vectors, |z, _, info, out| one_step_sha3_256(z, info, out).unwrap());
+
+    let vectors = [
+        OneStepVector {
+            z: Z,
+            salt: None,
+            fixed_info: fixed_info(),
+            dkm: "5b0b2278cc9a89f21838a8b572eb8f84e2b2f1e1ce3a4f41eef5480a1b91b6f68061bf06970d04e315b6d0c8581f09fe1c93e8d93feb9f6748516f2b80ac67ac39a96c966a7d7c89a99db4a52705fa7507909abf478491958679afb2017f68d2dfd47667",
+        },
+        OneStepVector {
+            z: "111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111",
+            salt: None,
+            fixed_info: FixedInfo::new().supp_pub_info(b"raw fixed info"),
+            dkm: "4ec1ff51ee0c9d2011ee491ec67db8e26be2ecd6330a3ee2adf3abd41542402a1de1e6850f7dac147bf3f747e86edca195b50a9e541b9bd4404a985d0feaca3f",
+        },
+    ];
+    check_one_step(&vectors, |z, _, info, out| one_step_sha3_512(z, info, out).unwrap());
+}
+
+#[test]
+fn one_step_kmac() {
+    let vectors = [OneStepVector {
+        z: Z,
+        salt: None,
+        fixed_info: fixed_info(),
+        dkm: "9aadc10a2cf99bc0e0bd6a3820e7b4c74a40ab7c08c6d725f87c3be213c7fc79",
+    }];
+    check_one_step(&vectors, one_step_kmac128);
+
+    let vectors = [
+        OneStepVector {
+            z: Z,
+            salt: Some(b"salt value"),
+            fixed_info: fixed_info(),
+            dkm: "be343a02f0184b754087b0fe16d24f96dcfa457798e19f50dd8baa0d222c85c7463e711cb0712c4c596b8aa5659f43fea3bc96e014915ba1c13f65c8e02c13d0",
+        },
+        OneStepVector {
+            z: Z,
+            salt: None,
+            fixed_info: fixed_info(),
+            dkm: "687f73a2defa78885c22e8d79814ba6b1d8909024cab3c8eca99c7e1c083faa09b3c31bc7aace26eecbca9ae43eed881",
+        },
+        OneStepVector {
+            z: Z,
+            salt: Some(&[0; 132]),
+            fixed_info: fixed_info(),
+            dkm: "687f73a2defa78885c22e8d79814ba6b1d8909024cab3c8eca99c7e1c083faa09b3c31bc7aace26eecbca9ae43eed881",
+        },
+    ];
+    check_one_step(&vectors, one_step_kmac256);
+}
+
+// The KMAC samples of SP 800-185, as `KMAC(key, context, L, label)`.
+#[test]
+fn kmac_kdf_sp800_185_samples() {
+    let key: Vec<u8> = (0x40..=0x5f).collect();
+    let mut out = [0; 32];
+    kdf_kmac128(&key, b"", &[0, 1, 2, 3], &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"
+    );
+    kdf_kmac128(&key, b"My Tagged Application", &[0, 1, 2, 3], &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"
+    );
+
+    let mut out = [0; 64];
+    kdf_kmac256(&key, b"My Tagged Application", &[0, 1, 2, 3], &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+    );
+}
+
+// The expected outputs match the `KBKDF` of OpenSSL 3.5 with KMAC, where the label is the `salt`
+// parameter and the context the `info` parameter.
+#[test]
+fn kmac_kdf() {
+    let key: Vec<u8> = (0x40..=0x5f).collect();
+    let mut out = [0; 32];
+    kdf_kmac128(&key, b"label", b"context", &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "74be949c12da9f64857ade5ce20504095dbb0c19dcb2bd25034de04f046b35d9"
+    );
+
+    // Multiple output blocks, and a context longer than a block.
+    let context: Vec<u8> = (0..200).collect();
+    let mut out = [0; 300];
+    kdf_kmac256(&key, b"encryption key", &context, &mut out);
+    assert_eq!(
+        hex::encode(out),
+        "7fc46c521ffa2911bbbed4c4527a61b440e39189486fe2d68f3134e1c7b8dc995aea1382889822bb68fc8d819998c2835878cefbacd12e927719a1d9a45160d93d4206e5bf001be66abd514f520baf7945ef38185eb7c2d7b439af443efe3cfebe6922f1acf9ba30307a75f124c34b1164900e1f94fc67cdcf027e2f5865cd4b95006f73f47bd071f9f1e49bec8b09ce35d795c0a90cd59f078c93f69613b17524baf8302ad35146c3b8ecff9f308180540907a9db9a7439dc838931288f78df05c4700fc803e1d93b824048b5d71e4ef1f59666df5fc3258c38400c64cc71fd6dd62a3d92fa7a282bf1a16fb4c692595b86a228496b3278318ecc757c59e8bfa99b966aeb3eb9601e6ebdbf31ccdcb99e90b554b99cc54c9dc2150b28466b52f7b43c1a7c1541a427596405"
+    );
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "aead")]
+
+// The vectors were computed with an independent model of Lake Keyak. They have not been checked
+// against XKCP yet, and must be replaced by its vectors when they are.
+
+mod common;
+
+use aead::{AeadInPlace, KeyInit};
+use common::{bytes, sequence};
+use keccak_asm::{
+    duplex::Duplex,
+    keyak::{LakeKeyak, LakeKeyakSession},
+};
+
+const KEY: [u8; 16] = sequence(0);
+const NONCE: [u8; 16] = sequence(0x10);
+
+#[test]
+fn full_rounds_match_assembly() {
+    let mut duplex = Duplex::<168>::new();
+    duplex.state_mut().iter_mut().enumerate().for_each(|(i, lane)| *lane = i as u64);
+    let mut lanes = *duplex.state();
+    duplex.permute();
+    sha3_asm::keccak_p1600(&mut lanes, 24);
+    assert_eq!(&lanes, duplex.state());
+}
+
+#[test]
+fn aead() {
+    let vectors: &[(Vec<u8>, Vec<u8>, &str, &str)] = &[
+        (vec![], vec![], "", "660ea49a50807fa3dea5475cc69e7a88"),
+        (vec![], b"ad".to_vec(), "", "9c0a3f2b21e9e9ec980187b3bcbc7974"),
+        (b"hello world".to_vec(), vec![], "f9380e5720df09132eddb7", "9b5d4e5c0092472bf46870e4dd7fc687"),
+        (
+            bytes(200),
+            bytes(250),
+            "915c60384bfa787b54b8d9e654a0d37350714df2c9139835c9368cd9180200662d1035eebe80e4569c6087d84da694b118d5cd6bad4a8c184c63f08035dc0433b38b6925b9b68d41923cf56ee3815afcc0baad8d3aada19d42305d413856fd356575a8be99e168ef3fdf9250b13663c6a9795e53b1053422cec4c9d7988c2cbe30c4904ed336dcd1057e03a16f5e2542fdf178d27f531b41f2a218a4c7428f99bebe334ba1d3327cbdabff52ebed2ba7a29c163f7571bf0301b661e6297f89ed785d228e875220a0",
+            "4130a9adede2ed359f64ad4fa443fac1",
+        ),
+        (
+            bytes(400),
+            vec![b'x'; 10],
+            "915c60384bfa787b54b8d9e654a0d37350714df2c9139835c9368cd9180200662d1035eebe80e4569c6087d84da694b118d5cd6bad4a8c184c63f08035dc0433b38b6925b9b68d41923cf56ee3815afcc0baad8d3aada19d42305d413856fd356575a8be99e168ef3fdf9250b13663c6a9795e53b1053422cec4c9d7988c2cbe30c4904ed336dcd1057e03a16f5e2542fdf178d27f531b41f2a218a4c7428f99bebe334ba1d3327c5d97823853389e2d72109305d3af8404c1186327c2fb7e08499d5ee59857134fb999264e71b341d8bd2118be1147870d53f025f469ec7862fdaf36f27454f35baee0bb2f79651ea281d6bfdac7a7f037e5bf7666d3ca2f20686598db59bd61fa4ed2547b8933c263a5159c8e3d1c2ab146e23f4ff710252bf3369b72ef0cf8a1d2bea81dd89631a2a9c6b372b3e32b694411bd5e81d9816b863b2c347cd2b328b9b561d050cdeff05ad998b74743adb8fc876da99a3172f5ba75e3e250ac2838cbb9d14377b4cb744e32b2f5dd5b328c3b8f4fa831fb9dea9ee67bfef5317c1826fcddc8d4786d57",
+            "b9c99f67c18a7f1a2e5e238d0ef67b70",
+        ),
+    ];
+
+    let cipher = LakeKeyak::new(&KEY.into());
+    for (plaintext, ad, ciphertext, tag) in vectors {
+        let mut buffer = plaintext.clone();
+        let t = cipher.encrypt_in_place_detached(&NONCE.into(), ad, &mut buffer).unwrap();
+        assert_eq!(hex::encode(&buffer), *ciphertext);
+        assert_eq!(hex::encode(t), *tag);
+
+        cipher.decrypt_in_place_detached(&NONCE.into(), ad, &mut buffer, &t).unwrap();
+        assert_eq!(buffer, *plaintext);
+
+        let mut bad = t;
+        bad[15] ^= 1;
+        let mut buffer = hex::decode(ciphertext).unwrap();
+        assert!(cipher.decrypt_in_place_detached(&NONCE.into(), ad, &mut buffer, &bad).is_err());
+        assert!(buffer.iter().all(|&b| b == 0));
+    }
+}
+
+struct Message {
+    plaintext: &'static [u8],
+    ad: &'static [u8],
+    forget: bool,
+    ciphertext: &'static str,
+    tag: &'static str,
+}
+
+#[test]
+fn session() {
+    let messages = [
+        Message {
+            plaintext: b"first",
+            ad: b"a1",
+            forget: false,
+            ciphertext: "78e44ff897",
+            tag: "cc0417fc6d862ea17d080b8d372d3fa1",
+        },
+        Message {
+            plaintext: b"second message",
+            ad: b"",
+            forget: true,
+            ciphertext: "bf61749303e20ecc187b78ec5048",
+            tag: "c6ca28965a82518899be75134a04b817",
+        },
+        Message {
+            plaintext: b"",
+            ad: b"only ad",
+            forget: false,
+            ciphertext: "",
+            tag: "9750af453f2961d83ad2fb8acd8d760b",
+        },
+    ];
+
+    let mut sender = LakeKeyakSession::new(&KEY, &NONCE, true);
+    let mut receiver = LakeKeyakSession::new(&KEY, &NONCE, true);
+    for Message { plaintext, ad, forget, ciphertext, tag } in messages {
+        let mut buffer = plaintext.to_vec();
+        let t = sender.wrap(&mut buffer, ad, forget).unwrap();
+        assert_eq!(hex::encode(&buffer), ciphertext);
+        assert_eq!(hex::encode(t), tag);
+        receiver.unwrap(&mut buffer, ad, &t, forget).unwrap();
+        assert_eq!(buffer, plaintext);
+    }
+
+    // A failure ends the session.
+    let mut buffer = *b"message";
+    let mut tag = sender.wrap(&mut buffer, &[], false).unwrap();
+    tag[0] ^= 1;
+    assert!(receiver.unwrap(&mut buffer, &[], &tag, false).is_err());
+    tag[0] ^= 1;
+    assert!(receiver.unwrap(&mut buffer, &[], &tag, false).is_err());
+    assert!(receiver.wrap(&mut buffer, &[], false).is_err());
+}
+
+#[test]
+fn session_is_bound_to_history() {
+    let mut a = LakeKeyakSession::new(&KEY, &NONCE, false);
+    let mut b = LakeKeyakSession::new(&KEY, &NONCE, false);
+    let mut first = *b"one";
+    a.wrap(&mut first, &[], false).unwrap();
+    let mut first = *b"uno";
+    b.wrap(&mut first, &[], false).unwrap();
+
+    let mut x = *b"two";
+    let mut y = *b"two";
+    assert_ne!(a.wrap(&mut x, &[], false).unwrap(), b.wrap(&mut y, &[], false).unwrap());
+    assert_ne!(x, y);
+}
+
+#[test]
+#[should_panic = "Keyak nonces must be at most 150 bytes long"]
+fn long_nonce() {
+    LakeKeyakSession::new(&KEY, &[0; 151], false);
+}
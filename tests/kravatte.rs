@@ -0,0 +1,195 @@
+// The vectors were computed with an independent model of Kravatte Achouffe and its modes. They
+// have not been checked against XKCP yet, and must be replaced by its vectors when they are.
+
+mod common;
+
+use common::{bytes, sequence};
+use keccak_asm::{
+    kravatte::{AuthError, Kravatte, KravatteSane, KravatteWbc},
+    Digest, Keccak256,
+};
+
+const KEY: [u8; 32] = sequence(0);
+const NONCE: [u8; 16] = sequence(0x20);
+
+fn expand(deck: &Kravatte, offset: usize, len: usize) -> String {
+    let mut out = vec![0; len];
+    deck.expand(offset, &mut out);
+    hex::encode(out)
+}
+
+#[test]
+fn deck_function() {
+    let mut deck = Kravatte::new(&KEY);
+    assert_eq!(
+        expand(&deck, 0, 32),
+        "1f1e601636fbca5dea4dbd8161701ee69fcb20504c34e6fff5fbcb4484809dae"
+    );
+
+    let mut empty = deck.clone();
+    empty.compress(b"");
+    assert_eq!(
+        expand(&empty, 0, 32),
+        "b4c89dcff02acf03b7489d089d4d1b97dbac4b65c85df3771b1a2c249d5dc44c"
+    );
+
+    deck.compress(&bytes(250));
+    assert_eq!(
+        expand(&deck, 190, 40),
+        "e0d859550b4bfc0d82026aaccd4d68a4a05a654e2702e706b5306b935cdad93fa482c6db5dd89f0c"
+    );
+    deck.compress(b"second");
+    assert_eq!(expand(&deck, 0, 16), "846edef2235423323786117eafa40669");
+}
+
+#[test]
+fn expand_offsets() {
+    let mut deck = Kravatte::new(&KEY);
+    deck.compress(b"offsets");
+    let mut full = [0u8; 700];
+    deck.expand(0, &mut full);
+    for offset in [0, 1, 199, 200, 201, 450] {
+        let mut out = [0u8; 150];
+        deck.expand(offset, &mut out);
+        assert_eq!(out, full[offset..offset + 150], "offset {offset}");
+    }
+}
+
+#[test]
+#[should_panic]
+fn key_too_long() {
+    Kravatte::new(&[0; 200]);
+}
+
+#[test]
+fn sane() {
+    let (mut sender, tag) = KravatteSane::new(&KEY, &NONCE);
+    assert_eq!(hex::encode(tag), "0be7f08d18688cc4243336ceaebeede9");
+    let (mut receiver, _) = KravatteSane::new(&KEY, &NONCE);
+
+    let long = b"p2".repeat(150);
+    let messages: &[(&[u8], &[u8], &str, &str)] = &[
+        (
+            b"ad1",
+            b"plaintext one",
+            "a8d6563d9f89bad91ed97c7d37",
+            "c3b9f3c53c13641fe0e11a05cd3c5670",
+        ),
+        (
+            b"",
+            &long,
+            "4e4654fc3c5078b48b9b582d7b822211e6cf8dd60fc2ee5f9642b62174c13129",
+            "e98dc902a06796c3c137a94819abeb2d",
+        ),
+        (b"only ad", b"", "", "648555ab7752b726aa1a0f8ee333d46d"),
+        (b"", b"", "", "a759616bafb86c35fe6d0c4a20ec6cf5"),
+    ];
+    for &(ad, plaintext, ciphertext, expected_tag) in messages {
+        let mut buffer = plaintext.to_vec();
+        let tag = sender.wrap(&mut buffer, ad).unwrap();
+        if buffer.len() > 32 {
+            assert_eq!(hex::encode(Keccak256::digest(&buffer)), ciphertext);
+        } else {
+            assert_eq!(hex::encode(&buffer), ciphertext);
+        }
+        assert_eq!(hex::encode(tag), expected_tag);
+
+        receiver.unwrap(&mut buffer, ad, &tag).unwrap();
+        assert_eq!(buffer, plaintext);
+    }
+}
+
+#[test]
+fn sane_tampering() {
+    let (mut sender, _) = KravatteSane::new(&KEY, &NONCE);
+    let mut buffer = b"attack at dawn".to_vec();
+    let tag = sender.wrap(&mut buffer, b"header").unwrap();
+
+    let (mut receiver, _) = KravatteSane::new(&KEY, &NONCE);
+    let mut tampered = buffer.clone();
+    tampered[0] ^= 1;
+    assert_eq!(receiver.unwrap(&mut tampered, b"header", &tag), Err(AuthError));
+    assert!(tampered.iter().all(|&b| b == 0));
+
+    let (mut receiver, _) = KravatteSane::new(&KEY, &NONCE);
+    assert_eq!(receiver.clone().unwrap(&mut buffer.clone(), b"other", &tag), Err(AuthError));
+    let mut bad_tag = tag;
+    bad_tag[15] ^= 0x80;
+    assert_eq!(receiver.clone().unwrap(&mut buffer.clone(), b"header", &bad_tag), Err(AuthError));
+
+    receiver.unwrap(&mut buffer, b"header", &tag).unwrap();
+    assert_eq!(buffer, b"attack at dawn");
+}
+
+#[test]
+fn sane_failure_ends_session() {
+    let (mut sender, _) = KravatteSane::new(&KEY, &NONCE);
+    let (mut receiver, _) = KravatteSane::new(&KEY, &NONCE);
+    let mut first = b"first".to_vec();
+    let first_tag = sender.wrap(&mut first, b"").unwrap();
+    let mut second = b"second".to_vec();
+    let second_tag = sender.wrap(&mut second, b"").unwrap();
+
+    let mut bad_tag = first_tag;
+    bad_tag[0] ^= 1;
+    assert_eq!(receiver.unwrap(&mut first.clone(), b"", &bad_tag), Err(AuthError));
+    // Even valid messages are rejected after a failure.
+    assert_eq!(receiver.unwrap(&mut first, b"", &first_tag), Err(AuthError));
+    assert!(first.iter().all(|&b| b == 0));
+    assert_eq!(receiver.unwrap(&mut second, b"", &second_tag), Err(AuthError));
+    assert_eq!(receiver.wrap(&mut b"reply".to_vec(), b""), Err(AuthError));
+}
+
+#[test]
+fn wbc() {
+    let wbc = KravatteWbc::new(&KEY);
+    let short: &[(usize, &str)] = &[
+        (1, "86"),
+        (2, "20a9"),
+        (33, "500e973ad901db0b8d7e4e96d364ccf57e843f7c38f01f64666924378745f8b74d"),
+    ];
+    for &(len, expected) in short {
+        let mut data = bytes(len);
+        wbc.encipher(b"tweak", &mut data);
+        assert_eq!(hex::encode(&data), expected, "length {len}");
+        wbc.decipher(b"tweak", &mut data);
+        assert_eq!(data, bytes(len));
+    }
+
+    let long: &[(usize, &str)] = &[
+        (398, "3380505f388deabd7eba0235ac1dc57f47ffece7cbbe3abb23170601564545bb"),
+        (399, "4de7f29069374d77556ad067f975628f3b55d231eb96477a9bfc17ad0455ad08"),
+        (500, "86c4f08aad3e1d353fde49c367734fd49020cff97004c79ab0ba54d8ec87a267"),
+        (1000, "b7fecd705c98489aea9e84fb11913caa3df4f6f9aef49e9c7d9a9ced3095a66f"),
+    ];
+    for &(len, expected) in long {
+        let mut data = bytes(len);
+        wbc.encipher(b"tweak", &mut data);
+        assert_eq!(hex::encode(Keccak256::digest(&data)), expected, "length {len}");
+        wbc.decipher(b"tweak", &mut data);
+        assert_eq!(data, bytes(len));
+    }
+
+    let mut data = [0u8; 100];
+    wbc.encipher(b"", &mut data);
+    assert_eq!(
+        hex::encode(Keccak256::digest(data)),
+        "5fc457ab591937be40e8e65b77950f3549e83a22554ed77773924b0557c721eb"
+    );
+}
+
+#[test]
+fn wbc_roundtrip() {
+    let wbc = KravatteWbc::new(b"another key");
+    for len in [0, 3, 199, 200, 397, 401, 797, 798, 799, 2000] {
+        let mut data = bytes(len);
+        wbc.encipher(b"t", &mut data);
+        let mut other = bytes(len);
+        wbc.encipher(b"u", &mut other);
+        if len > 0 {
+            assert_ne!(data, other, "length {len}");
+        }
+        wbc.decipher(b"t", &mut data);
+        assert_eq!(data, bytes(len), "length {len}");
+    }
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::{
+    merkle::{
+        process_multi_proof, verify, InvalidMultiProof, MerkleTree, MerkleTreeError, MultiProof,
+    },
+    Digest, Keccak256,
+};
+
+fn h(s: &str) -> [u8; 32] {
+    hex::decode_to_array(s).unwrap()
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn five() -> MerkleTree {
+    let leaves: Vec<_> = (0..5u8).map(|i| keccak(&[i])).collect();
+    MerkleTree::sorted(&leaves).unwrap()
+}
+
+// https://github.com/OpenZeppelin/merkle-tree#building-a-tree
+#[test]
+fn standard() {
+    let value = |address: u8, amount: u64| {
+        let mut encoded = [0u8; 64];
+        encoded[12..32].fill(address);
+        encoded[56..].copy_from_slice(&amount.to_be_bytes());
+        encoded
+    };
+    let values = [value(0x11, 5_000_000_000_000_000_000), value(0x22, 2_500_000_000_000_000_000)];
+    let tree = MerkleTree::standard(&values).unwrap();
+    let root = h("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77");
+    assert_eq!(tree.root(), root);
+    assert_eq!(tree.num_leaves(), 2);
+
+    let leaf = keccak_asm::merkle::hash_leaf(&values[0]);
+    let index = tree.position(&leaf).unwrap();
+    let proof = tree.proof(index).unwrap();
+    assert!(verify(&root, &leaf, &proof));
+    assert!(!verify(&root, &leaf, &[]));
+}
+
+#[test]
+fn proof() {
+    let tree = five();
+    assert_eq!(tree.root(), h("806ebfdd294fe1b9c4cf959ffb742f7122806379a001ba00e067ea1ac90d259b"));
+    assert_eq!(
+        tree.proof(3).unwrap(),
+        [
+            h("bc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a"),
+            h("460705b2c816a244a5bbc3d1e82564c20ef556b8c7677c4c6160ec8cea0d3deb"),
+        ]
+    );
+    for (i, leaf) in tree.leaves().enumerate() {
+        assert!(verify(&tree.root(), leaf, &tree.proof(i).unwrap()));
+    }
+    assert_eq!(tree.proof(5), Err(MerkleTreeError::IndexOutOfBounds(5)));
+    assert_eq!(MerkleTree::new(&[]), Err(MerkleTreeError::Empty));
+
+    let single = MerkleTree::new(&[[1; 32]]).unwrap();
+    assert_eq!(single.root(), [1; 32]);
+    assert!(single.proof(0).unwrap().is_empty());
+}
+
+#[test]
+fn multi_proof() {
+    let tree = five();
+    let multi = tree.multi_proof(&[3, 0, 2]).unwrap();
+    assert_eq!(
+        multi,
+        MultiProof {
+            leaves: vec![
+                h("5fe7f977e71dba2ea1a68e21057beebb9be2ac30c6410aa38d4f3fbe41dcffd2"),
+                h("bc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a"),
+                h("f2ee15ea639b73fa3db9b34a245bdfa015c260c598b211bf05a1ecc4b3e3b4f2"),
+            ],
+            proof: vec![
+                h("69c322e3248a5dfc29d73c5b0553b0185a35cd5bb6386747517ef7e53b15e287"),
+                h("f343681465b9efe82c933c3e8748c70cb8aa06539c361de20f72eac04e766393"),
+            ],
+            proof_flags: vec![false, true, false, true],
+        }
+    );
+    assert!(multi.verify(&tree.root()));
+
+    for indices in [&[][..], &[0], &[4, 3], &[0, 1, 2, 3, 4], &[1, 4]] {
+        assert!(tree.multi_proof(indices).unwrap().verify(&tree.root()), "{indices:?}");
+    }
+    assert_eq!(tree.multi_proof(&[1, 2, 1]), Err(MerkleTreeError::DuplicateIndex(1)));
+}
+
+#[test]
+fn invalid_multi_proof() {
+    let tree = five();
+    let multi = tree.multi_proof(&[0, 2, 3]).unwrap();
+
+    let mut short = multi.clone();
+    short.proof.pop();
+    assert_eq!(short.process(), Err(InvalidMultiProof));
+
+    // Flags which consume inner nodes before they are computed.
+    let leaves = [[1; 32], [2; 32]];
+    assert_eq!(process_multi_proof(&leaves, &[], &[true]), Ok(tree_pair(&leaves)));
+    assert_eq!(process_multi_proof(&[], &[[1; 32], [2; 32]], &[true]), Err(InvalidMultiProof));
+}
+
+fn tree_pair(leaves: &[[u8; 32]; 2]) -> [u8; 32] {
+    keccak_asm::merkle::hash_pair(&leaves[0], &leaves[1])
+}
+
+#[test]
+fn large() {
+    let leaves: Vec<_> = (0..3000u32).map(|i| keccak(&i.to_be_bytes())).collect();
+    let tree = MerkleTree::new(&leaves).unwrap();
+    assert_eq!(tree.root(), h("25146f67a5c6b1f2346484ec6385c9c88018bac5d4a71fb708345e9b97df7935"));
+    assert!(tree.leaves().eq(leaves.iter()));
+    assert!(verify(&tree.root(), &leaves[1234], &tree.proof(1234).unwrap()));
+}
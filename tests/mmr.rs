@@ -0,0 +1,146 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::{
+    mmr::{leaf_index_to_pos, mmr_size, Mmr, MmrError, MmrStore},
+    Digest, Keccak256,
+};
+use std::{cell::Cell, collections::HashMap, convert::Infallible};
+
+fn leaf(i: u64) -> [u8; 32] {
+    Keccak256::digest(i.to_be_bytes()).into()
+}
+
+fn mmr(leaves: u64) -> Mmr<Vec<[u8; 32]>> {
+    let mut mmr = Mmr::new(Vec::new());
+    for i in 0..leaves {
+        assert_eq!(mmr.append(leaf(i)).unwrap(), leaf_index_to_pos(i).unwrap());
+    }
+    mmr
+}
+
+#[test]
+fn root() {
+    for (leaves, size, root) in [
+        (1, 1, "011b4d03dd8c01f1049143cf9c4c817e4b167f1d1b83e5c6f0f10d89ba1e7bce"),
+        (2, 3, "1a232952e30c721bb8ce6d74cdc988052b3a4e87eaac5ad92e44479241d34f9c"),
+        (3, 4, "ab5cd91218332287ca054dc4259ccae88e353af48b0e7e4247b94c37b759d5c9"),
+        (7, 11, "a50d5b67a31ecab1e676dac1ed69b3ec128e946868e9af0c40681b8e2f8c46be"),
+        (11, 19, "40df26b81a4ca34d221365eab011f20b1b4bcc2e4c30dc2d20a40e8d30f8f561"),
+    ] {
+        let mmr = mmr(leaves);
+        assert_eq!(mmr.size(), size);
+        assert_eq!(mmr_size(leaves), Some(size));
+        assert_eq!(mmr.leaf_count(), leaves);
+        assert_eq!(hex::encode(mmr.root().unwrap()), root);
+    }
+
+    assert_eq!(mmr(0).root(), Err(MmrError::Empty));
+    assert_eq!(Mmr::with_size(Vec::new(), 2).unwrap_err(), MmrError::InvalidSize(2));
+}
+
+#[test]
+fn inclusion_proof() {
+    let mmr = mmr(27);
+    let root = mmr.root().unwrap();
+    for i in 0..27 {
+        let proof = mmr.inclusion_proof(i).unwrap();
+        assert!(proof.verify(&root, &leaf(i)), "{i}");
+        assert!(!proof.verify(&root, &leaf(i + 1)), "{i}");
+    }
+
+    // Proofs against older roots.
+    let size = mmr_size(10).unwrap();
+    let old_root = mmr.root_at(size).unwrap();
+    let proof = mmr.inclusion_proof_at(9, size).unwrap();
+    assert!(proof.verify(&old_root, &leaf(9)));
+    assert!(!proof.verify(&root, &leaf(9)));
+    assert_eq!(mmr.inclusion_proof_at(10, size), Err(MmrError::LeafOutOfBounds(10)));
+    assert_eq!(mmr.inclusion_proof(27), Err(MmrError::LeafOutOfBounds(27)));
+}
+
+#[test]
+fn consistency_proof() {
+    let mmr = mmr(27);
+    let root = mmr.root().unwrap();
+    for old in 1..=27 {
+        let old_size = mmr_size(old).unwrap();
+        let old_root = mmr.root_at(old_size).unwrap();
+        let proof = mmr.consistency_proof(old_size).unwrap();
+        assert!(proof.verify(&old_root, &root), "{old}");
+        assert!(!proof.verify(&root, &old_root) || old == 27, "{old}");
+
+        let mut tampered = proof.clone();
+        tampered.old_peaks[0][0] ^= 1;
+        assert!(!tampered.verify(&old_root, &root), "{old}");
+    }
+    assert_eq!(mmr.consistency_proof(mmr.size() + 1), Err(MmrError::InvalidSize(51)));
+}
+
+#[test]
+fn overflowing_proofs() {
+    assert_eq!(leaf_index_to_pos(u64::MAX), None);
+    assert_eq!(mmr_size(u64::MAX), None);
+
+    let mmr = mmr(27);
+    let root = mmr.root().unwrap();
+    let proof = mmr.inclusion_proof(0).unwrap();
+    assert!(proof.verify(&root, &leaf(0)));
+
+    let mut tampered = proof.clone();
+    tampered.leaf_index = u64::MAX;
+    assert!(!tampered.verify(&root, &leaf(0)));
+
+    // `leaf_index_to_pos` wraps to the position of leaf 0.
+    let mut tampered = proof.clone();
+    tampered.leaf_index = (1 << 63) + 1;
+    assert!(!tampered.verify(&root, &leaf(0)));
+
+    let mut tampered = proof;
+    tampered.path.resize(100, [0; 32]);
+    assert!(!tampered.verify(&root, &leaf(0)));
+
+    let old_size = mmr_size(5).unwrap();
+    let old_root = mmr.root_at(old_size).unwrap();
+    let mut tampered = mmr.consistency_proof(old_size).unwrap();
+    tampered.paths[0].resize(100, [0; 32]);
+    assert!(!tampered.verify(&old_root, &root));
+}
+
+/// A store which counts reads, standing in for an on-disk store.
+#[derive(Default)]
+struct CountingStore {
+    nodes: HashMap<u64, [u8; 32]>,
+    reads: Cell<usize>,
+}
+
+impl MmrStore for CountingStore {
+    type Error = Infallible;
+
+    fn get(&self, pos: u64) -> Result<Option<[u8; 32]>, Self::Error> {
+        self.reads.set(self.reads.get() + 1);
+        Ok(self.nodes.get(&pos).copied())
+    }
+
+    fn append(&mut self, pos: u64, nodes: &[[u8; 32]]) -> Result<(), Self::Error> {
+        for (i, node) in nodes.iter().enumerate() {
+            self.nodes.insert(pos + i as u64, *node);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_store() {
+    let mut mmr = Mmr::new(CountingStore::default());
+    for i in 0..16 {
+        mmr.append(leaf(i)).unwrap();
+    }
+    assert_eq!(mmr.root().unwrap(), self::mmr(16).root().unwrap());
+    // A single peak.
+    assert_eq!(mmr.store().reads.get(), 15 + 1);
+
+    let mut store = mmr.into_store();
+    store.nodes.remove(&0);
+    let mmr = Mmr::with_size(store, mmr_size(16).unwrap()).unwrap();
+    assert_eq!(mmr.inclusion_proof(1), Err(MmrError::MissingNode(0)));
+}
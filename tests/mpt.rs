@@ -0,0 +1,167 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::mpt::{
+    ordered_trie_root, sec_trie_root, trie_root, verify_account_proof, verify_proof,
+    verify_storage_proof, ProofError, EMPTY_ROOT_HASH,
+};
+
+// https://github.com/ethereum/tests/blob/develop/TrieTests/trieanyorder.json
+#[test]
+fn any_order() {
+    assert_eq!(trie_root::<_, &[u8], &[u8]>([]), EMPTY_ROOT_HASH);
+    assert_eq!(
+        hex::encode(EMPTY_ROOT_HASH),
+        "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+    );
+    assert_eq!(
+        hex::encode(trie_root([("foo", "bar"), ("food", "bass")])),
+        "17beaa1648bafa633cda809c90c04af50fc8aed3cb40d16efbddee6fdf63c4c3"
+    );
+    assert_eq!(
+        hex::encode(trie_root([("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")])),
+        "8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3"
+    );
+    assert_eq!(
+        hex::encode(trie_root([
+            ("do", "verb"),
+            ("dog", "puppy"),
+            ("doge", "coin"),
+            ("horse", "stallion")
+        ])),
+        "5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84"
+    );
+}
+
+// https://github.com/ethereum/tests/blob/develop/TrieTests/trieanyorder_secureTrie.json
+#[test]
+fn secure() {
+    assert_eq!(
+        hex::encode(sec_trie_root([
+            ("dogglesworth", "cat"),
+            ("doe", "reindeer"),
+            ("dog", "puppy")
+        ])),
+        "d4cd937e4a4368d7931a9cf51686b7e10abb3dce38a39000fd7902a092b64585"
+    );
+}
+
+#[test]
+fn ordered() {
+    assert_eq!(ordered_trie_root::<[&[u8]; 0]>([]), EMPTY_ROOT_HASH);
+
+    let items = (0..130u8)
+        .map(|i| {
+            let mut item = vec![0x02, 0xf8];
+            item.extend_from_slice(&[i; 40]);
+            item
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hex::encode(ordered_trie_root(&items)),
+        "4737a7ebf25e77248bbbe516c1ec7d4a98169f238f3ab20323cb01eacc4bc4cd"
+    );
+}
+
+#[test]
+#[should_panic = "sorted"]
+fn unsorted() {
+    trie_root([("dog", "puppy"), ("do", "verb")]);
+}
+
+// Trie from `any_order` above, with both hashed and embedded nodes.
+const DOGS_PROOF: &[&str] = &[
+    "e5831646f6a0db6ae1fda66890f6693f36560d36b4dca68b4d838f17016b151efe1d4c95c453",
+    "f83b8080808080ca20887265696e6465657280a037efd11993cb04a54048c25320e9f29c50a432d28afdf01598b2978ce1ca3068808080808080808080",
+    "e4808080808080ce89376c6573776f72746883636174808080808080808080857075707079",
+];
+
+// Tries of 30 accounts and 40 storage slots.
+const STORAGE_ROOT: &str = "0cb7574f75b2489364e3474993e806978c5386aeea1808318934d12a383f9538";
+const SLOT_5_PROOF: &[&str] = &[
+    "f901f1a0f19bd17885bebef3696159e6ac61551e6f8fa5e860c9748c56b91b4987af1857a08296f255e1b20e2dd0e64abf3d05a795ffba886e463c02349c6ac3ffe1b35504a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0a7f716fd37162c12ba1413f57f309e06e2b06a74eb7c6d2ceea5f85c803d4a07a02baa73781f00b92709517dfc173b73a43ee91169c9da99634fe1318a0af37d09a0b297859ca11b5ad78844dad60b7c1a1738ef7c2c962c9c83140c08ba98426c1da0345a47ec4fbc5c40649dcc6e1f78df0aa55373f9ca4f4be190badbf1f66556c4a018749a57ff584f7dfe8b379f042ad995f039c7fa0584c925010a23d11eae857ca06ac160543c07aad4f0df61e382f47e6e55711b7590b5038d74159e9adb21e7e3a067d5387b9b3f1f9357edde08302c8e0dec1565bdf5c2a5e107df5e4b86e8c2fea0de1493539677904d0db6dc3e5d36ae99d02251d1aba8f5adf8b370b767187213a012bed3fc72e26828ff8d759f70689514838f6b5dadd5e222c82f44dc4cd2bc62a082f0837d7aaf3b386611464401f54892a7b991466165a34c2bc1b37199d82159a0a30ea94a47431c6780ead9e4240e5bfd87d89437d3f41e0717814ff0b303995780a01b26f946d79adfc95207743174bf9f26ef10e670316682462f18db8eef922b4f80",
+    "f89180a081e5e89ebcbc3d77ff527d78eb4ab00400b9d93b28a7cf8463bc28d038f585c280a0348135bafa8d6f17e1f4619400228a1e7977fbee7cbc2dc95484aec47cc9140980a0fe1fdcdce3719458a785e71a794d5786347447ab3bc4c4eb89d76a5f190741cb8080808080808080a0172a846f4f038ce0729b9c5d9379c8c0b342de5311b0f890bd0e9b39b2497c8b8080",
+    "e5a0206b6384b5eca791c62761152d0c79bb0604c104a5fb6f4eb0703f3154bb3db083829aac",
+];
+
+const SLOT_1000_PROOF: &[&str] = &[
+    "f901f1a0f19bd17885bebef3696159e6ac61551e6f8fa5e860c9748c56b91b4987af1857a08296f255e1b20e2dd0e64abf3d05a795ffba886e463c02349c6ac3ffe1b35504a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0a7f716fd37162c12ba1413f57f309e06e2b06a74eb7c6d2ceea5f85c803d4a07a02baa73781f00b92709517dfc173b73a43ee91169c9da99634fe1318a0af37d09a0b297859ca11b5ad78844dad60b7c1a1738ef7c2c962c9c83140c08ba98426c1da0345a47ec4fbc5c40649dcc6e1f78df0aa55373f9ca4f4be190badbf1f66556c4a018749a57ff584f7dfe8b379f042ad995f039c7fa0584c925010a23d11eae857ca06ac160543c07aad4f0df61e382f47e6e55711b7590b5038d74159e9adb21e7e3a067d5387b9b3f1f9357edde08302c8e0dec1565bdf5c2a5e107df5e4b86e8c2fea0de1493539677904d0db6dc3e5d36ae99d02251d1aba8f5adf8b370b767187213a012bed3fc72e26828ff8d759f70689514838f6b5dadd5e222c82f44dc4cd2bc62a082f0837d7aaf3b386611464401f54892a7b991466165a34c2bc1b37199d82159a0a30ea94a47431c6780ead9e4240e5bfd87d89437d3f41e0717814ff0b303995780a01b26f946d79adfc95207743174bf9f26ef10e670316682462f18db8eef922b4f80",
+];
+
+const STATE_ROOT: &str = "27ac4a6e5968dd10b09a611bf749d158376e44bf32d58d6a6b1fe1e6510bdfe6";
+const ACCOUNT_3_PROOF: &[&str] = &[
+    "f901d1a03366abd78e84359ac858dfe85d98199082e70c5fb85f7bdca6af32953fdcf50ca06a7724118741605d372e6c431ce541c91137481939a341cde328b0a6932b2ebf80a061732e4d77234a8bae5313f8064a07c525ba7628a22c94af999f79ce5b2e6948a0a95f62c2f51c656b5d09623190ad0766422a33f7a3cc3a8043bc73b5b00497c9a0f8737c3e88536ca9cd338da9ee99125b4a5a5cfeaf14a18e70cd3a77e2d69691a01bf10d592a592126a3877a4499fe3ae13e272513c387314ea9c8e6cb1dcab986a061bf1829ac9ad0d1dea93ff4c155156837341a57a6d1a6c950b93daaf962bf1980a0c04fe3726b5bc9f0a8934eac3359de50df7a8f52c0f8070fe3b2798da0b9564aa0e5a3a5593733f72e6feec589a8917596dbdc9e18b9e1579bd2f1d2e8efe5847aa04ffb2338ad2a1a2cd9f9263e5ed80da4c4d347c2ffa33370eb048544c497a3b7a03fa1dbe80ff4d61406ce928a7ff0c8bb09f37ba1fbadb350a10c0f62e54aa65fa049e769876d442d100923c502aaedada4769a3f1be844cc5650d616a0d93e44cda044faf06a8979d4a2c868e42345cf9dc3f373fb1b7be694f01190cbb73567fa80a02de980273727097bc36dc7ed938d246dbaecee313dd551bf66edac6064ce4c4880",
+    "e212a01ce9743138f0743ff103e2ab4579e1b3e80a2dff591c5252a937116c83909e76",
+    "f851808080808080a0fcdc34771dcf29908bc249a2e234f0144d7091b73ba1e88582f0f7c2c110b7f68080808080a07c5fd48e7c499098f589421cf2dbb5ee9aeeb43b7f480d5369e69ce06a1ed2d280808080",
+    "f8709f32f498f37adab9c7a4bf0aae161bb929b33867f5b5976848450005f577b8cbb84ef84c038829a2241af62c0000a00cb7574f75b2489364e3474993e806978c5386aeea1808318934d12a383f9538a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+];
+
+const ACCOUNT_FF_PROOF: &[&str] = &[
+    "f901d1a03366abd78e84359ac858dfe85d98199082e70c5fb85f7bdca6af32953fdcf50ca06a7724118741605d372e6c431ce541c91137481939a341cde328b0a6932b2ebf80a061732e4d77234a8bae5313f8064a07c525ba7628a22c94af999f79ce5b2e6948a0a95f62c2f51c656b5d09623190ad0766422a33f7a3cc3a8043bc73b5b00497c9a0f8737c3e88536ca9cd338da9ee99125b4a5a5cfeaf14a18e70cd3a77e2d69691a01bf10d592a592126a3877a4499fe3ae13e272513c387314ea9c8e6cb1dcab986a061bf1829ac9ad0d1dea93ff4c155156837341a57a6d1a6c950b93daaf962bf1980a0c04fe3726b5bc9f0a8934eac3359de50df7a8f52c0f8070fe3b2798da0b9564aa0e5a3a5593733f72e6feec589a8917596dbdc9e18b9e1579bd2f1d2e8efe5847aa04ffb2338ad2a1a2cd9f9263e5ed80da4c4d347c2ffa33370eb048544c497a3b7a03fa1dbe80ff4d61406ce928a7ff0c8bb09f37ba1fbadb350a10c0f62e54aa65fa049e769876d442d100923c502aaedada4769a3f1be844cc5650d616a0d93e44cda044faf06a8979d4a2c868e42345cf9dc3f373fb1b7be694f01190cbb73567fa80a02de980273727097bc36dc7ed938d246dbaecee313dd551bf66edac6064ce4c4880",
+    "f871a0334e996ba6f185447d798724a315dbf6f96f198183ba593ee362856ba50713c8b84ef84c0d88b469471f80140000a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+];
+
+fn decode(proof: &[&str]) -> Vec<Vec<u8>> {
+    proof.iter().map(|node| hex::decode(node).unwrap()).collect()
+}
+
+fn root(s: &str) -> [u8; 32] {
+    hex::decode_to_array(s).unwrap()
+}
+
+#[test]
+fn proof() {
+    let root = trie_root([("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")]);
+    let proof = decode(DOGS_PROOF);
+
+    assert_eq!(verify_proof(&root, b"dog", &proof), Ok(Some(b"puppy".to_vec())));
+    assert_eq!(verify_proof(&root, b"dogglesworth", &proof), Ok(Some(b"cat".to_vec())));
+    assert_eq!(verify_proof(&root, b"dogg", &proof), Ok(None));
+    assert_eq!(verify_proof(&root, b"doge", &proof), Ok(None));
+    assert_eq!(verify_proof(&root, b"doe", &proof[..2]), Ok(Some(b"reindeer".to_vec())));
+    assert_eq!(verify_proof(&root, b"do", &proof[..1]), Ok(None));
+    assert_eq!(verify_proof(&root, b"cat", &proof[..1]), Ok(None));
+
+    assert_eq!(verify_proof(&root, b"dog", &proof[..2]), Err(ProofError::MissingNode { index: 2 }));
+    assert_eq!(verify_proof(&root, b"doe", &proof), Err(ProofError::PathDivergence { index: 2 }));
+    assert!(matches!(
+        verify_proof(&root, b"dog", &proof[1..]),
+        Err(ProofError::HashMismatch { index: 0, .. })
+    ));
+
+    let mut malformed = proof.clone();
+    malformed[0] = vec![0xc0];
+    let root = <keccak_asm::Keccak256 as keccak_asm::Digest>::digest(&malformed[0]).into();
+    assert!(matches!(
+        verify_proof(&root, b"dog", &malformed),
+        Err(ProofError::MalformedNode { index: 0, .. })
+    ));
+
+    assert_eq!(verify_proof::<&[u8]>(&EMPTY_ROOT_HASH, b"dog", &[]), Ok(None));
+    assert_eq!(verify_proof(&EMPTY_ROOT_HASH, b"dog", &[[0x80]]), Ok(None));
+}
+
+#[test]
+fn account_and_storage_proofs() {
+    let state_root = root(STATE_ROOT);
+    let storage_root = root(STORAGE_ROOT);
+
+    let account =
+        verify_account_proof(&state_root, &[3; 20], &decode(ACCOUNT_3_PROOF)).unwrap().unwrap();
+    assert_eq!(account.nonce, 3);
+    assert_eq!(account.balance[24..], 3_000_000_000_000_000_000u64.to_be_bytes());
+    assert_eq!(account.storage_root, storage_root);
+    assert_eq!(
+        hex::encode(account.code_hash),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(verify_account_proof(&state_root, &[0xff; 20], &decode(ACCOUNT_FF_PROOF)), Ok(None));
+    assert!(verify_account_proof(&state_root, &[4; 20], &decode(ACCOUNT_3_PROOF)).is_err());
+
+    let mut slot = [0; 32];
+    slot[31] = 5;
+    let value = verify_storage_proof(&storage_root, &slot, &decode(SLOT_5_PROOF)).unwrap();
+    assert_eq!(value[28..], 39596u32.to_be_bytes());
+
+    let slot = root("00000000000000000000000000000000000000000000000000000000000003e8");
+    assert_eq!(verify_storage_proof(&storage_root, &slot, &decode(SLOT_1000_PROOF)), Ok([0; 32]));
+}
@@ -0,0 +1,55 @@
+use keccak_asm::progpow::{keccak_f800, keccak_f800_progpow};
+
+#[test]
+fn f800() {
+    let mut state = [0u32; 25];
+    keccak_f800(&mut state);
+    assert_eq!(
+        state,
+        [
+            0xe531d45d, 0xf404c6fb, 0x23a0bf99, 0xf1f8452f, 0x51ffd042, 0xe539f578, 0xf00b80a7,
+            0xaf973664, 0xbf5af34c, 0x227a2424, 0x88172715, 0x9f685884, 0xb15cd054, 0x1bf4fc0e,
+            0x6166fa91, 0x1a9e599a, 0xa3970a1f, 0xab659687, 0xafab8d68, 0xe74b1015, 0x34001a98,
+            0x4119eff3, 0x930a0e76, 0x87b28070, 0x11efe996,
+        ]
+    );
+    keccak_f800(&mut state);
+    assert_eq!(
+        state,
+        [
+            0x75bf2d0d, 0x9b610e89, 0xc826af40, 0x64cd84ab, 0xf905bdd6, 0xbc832835, 0x5f8001b9,
+            0x15662cce, 0x8e38c95e, 0x701fe543, 0x1b544380, 0x89acdeff, 0x51edb5de, 0x0e9702d9,
+            0x6c19aa16, 0xa2913eee, 0x60754e9a, 0x9819063c, 0xf4709254, 0xd09f9084, 0x772da259,
+            0x1db35df7, 0x5aa60162, 0x358825d5, 0xb3783bab,
+        ]
+    );
+
+    let mut state: [u32; 25] = core::array::from_fn(|i| i as u32);
+    keccak_f800(&mut state);
+    assert_eq!(
+        state,
+        [
+            0xe42f30cf, 0x1ecfd968, 0x0d89e1f7, 0x79b7d904, 0xdff14170, 0x30f6c89d, 0x64a7cfd3,
+            0x2d78ad80, 0xabc3edf5, 0x0086ed7c, 0xff6f46ea, 0xe0fd3d17, 0x71ca0a08, 0x4d284667,
+            0xb3869f22, 0xce40f3f9, 0x081e2727, 0xc6690c35, 0x77a234e8, 0x5bbac530, 0x2963ce54,
+            0x712973a9, 0x0a290a4f, 0xfd459217, 0xb6ccb003,
+        ]
+    );
+}
+
+#[test]
+fn progpow() {
+    assert_eq!(
+        hex::encode(keccak_f800_progpow(&[0; 32], 0, &[0; 32])),
+        "5dd431e5fbc604f499bfa0232f45f8f142d0ff5178f539e5a7800bf0643697af"
+    );
+
+    // From the EIP-1057 test vectors, where the header and the result are given as 32-bit words.
+    let header: [u8; 32] =
+        hex::decode_to_array("ffeeddccbbaa9988776655443322110000112233445566778899aabbccddeeff")
+            .unwrap();
+    assert_eq!(
+        hex::encode(keccak_f800_progpow(&header, 0x1234_5678_9abc_def0, &[0; 32])),
+        "ee304846ddd0a47b98179e96b60ec5ceeae2727834367e593de780e3e6d1892f"
+    );
+}
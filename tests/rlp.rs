@@ -0,0 +1,223 @@
+mod common;
+
+use common::hex_array;
+use keccak_asm::{
+    bloom::Bloom,
+    rlp::{
+        keccak256_rlp, AccessListItem, Eip1559Transaction, Eip2930Transaction, Encodable, Header,
+        LegacyTransaction, List, Log, Receipt, TxKind, Uint,
+    },
+    Digest, Keccak256,
+};
+
+const EMPTY_OMMERS_HASH: [u8; 32] =
+    hex_array(b"1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347");
+const EMPTY_ROOT: [u8; 32] =
+    hex_array(b"56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+
+fn hash<T: Encodable + ?Sized>(value: &T) -> [u8; 32] {
+    keccak256_rlp(value)
+}
+
+fn u256(n: u128) -> [u8; 32] {
+    let mut out = [0; 32];
+    out[16..].copy_from_slice(&n.to_be_bytes());
+    out
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(hash(&0u64), Keccak256::digest([0x80]).as_slice());
+    assert_eq!(hash(&0x7fu8), Keccak256::digest([0x7f]).as_slice());
+    assert_eq!(hash(&0x80u8), Keccak256::digest([0x81, 0x80]).as_slice());
+    assert_eq!(hash(&0x0400u16), Keccak256::digest([0x82, 0x04, 0x00]).as_slice());
+    assert_eq!(hash(&Uint(&[0, 0, 1])), Keccak256::digest([0x01]).as_slice());
+    assert_eq!(hash(&Uint(&[0; 32])), Keccak256::digest([0x80]).as_slice());
+    assert_eq!(hash(&false), Keccak256::digest([0x80]).as_slice());
+    assert_eq!(hash(&true), Keccak256::digest([0x01]).as_slice());
+    assert_eq!(u64::MAX.length(), 9);
+}
+
+#[test]
+fn strings_and_lists() {
+    assert_eq!(hash("dog"), Keccak256::digest(b"\x83dog").as_slice());
+    assert_eq!(hash(&[0u8; 0][..]), Keccak256::digest([0x80]).as_slice());
+    assert_eq!(hash(&[0u8][..]), Keccak256::digest([0x00]).as_slice());
+
+    let long = [b'a'; 56];
+    let mut expected = vec![0xb8, 56];
+    expected.extend_from_slice(&long);
+    assert_eq!(long.length(), 58);
+    assert_eq!(hash(&long), Keccak256::digest(&expected).as_slice());
+
+    let list = List(&["cat", "dog"]);
+    assert_eq!(list.length(), 9);
+    assert_eq!(hash(&list), Keccak256::digest(b"\xc8\x83cat\x83dog").as_slice());
+    assert_eq!(hash(&List::<u8>(&[])), Keccak256::digest([0xc0]).as_slice());
+
+    let items = [[0xff; 32]; 2];
+    let mut expected = vec![0xf8, 66];
+    for item in &items {
+        expected.push(0xa0);
+        expected.extend_from_slice(item);
+    }
+    assert_eq!(hash(&List(&items)), Keccak256::digest(&expected).as_slice());
+}
+
+#[test]
+fn mainnet_genesis_header() {
+    let header = Header {
+        ommers_hash: EMPTY_OMMERS_HASH,
+        state_root: hex::decode_to_array(
+            "d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544",
+        )
+        .unwrap(),
+        transactions_root: EMPTY_ROOT,
+        receipts_root: EMPTY_ROOT,
+        difficulty: u256(0x4_0000_0000),
+        gas_limit: 5000,
+        extra_data: &hex::decode_to_array::<_, 32>(
+            "11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+        )
+        .unwrap(),
+        nonce: 0x42u64.to_be_bytes(),
+        ..Default::default()
+    };
+    assert_eq!(header.length(), 535);
+    assert_eq!(
+        hex::encode(header.hash()),
+        "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+    );
+}
+
+#[test]
+fn cancun_header() {
+    let header = Header {
+        parent_hash: [0x11; 32],
+        ommers_hash: EMPTY_OMMERS_HASH,
+        beneficiary: [0x22; 20],
+        state_root: [0x33; 32],
+        transactions_root: [0x44; 32],
+        receipts_root: [0x55; 32],
+        logs_bloom: Bloom([0x66; 256]),
+        difficulty: [0; 32],
+        number: 19_000_000,
+        gas_limit: 30_000_000,
+        gas_used: 12_345_678,
+        timestamp: 1_700_000_000,
+        extra_data: b"beaverbuild.org",
+        mix_hash: [0x77; 32],
+        nonce: [0; 8],
+        base_fee_per_gas: Some(7),
+        withdrawals_root: Some([0x88; 32]),
+        blob_gas_used: Some(131072),
+        excess_blob_gas: Some(0),
+        parent_beacon_block_root: Some([0x99; 32]),
+        requests_hash: None,
+    };
+    assert_eq!(header.length(), 598);
+    assert_eq!(
+        hex::encode(header.hash()),
+        "b48d1cfab1e43b9def7f2e35786f964281136b089fa11354edbb6871661e39c0"
+    );
+}
+
+#[test]
+fn eip155_legacy_transaction() {
+    // The example of EIP-155.
+    let tx = LegacyTransaction {
+        nonce: 9,
+        gas_price: 20_000_000_000,
+        gas_limit: 21000,
+        to: TxKind::Call([0x35; 20]),
+        value: u256(1_000_000_000_000_000_000),
+        input: &[],
+        v: 37,
+        r: hex::decode_to_array("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+            .unwrap(),
+        s: hex::decode_to_array("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+            .unwrap(),
+    };
+    let encoded = hex::decode(
+        "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+    )
+    .unwrap();
+    assert_eq!(tx.length(), encoded.len());
+    assert_eq!(tx.hash(), Keccak256::digest(&encoded).as_slice());
+    assert_eq!(
+        hex::encode(tx.hash()),
+        "33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+    );
+}
+
+#[test]
+fn typed_transactions() {
+    let mut transfer = [0u8; 68];
+    transfer[..4].copy_from_slice(&[0xa9, 0x05, 0x9c, 0xbb]);
+    let mut s = [0x0d; 32];
+    s[0] = 0;
+    let keys = [[0; 32], u256(1)];
+    let access_list = [AccessListItem { address: [0xaa; 20], storage_keys: &keys }];
+    let tx = Eip1559Transaction {
+        chain_id: 1,
+        nonce: 42,
+        max_priority_fee_per_gas: 2_000_000_000,
+        max_fee_per_gas: 30_000_000_000,
+        gas_limit: 100_000,
+        to: TxKind::Call([0xbb; 20]),
+        value: u256(1_000_000_000_000_000_000),
+        input: &transfer,
+        access_list: &access_list,
+        y_parity: true,
+        r: [0xcc; 32],
+        s,
+    };
+    assert_eq!(tx.length(), 279);
+    assert_eq!(
+        hex::encode(tx.hash()),
+        "438a4ec540a3f2a2106c142e85239dc65e21e3535d2cb01170ad1d391b08e5f8"
+    );
+
+    let tx = Eip2930Transaction {
+        chain_id: 5,
+        gas_price: 1,
+        gas_limit: 53000,
+        to: TxKind::Create,
+        input: &[0x60, 0x00],
+        r: [0x01; 32],
+        s: [0x02; 32],
+        ..Default::default()
+    };
+    assert_eq!(tx.length(), 81);
+    assert_eq!(
+        hex::encode(tx.hash()),
+        "6d21efb4adcc636fac5ab9504989f5cac82a167ec87f626efe4b1147608f2aa2"
+    );
+}
+
+#[test]
+fn receipts() {
+    let topics = [[0xee; 32], [0xff; 32]];
+    let logs = [Log { address: [0xdd; 20], topics: &topics, data: &[0; 32] }];
+    let mut receipt = Receipt {
+        tx_type: 2,
+        success: true,
+        cumulative_gas_used: 21000,
+        logs_bloom: Bloom([0x10; 256]),
+        logs: &logs,
+    };
+    assert_eq!(receipt.length(), 392);
+    assert_eq!(receipt.length_2718(), 393);
+    assert_eq!(
+        hex::encode(receipt.hash()),
+        "552e73ef62eec1b063bdf6038dec3ab63e258f9bb0912eebef9db440f25d3fbf"
+    );
+
+    receipt.tx_type = 0;
+    assert_eq!(receipt.length_2718(), 392);
+    assert_eq!(receipt.hash(), keccak256_rlp(&receipt));
+    assert_eq!(
+        hex::encode(receipt.hash()),
+        "bf87974d3d25f8cf042e4c31b0ab4a4042e2265189ba0f4a809f31be1a884e98"
+    );
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes256,
+};
+use common::sequence;
+use keccak_asm::{rlpx::Mac, Digest, Keccak256};
+
+const MAC_SECRET: [u8; 32] = sequence(0);
+
+fn aes(block: &[u8; 16]) -> [u8; 16] {
+    let mut block = (*block).into();
+    Aes256::new(&MAC_SECRET.into()).encrypt_block(&mut block);
+    block.into()
+}
+
+fn handshake() -> (Mac, Mac) {
+    let initiator_nonce: [u8; 32] = core::array::from_fn(|i| 0xa0 + i as u8 % 16);
+    let recipient_nonce: [u8; 32] = core::array::from_fn(|i| 0x50 ^ i as u8);
+    let mut auth = vec![0x01, 0x90];
+    auth.extend(0..100);
+    let mut ack = vec![0x00, 0x80];
+    ack.extend([0x42; 60]);
+    Mac::egress_ingress(true, &MAC_SECRET, &initiator_nonce, &recipient_nonce, &auth, &ack)
+}
+
+#[test]
+fn peek_finalize() {
+    let peek_finalize = |hasher: &Keccak256| {
+        let (core, buffer) = hasher.clone().decompose();
+        core.peek_finalize(&buffer)
+    };
+    let mut hasher = Keccak256::new();
+    assert_eq!(peek_finalize(&hasher), Keccak256::digest(b""));
+    for i in 0..300u32 {
+        hasher.update(i.to_le_bytes());
+        let data: Vec<u8> = (0..=i).flat_map(u32::to_le_bytes).collect();
+        assert_eq!(peek_finalize(&hasher), Keccak256::digest(&data), "{i}");
+    }
+    let peeked = peek_finalize(&hasher);
+    assert_eq!(hasher.finalize(), peeked);
+}
+
+#[test]
+fn handshake_states() {
+    let (egress, ingress) = handshake();
+    assert_eq!(hex::encode(egress.digest()), "91f509bdc5ad60f602e7b4cb49bc5278");
+    assert_eq!(hex::encode(ingress.digest()), "79f6b1185a7d628bcf859cb59f3612c5");
+
+    // The recipient's states are swapped.
+    let initiator_nonce: [u8; 32] = core::array::from_fn(|i| 0xa0 + i as u8 % 16);
+    let recipient_nonce: [u8; 32] = core::array::from_fn(|i| 0x50 ^ i as u8);
+    let (recipient_egress, recipient_ingress) = Mac::egress_ingress(
+        false,
+        &MAC_SECRET,
+        &initiator_nonce,
+        &recipient_nonce,
+        b"auth",
+        b"ack",
+    );
+    assert_eq!(recipient_egress.digest(), Mac::new(&MAC_SECRET, &initiator_nonce, b"ack").digest());
+    assert_eq!(
+        recipient_ingress.digest(),
+        Mac::new(&MAC_SECRET, &recipient_nonce, b"auth").digest()
+    );
+}
+
+#[test]
+fn frame_macs() {
+    let (mut egress, _) = handshake();
+    let header: [u8; 16] = core::array::from_fn(|i| 16 + i as u8);
+    assert_eq!(hex::encode(egress.update_header(&header, aes)), "3e4628954480a562dc9ad7c8ce6c716a");
+    assert_eq!(
+        hex::encode(egress.update_body(&[0x33; 48], aes)),
+        "928ee4700fbcca0884b066b32fdc095c"
+    );
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::solidity::SolidityHasher;
+
+const ADDRESS: [u8; 20] = [
+    0xd8, 0xda, 0x6b, 0xf2, 0x69, 0x64, 0xaf, 0x9d, 0x7e, 0xed, 0x9e, 0x10, 0xc6, 0x5e, 0x1e, 0x8e,
+    0x5e, 0x04, 0x50, 0x45,
+];
+
+#[test]
+fn encode_packed() {
+    let bytes32: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let mut hasher = SolidityHasher::packed();
+    hasher
+        .push_address(&ADDRESS)
+        .push_uint::<16>(0x1234u16)
+        .push_int::<8>(-1i8)
+        .push_bool(true)
+        .push_bytes32(&bytes32)
+        .push_string("hello")
+        .push_bytes(&[0xde, 0xad])
+        .push_uint_array::<32, _>(&[1u32, 2])
+        .push_address_array(&[ADDRESS])
+        .push_uint::<16>(0x1234u64);
+    assert_eq!(
+        hex::encode(hasher.finalize()),
+        "024e2524583ae36a8c6dae2e2626aa5935b445602b8c959fa64dfecbf7223c8e"
+    );
+}
+
+#[test]
+fn encode() {
+    let data = [b'a'; 40];
+    let mut hasher = SolidityHasher::abi();
+    hasher
+        .push_address(&ADDRESS)
+        .push_bytes(&data)
+        .push_int::<64>(-5i64)
+        .push_string("x")
+        .push_uint_array::<8, _>(&[7u8, 8, 9])
+        .push_fixed_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(
+        hex::encode(hasher.finalize()),
+        "0545ab257c949cefee0759e33513482e5f20b9e01c6016c81b663360d60375b6"
+    );
+
+    let mut hasher = SolidityHasher::abi();
+    hasher.push_address(&ADDRESS).push_uint::<256>(42u8).push_bool(true);
+    assert_eq!(
+        hex::encode(hasher.finalize()),
+        "8d2d2626445e71463581b7637bdcef4f6273f62921ec074c1ae34e49fd0a152d"
+    );
+}
+
+#[test]
+#[should_panic = "does not fit in uint8"]
+fn uint_overflow() {
+    SolidityHasher::packed().push_uint::<8>(256u16);
+}
+
+#[test]
+#[should_panic = "does not fit in int8"]
+fn int_overflow() {
+    SolidityHasher::abi().push_int::<8>(-129i16);
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "alloc")]
+
+use keccak_asm::{
+    sparse_merkle::{SparseMerkleProof, SparseMerkleTree},
+    Digest, Keccak256,
+};
+
+fn keccak(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    parts.iter().for_each(|part| hasher.update(part));
+    hasher.finalize().into()
+}
+
+fn key(i: u8) -> [u8; 32] {
+    keccak(&[b"key", &[i]])
+}
+
+fn value(i: u8) -> [u8; 32] {
+    keccak(&[b"value", &[i]])
+}
+
+#[test]
+fn root() {
+    let mut tree = SparseMerkleTree::new();
+    assert!(tree.is_empty());
+    assert_eq!(
+        hex::encode(tree.root()),
+        "a7ff9e28ffd3def443d324547688c2c4eb98edf7da757d6bfa22bff55b9ce24a"
+    );
+    assert_eq!(tree.zero_hashes().last(), Some(&tree.root()));
+    assert_eq!(tree.zero_hashes()[0], [0; 32]);
+    for pair in tree.zero_hashes().windows(2) {
+        assert_eq!(pair[1], keccak(&[&pair[0], &pair[0]]));
+    }
+
+    tree.update(key(0), value(0));
+    assert_eq!(
+        hex::encode(tree.root()),
+        "3e0616c0e5021b0049e04f1af0947c94550fc9550e2b26f3d45ee5cccd42186e"
+    );
+    tree.update(key(1), value(1));
+    tree.update(key(2), value(2));
+    assert_eq!(tree.len(), 3);
+    assert_eq!(tree.get(&key(1)), value(1));
+    assert_eq!(tree.get(&key(3)), [0; 32]);
+    assert_eq!(
+        hex::encode(tree.root()),
+        "e303d1df1c94d83fa83e15d33b03458a9f36927ce9485586589f82b878dd1445"
+    );
+
+    // Removing all the keys restores the empty tree.
+    tree.update_batch((0..3).map(|i| (key(i), [0; 32])));
+    assert!(tree.is_empty());
+    assert_eq!(tree, SparseMerkleTree::new());
+}
+
+#[test]
+fn batch_update() {
+    let mut sequential = SparseMerkleTree::new();
+    for i in 0..50 {
+        sequential.update(key(i), value(i));
+    }
+    sequential.update(key(7), value(100));
+
+    let mut batch = SparseMerkleTree::new();
+    batch.update_batch((0..50).map(|i| (key(i), value(i))).chain([(key(7), value(100))]));
+    assert_eq!(batch.root(), sequential.root());
+    assert_eq!(batch, sequential);
+}
+
+#[test]
+fn proofs() {
+    let mut tree = SparseMerkleTree::new();
+    tree.update_batch((0..20).map(|i| (key(i), value(i))));
+    let root = tree.root();
+
+    for i in 0..20 {
+        let proof = tree.proof(&key(i));
+        assert_eq!(
+            proof.siblings.len(),
+            proof.bitmap.iter().map(|b| b.count_ones() as usize).sum::<usize>()
+        );
+        assert!(proof.verify(&root, &key(i), &value(i)));
+        assert!(!proof.verify(&root, &key(i), &[0; 32]));
+        assert!(!proof.verify(&root, &key(i), &value(i + 1)));
+    }
+
+    // Non-inclusion.
+    let proof = tree.proof(&key(20));
+    assert!(proof.verify(&root, &key(20), &[0; 32]));
+    assert!(!proof.verify(&root, &key(20), &value(20)));
+
+    // Keys sharing a long prefix.
+    let mut a = [0x55; 32];
+    let mut b = a;
+    b[31] ^= 1;
+    tree.update_batch([(a, value(1)), (b, value(2))]);
+    let proof = tree.proof(&a);
+    assert_eq!(proof.siblings.first(), Some(&value(2)));
+    assert!(proof.verify(&tree.root(), &a, &value(1)));
+    a[31] ^= 2;
+    assert!(tree.proof(&a).verify(&tree.root(), &a, &[0; 32]));
+
+    // Compressed proofs must match their bitmap.
+    let mut invalid = tree.proof(&b);
+    invalid.siblings.push([1; 32]);
+    assert_eq!(invalid.compute_root(&b, &value(2)), None);
+    assert_eq!(
+        SparseMerkleProof::default().compute_root(&[0; 32], &[0; 32]),
+        Some(SparseMerkleTree::new().root())
+    );
+}
@@ -0,0 +1,48 @@
+use keccak_asm::starknet::{
+    sn_keccak, starknet_selector, storage_var_address, storage_var_address_with_keys, ADDR_BOUND,
+};
+
+#[test]
+fn selectors() {
+    assert_eq!(
+        hex::encode(starknet_selector("transfer")),
+        "0083afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e"
+    );
+    assert_eq!(
+        hex::encode(starknet_selector("balanceOf")),
+        "02e4263afad30923c891518314c3c95dbe830a16874e8abc5777a9a20b54c76e"
+    );
+    assert_eq!(starknet_selector("__default__"), [0; 32]);
+    assert_eq!(starknet_selector("__l1_default__"), [0; 32]);
+    assert_eq!(
+        hex::encode(sn_keccak(b"")),
+        "01d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+}
+
+#[test]
+fn storage_addresses() {
+    assert_eq!(
+        hex::encode(storage_var_address("Account_public_key")),
+        "01379ac0624b939ceb9dede92211d7db5ee174fe28be72245b0a1a2abd81c98f"
+    );
+
+    let base = storage_var_address("ERC20_balances");
+    let owner = [0x11; 32];
+    let address = storage_var_address_with_keys("ERC20_balances", &[owner], |a, b| {
+        assert_eq!((*a, *b), (base, owner));
+        [0x02; 32]
+    });
+    assert_eq!(address, [0x02; 32]);
+    assert_eq!(storage_var_address_with_keys("ERC20_balances", &[], |_, _| unreachable!()), base);
+
+    // The largest field element, `2^251 + 17 * 2^192`, is reduced by `ADDR_BOUND`.
+    let mut max = [0; 32];
+    max[0] = 0x08;
+    max[7] = 0x11;
+    let mut reduced = [0; 32];
+    reduced[7] = 0x11;
+    reduced[30] = 0x01;
+    assert_eq!(storage_var_address_with_keys("x", &[[0; 32]], |_, _| max), reduced);
+    assert_eq!(storage_var_address_with_keys("x", &[[0; 32]], |_, _| ADDR_BOUND), [0; 32]);
+}
@@ -0,0 +1,75 @@
+use keccak_asm::storage::{BytesStorage, StorageSlot};
+
+fn slot(s: &str) -> StorageSlot {
+    StorageSlot(hex::decode_to_array(s).unwrap())
+}
+
+#[test]
+fn erc7201() {
+    // https://eips.ethereum.org/EIPS/eip-7201
+    assert_eq!(
+        StorageSlot::erc7201("example.main"),
+        slot("183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500")
+    );
+    // `OwnableUpgradeable`
+    assert_eq!(
+        StorageSlot::erc7201("openzeppelin.storage.Ownable"),
+        slot("9016d09d72d40fdae2fd8ceac6b6234c7706214fd39c1cd1e609a0528c199300")
+    );
+}
+
+#[test]
+fn mappings() {
+    let address: [u8; 20] =
+        hex::decode_to_array("d8da6bf26964af9d7eed9e10c65e1e8e5e045045").unwrap();
+    let balances = StorageSlot::new(3);
+    let balance = balances.mapping(&address);
+    assert_eq!(balance, slot("38e9aee1bd012aed330376cb42b01a4b6488840ac8153865585336c566916406"));
+    assert_eq!(
+        balance.mapping(&7u8),
+        slot("86bb51fc3393f5d9b210f0e3d1ba10f12da43401bfeafaf9ed84d4b0864179c6")
+    );
+    assert_eq!(balance.mapping(&7u8), balance.mapping(&StorageSlot::new(7)));
+    assert_eq!(
+        balances.mapping("hello"),
+        slot("963a4c0d01b136d7a32fcf2a069eced58a33a0b6ef6c92ca6b7eb61e2282c309")
+    );
+    assert_eq!(balances.mapping("hello"), balances.mapping(&b"hello"[..]));
+    assert_eq!(balances.mapping(&-1i8), balances.mapping(&[0xff; 32]));
+}
+
+#[test]
+fn arrays() {
+    let array = StorageSlot::new(3);
+    let data = slot("c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85b");
+    assert_eq!(array.data(), data);
+    assert_eq!(array.array_element(0, 1), data);
+    assert_eq!(
+        array.array_element(5, 2),
+        slot("c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f865")
+    );
+    assert_eq!(
+        array.packed_array_element(37, 4),
+        (slot("c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85f"), 20)
+    );
+    assert_eq!(StorageSlot([0xff; 32]).offset(3), StorageSlot::new(2));
+    assert_eq!(StorageSlot([0xff; 32]).checked_add(StorageSlot::new(1)), None);
+}
+
+#[test]
+fn bytes() {
+    let s = StorageSlot::new(1);
+
+    let mut short = [0; 32];
+    short[..5].copy_from_slice(b"hello");
+    short[31] = 10;
+    assert_eq!(s.bytes_storage(&short), Some(BytesStorage::Short { len: 5 }));
+    short[20] = 1;
+    assert_eq!(s.bytes_storage(&short), None);
+
+    let long = StorageSlot::new(100 * 2 + 1).0;
+    let layout = s.bytes_storage(&long).unwrap();
+    assert_eq!(layout, BytesStorage::Long { len: 100, data: s.data() });
+    let slots = layout.data_slots().collect::<Vec<_>>();
+    assert_eq!(slots, [s.data(), s.data().offset(1), s.data().offset(2), s.data().offset(3)]);
+}
@@ -0,0 +1,118 @@
+use keccak_asm::strobe::{AuthError, Strobe128};
+use strobe_rs::{SecParam, Strobe};
+
+const PROTOCOL: &[u8] = b"Conformance Test Protocol";
+
+/// Runs the same operations on both implementations, checking all outputs.
+#[test]
+fn conformance() {
+    let mut ours = Strobe128::new(PROTOCOL);
+    let mut theirs = Strobe::new(PROTOCOL, SecParam::B128);
+
+    let msg = [99u8; 1024];
+    ours.meta_ad(b"ms", false);
+    ours.meta_ad(b"g", true);
+    ours.ad(&msg, false);
+    theirs.meta_ad(b"ms", false);
+    theirs.meta_ad(b"g", true);
+    theirs.ad(&msg, false);
+
+    let mut a = [0u8; 300];
+    let mut b = [0u8; 300];
+    ours.meta_ad(b"prf", false);
+    ours.prf(&mut a, false);
+    theirs.meta_ad(b"prf", false);
+    theirs.prf(&mut b, false);
+    assert_eq!(a, b);
+
+    ours.meta_key(b"key", false);
+    ours.key(&a[..32], false);
+    theirs.meta_key(b"key", false);
+    theirs.key(&b[..32], false);
+
+    ours.send_clr(b"cleartext", false);
+    theirs.send_clr(b"cleartext", false);
+    ours.meta_recv_clr(b"meta", false);
+    theirs.meta_recv_clr(b"meta", false);
+    ours.recv_clr(&msg[..200], false);
+    theirs.recv_clr(&msg[..200], false);
+
+    let mut a = [7u8; 400];
+    let mut b = [7u8; 400];
+    ours.send_enc(&mut a[..100], false);
+    ours.send_enc(&mut a[100..], true);
+    theirs.send_enc(&mut b[..100], false);
+    theirs.send_enc(&mut b[100..], true);
+    assert_eq!(a, b);
+    ours.meta_send_enc(&mut a[..3], false);
+    theirs.meta_send_enc(&mut b[..3], false);
+    assert_eq!(a, b);
+
+    ours.recv_enc(&mut a, false);
+    theirs.recv_enc(&mut b, false);
+    assert_eq!(a, b);
+    ours.meta_recv_enc(&mut a[..10], false);
+    theirs.meta_recv_enc(&mut b[..10], false);
+    assert_eq!(a, b);
+
+    ours.ratchet(32, false);
+    theirs.ratchet(32, false);
+    ours.meta_ratchet(200, false);
+    theirs.meta_ratchet(200, false);
+
+    let mut a = [0u8; 16];
+    let mut b = [0u8; 16];
+    ours.meta_send_mac(&mut a, false);
+    theirs.meta_send_mac(&mut b, false);
+    assert_eq!(a, b);
+    ours.send_mac(&mut a, false);
+    theirs.send_mac(&mut b, false);
+    assert_eq!(a, b);
+    assert_eq!(ours.recv_mac(&mut a), Err(AuthError));
+    assert!(theirs.recv_mac(&mut b).is_err());
+    assert_eq!(a, b);
+    ours.meta_recv_mac(&mut a).unwrap_err();
+    theirs.meta_recv_mac(&mut b).unwrap_err();
+    assert_eq!(a, b);
+
+    let mut a = [0u8; 64];
+    let mut b = [0u8; 64];
+    ours.meta_prf(&mut a, false);
+    theirs.meta_prf(&mut b, false);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn session() {
+    let mut initiator = Strobe128::new(b"session");
+    let mut responder = Strobe128::new(b"session");
+    initiator.key(b"shared secret", false);
+    responder.key(b"shared secret", false);
+
+    let mut message = *b"attack at dawn";
+    initiator.send_enc(&mut message, false);
+    assert_ne!(&message, b"attack at dawn");
+    responder.recv_enc(&mut message, false);
+    assert_eq!(&message, b"attack at dawn");
+
+    let mut mac = [0; 16];
+    initiator.send_mac(&mut mac, false);
+    responder.recv_mac(&mut mac.clone()).unwrap();
+
+    // The responder replies.
+    let mut reply = *b"ack";
+    responder.send_enc(&mut reply, false);
+    initiator.recv_enc(&mut reply, false);
+    assert_eq!(&reply, b"ack");
+    responder.send_mac(&mut mac, false);
+    mac[0] ^= 1;
+    assert_eq!(initiator.recv_mac(&mut mac), Err(AuthError));
+}
+
+#[test]
+#[should_panic = "`more` can only continue the same operation"]
+fn more_changes_operation() {
+    let mut strobe = Strobe128::new(PROTOCOL);
+    strobe.ad(b"hello", false);
+    strobe.key(b"world", true);
+}
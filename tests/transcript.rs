@@ -0,0 +1,206 @@
+mod common;
+
+use common::hex_array;
+use keccak_asm::{
+    transcript::{Chaining, KeccakTranscript, Transcript},
+    Digest, Keccak256,
+};
+
+#[test]
+fn merlin_equivalence() {
+    let mut ours = Transcript::new(b"test protocol");
+    let mut theirs = merlin::Transcript::new(b"test protocol");
+    ours.append_message(b"some label", b"some data");
+    theirs.append_message(b"some label", b"some data");
+    ours.append_u64(b"number", 0x0123_4567_89ab_cdef);
+    theirs.append_u64(b"number", 0x0123_4567_89ab_cdef);
+
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    ours.challenge_bytes(b"challenge", &mut a);
+    theirs.challenge_bytes(b"challenge", &mut b);
+    assert_eq!(a, b);
+
+    let data = [99u8; 1024];
+    ours.append_message(b"big data", &data);
+    theirs.append_message(b"big data", &data);
+    let mut a = [0u8; 1000];
+    let mut b = [0u8; 1000];
+    ours.challenge_bytes(b"big challenge", &mut a);
+    theirs.challenge_bytes(b"big challenge", &mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn merlin_vector() {
+    let mut transcript = Transcript::new(b"test protocol");
+    transcript.append_message(b"some label", b"some data");
+    let mut challenge = [0u8; 32];
+    transcript.challenge_bytes(b"challenge", &mut challenge);
+    assert_eq!(
+        hex::encode(challenge),
+        "d5a21972d0d5fe320c0d263fac7fffb8145aa640af6e9bca177c03c7efcf0615"
+    );
+}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn transcript_rng() {
+    use keccak_asm::{
+        drbg::{rand_core::RngCore, HashDrbg},
+        Sha3_256,
+    };
+
+    let mut ours = Transcript::new(b"rng protocol");
+    let mut theirs = merlin::Transcript::new(b"rng protocol");
+    ours.append_message(b"statement", b"public");
+    theirs.append_message(b"statement", b"public");
+
+    let new_rng = || HashDrbg::<Sha3_256>::new(&[0x42; 32], &[0; 16], &[]).unwrap();
+    let mut a =
+        ours.build_rng().rekey_with_witness_bytes(b"witness", b"secret").finalize(&mut new_rng());
+    let mut b =
+        theirs.build_rng().rekey_with_witness_bytes(b"witness", b"secret").finalize(&mut new_rng());
+    let mut x = [0u8; 100];
+    let mut y = [0u8; 100];
+    a.fill_bytes(&mut x);
+    b.fill_bytes(&mut y);
+    assert_eq!(x, y);
+    assert_eq!(a.next_u64(), b.next_u64());
+}
+
+const BN254_R: [u8; 32] =
+    hex_array(b"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+fn word(x: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&x.to_be_bytes());
+    word
+}
+
+#[test]
+fn keccak_transcript() {
+    let mut transcript = KeccakTranscript::new(&BN254_R);
+    assert_eq!(transcript.state(), Some([0; 32]));
+    transcript.append_word(&word(1));
+    transcript.append_g1(&word(2), &word(3));
+    transcript.append_u64(7);
+
+    // `keccak256(abi.encodePacked(bytes32(0), 1, 2, 3, 7))`
+    let mut packed = vec![0u8; 32];
+    for x in [1, 2, 3, 7] {
+        packed.extend_from_slice(&word(x));
+    }
+    let hash = Keccak256::digest(&packed);
+    assert_eq!(
+        hex::encode(transcript.clone().challenge_unreduced()),
+        "cd0d76cca7e9479858e3becd9b6caa315c14e0d65ed988e6e8f4305c01a3b4f6"
+    );
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "0b7c3d012322c6f177a2a7f3956748bcbb453fb477f3c6a1d96c5a0c41a3b4f2"
+    );
+    assert_eq!(transcript.state().unwrap(), hash.as_slice());
+
+    // Squeezing again hashes the state alone.
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "173dfba12d489a9edf830b38324b1331ebe505d144c1b6c88c75ba2b04e85750"
+    );
+
+    transcript.append_g2(&[word(11), word(10)], &[word(13), word(12)]);
+    transcript.append_bytes(&[0xde, 0xad]);
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "17f9688839b953711f8b52707c548de75000c10b0e3363a31b7a99e559b6f4b6"
+    );
+
+    let state = transcript.state().unwrap();
+    let mut small = KeccakTranscript::new(&word(7)).with_state(state);
+    assert_eq!(small.challenge(), word(1));
+}
+
+#[test]
+fn keccak_transcript_large_modulus() {
+    let mut modulus = [0u8; 32];
+    modulus[0] = 0x80;
+    modulus[31] = 0x01;
+    let mut transcript = KeccakTranscript::new(&modulus);
+    transcript.append_word(&word(1));
+    transcript.append_g1(&word(2), &word(3));
+    transcript.append_u64(7);
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "4d0d76cca7e9479858e3becd9b6caa315c14e0d65ed988e6e8f4305c01a3b4f5"
+    );
+
+    let mut transcript = KeccakTranscript::new(&[0xff; 32]).with_state([0xff; 32]);
+    let hash = Keccak256::digest([0xff; 32]);
+    assert_eq!(transcript.challenge(), hash.as_slice());
+}
+
+#[test]
+fn keccak_transcript_reduced_chaining() {
+    let mut transcript = KeccakTranscript::new(&BN254_R).with_chaining(Chaining::Reduced);
+    assert_eq!(transcript.state(), None);
+    transcript.append_word(&word(1));
+    transcript.append_word(&word(2));
+    let c1 = transcript.challenge();
+    assert_eq!(hex::encode(c1), "277a420332215ead37ba61fee84f0d23276a6799e5da57c1354dc37d12a7c2dc");
+    assert_eq!(transcript.state(), Some(c1));
+
+    // `keccak256(abi.encodePacked(c1, 3)) % r`
+    transcript.append_u64(3);
+    let c2 = transcript.challenge();
+    assert_eq!(hex::encode(c2), "22c58535ac9edf53df970c2ba1257a7f357e837d5783aed801bb3eb693b9fdf4");
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "2f581991ff0037006eba411a94b064217c6a16190250ac4b97d5852c7511886a"
+    );
+}
+
+/// The challenges of a PLONK proof in the order of `calculateChallenges` in snarkjs, which appends
+/// the previous challenges explicitly. The values were computed with an independent Keccak model,
+/// not with snarkjs.
+#[test]
+fn keccak_transcript_no_chaining() {
+    let mut transcript = KeccakTranscript::new(&BN254_R).with_chaining(Chaining::None);
+    // The verification key `Qm, Ql, Qr, Qo, Qc, S1, S2, S3`, a public input and `A, B, C`.
+    for i in 0..8 {
+        transcript.append_g1(&word(2 * i + 2), &word(2 * i + 3));
+    }
+    transcript.append_u64(42);
+    for i in 0..3 {
+        transcript.append_g1(&word(2 * i + 100), &word(2 * i + 101));
+    }
+    let beta = transcript.challenge();
+    assert_eq!(transcript.state(), None);
+    assert_eq!(
+        hex::encode(beta),
+        "2b5cb8eed815f79c89f31bf6ad7898054c8b96063d88f49157ef55e3d4e40530"
+    );
+
+    transcript.append_word(&beta);
+    let gamma = transcript.challenge();
+    assert_eq!(
+        hex::encode(gamma),
+        "0fcb73611b3a1565d2814970c98a7e33cdab18da8deef23e14aa6f1f6e7f3a7b"
+    );
+
+    transcript.append_word(&beta);
+    transcript.append_word(&gamma);
+    transcript.append_g1(&word(200), &word(201));
+    assert_eq!(
+        hex::encode(transcript.challenge()),
+        "00fc22e3ce4b4652a1196f37419bc86fd9878fa2c1b40a49bcc8075f6d9db0a2"
+    );
+
+    // Squeezing with nothing appended hashes the empty string.
+    assert_eq!(transcript.challenge_unreduced(), Keccak256::digest([]).as_slice());
+}
+
+#[test]
+#[should_panic = "modulus must not be zero"]
+fn keccak_transcript_zero_modulus() {
+    KeccakTranscript::new(&[0; 32]);
+}
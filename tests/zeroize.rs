@@ -0,0 +1,28 @@
+#![cfg(feature = "zeroize")]
+
+use core::mem::{size_of, MaybeUninit};
+use digest::core_api::{Buffer, FixedOutputCore, UpdateCore};
+use keccak_asm::{Digest, Keccak256, Keccak256Core};
+
+const SECRET: [u8; 40] = *b"0123456789abcdef-secret-key-material-xyz";
+
+#[test]
+fn drop_wipes_lanes() {
+    let mut core = Keccak256Core::default();
+    core.update_blocks(&[Default::default()]);
+    let mut slot = MaybeUninit::new(core);
+    let bytes = unsafe {
+        core::ptr::drop_in_place(slot.as_mut_ptr());
+        core::slice::from_raw_parts(slot.as_ptr().cast::<u8>(), size_of::<Keccak256Core>())
+    };
+    assert!(bytes.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn finalize_wipes_padded_block() {
+    let mut buffer = Buffer::<Keccak256Core>::new(&SECRET);
+    let mut out = Default::default();
+    Keccak256Core::default().finalize_fixed_core(&mut buffer, &mut out);
+    assert_eq!(&out[..], &Keccak256::digest(SECRET)[..]);
+    assert!(buffer.pad_with_zeros().iter().all(|&b| b == 0));
+}